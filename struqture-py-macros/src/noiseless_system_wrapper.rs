@@ -354,7 +354,7 @@ pub fn noiselesswrapper(
                 ///
                 /// Raises:
                 ///     ValueError: CalculatorError.
-                ///     RuntimeError: Could not convert to complex superoperator matrix.
+                ///     RuntimeError: Could not construct the sparse matrix, e.g. because number_spins is smaller than the number of spins in self.
                 pub fn sparse_matrix_coo(&self, number_spins: usize) -> PyResult<PyCooMatrix> {
                     let coo = self
                         .internal
@@ -363,12 +363,69 @@ pub fn noiselesswrapper(
                             StruqtureError::CalculatorError(c_err) => {
                                 PyValueError::new_err(format!("{}", c_err))
                             }
-                            _ => PyRuntimeError::new_err(
-                                "Could not convert to complex superoperator matrix".to_string(),
-                            ),
+                            _ => PyRuntimeError::new_err(format!(
+                                "Could not construct the sparse matrix: {:?}",
+                                err
+                            )),
                         })?;
                     to_py_coo(coo)
                 }
+                /// Constructs the sparse matrix representation of self as a scipy CSR matrix with a given number of spins.
+                ///
+                /// The result can be unpacked into scipy.sparse.csr_matrix(*result), which sets the shape.
+                ///
+                /// Args:
+                ///     number_spins (int): The number of spins in self.
+                ///
+                /// Returns:
+                ///     Tuple[Tuple[np.ndarray, np.ndarray, np.ndarray], Tuple[int, int]]: The little endian matrix representation of self as ((values, column indices, row pointers), shape).
+                ///
+                /// Raises:
+                ///     ValueError: CalculatorError.
+                ///     RuntimeError: Could not construct the sparse matrix, e.g. because number_spins is smaller than the number of spins in self.
+                pub fn sparse_matrix_csr(&self, number_spins: usize) -> PyResult<PyCompressedSparseMatrix> {
+                    let matrix = self
+                        .internal
+                        .sparse_matrix_csr(number_spins)
+                        .map_err(|err| match err {
+                            StruqtureError::CalculatorError(c_err) => {
+                                PyValueError::new_err(format!("{}", c_err))
+                            }
+                            _ => PyRuntimeError::new_err(format!(
+                                "Could not construct the sparse matrix: {:?}",
+                                err
+                            )),
+                        })?;
+                    to_py_compressed(matrix, 2usize.pow(number_spins as u32))
+                }
+                /// Constructs the sparse matrix representation of self as a scipy CSC matrix with a given number of spins.
+                ///
+                /// The result can be unpacked into scipy.sparse.csc_matrix(*result), which sets the shape.
+                ///
+                /// Args:
+                ///     number_spins (int): The number of spins in self.
+                ///
+                /// Returns:
+                ///     Tuple[Tuple[np.ndarray, np.ndarray, np.ndarray], Tuple[int, int]]: The little endian matrix representation of self as ((values, row indices, column pointers), shape).
+                ///
+                /// Raises:
+                ///     ValueError: CalculatorError.
+                ///     RuntimeError: Could not construct the sparse matrix, e.g. because number_spins is smaller than the number of spins in self.
+                pub fn sparse_matrix_csc(&self, number_spins: usize) -> PyResult<PyCompressedSparseMatrix> {
+                    let matrix = self
+                        .internal
+                        .sparse_matrix_csc(number_spins)
+                        .map_err(|err| match err {
+                            StruqtureError::CalculatorError(c_err) => {
+                                PyValueError::new_err(format!("{}", c_err))
+                            }
+                            _ => PyRuntimeError::new_err(format!(
+                                "Could not construct the sparse matrix: {:?}",
+                                err
+                            )),
+                        })?;
+                    to_py_compressed(matrix, 2usize.pow(number_spins as u32))
+                }
         }
     } else {
         TokenStream::new()
@@ -391,7 +448,7 @@ pub fn noiselesswrapper(
                 ///
                 /// Raises:
                 ///     ValueError: CalculatorError.
-                ///     RuntimeError: Could not convert to complex superoperator matrix.
+                ///     RuntimeError: Could not construct the sparse superoperator matrix, e.g. because number_spins is smaller than the number of spins in self.
                 pub fn sparse_matrix_superoperator_coo(&self, number_spins: usize) -> PyResult<PyCooMatrix> {
                     let coo = self
                         .internal
@@ -400,12 +457,75 @@ pub fn noiselesswrapper(
                             StruqtureError::CalculatorError(c_err) => {
                                 PyValueError::new_err(format!("{}", c_err))
                             }
-                            _ => PyRuntimeError::new_err(
-                                "Could not convert to complex superoperator matrix".to_string(),
-                            ),
+                            _ => PyRuntimeError::new_err(format!(
+                                "Could not construct the sparse superoperator matrix: {:?}",
+                                err
+                            )),
                         })?;
                     to_py_coo(coo)
                 }
+                /// Construct the sparse matrix representation of the superoperator in CSR representation.
+                ///
+                /// The superoperator for the operator O is defined as the Matrix S so that
+                /// `flatten(-i [O, p]) = S flatten(p)` wher `[,]` is the commutator, `p` is a matrix
+                /// and `flatten` flattens a matrix into a vector in row-major form.
+                /// The result can be unpacked into scipy.sparse.csr_matrix(*result), which sets the shape.
+                ///
+                /// Args:
+                ///     number_spins (int): The number of spins to construct the matrix for.
+                ///
+                /// Returns:
+                ///     Tuple[Tuple[np.ndarray, np.ndarray, np.ndarray], Tuple[int, int]]: The little endian matrix representation of self as ((values, column indices, row pointers), shape).
+                ///
+                /// Raises:
+                ///     ValueError: CalculatorError.
+                ///     RuntimeError: Could not construct the sparse superoperator matrix, e.g. because number_spins is smaller than the number of spins in self.
+                pub fn sparse_matrix_superoperator_csr(&self, number_spins: usize) -> PyResult<PyCompressedSparseMatrix> {
+                    let matrix = self
+                        .internal
+                        .sparse_matrix_superoperator_csr(number_spins)
+                        .map_err(|err| match err {
+                            StruqtureError::CalculatorError(c_err) => {
+                                PyValueError::new_err(format!("{}", c_err))
+                            }
+                            _ => PyRuntimeError::new_err(format!(
+                                "Could not construct the sparse superoperator matrix: {:?}",
+                                err
+                            )),
+                        })?;
+                    to_py_compressed(matrix, 2usize.pow(number_spins as u32).pow(2))
+                }
+                /// Construct the sparse matrix representation of the superoperator in CSC representation.
+                ///
+                /// The superoperator for the operator O is defined as the Matrix S so that
+                /// `flatten(-i [O, p]) = S flatten(p)` wher `[,]` is the commutator, `p` is a matrix
+                /// and `flatten` flattens a matrix into a vector in row-major form.
+                /// The result can be unpacked into scipy.sparse.csc_matrix(*result), which sets the shape.
+                ///
+                /// Args:
+                ///     number_spins (int): The number of spins to construct the matrix for.
+                ///
+                /// Returns:
+                ///     Tuple[Tuple[np.ndarray, np.ndarray, np.ndarray], Tuple[int, int]]: The little endian matrix representation of self as ((values, row indices, column pointers), shape).
+                ///
+                /// Raises:
+                ///     ValueError: CalculatorError.
+                ///     RuntimeError: Could not construct the sparse superoperator matrix, e.g. because number_spins is smaller than the number of spins in self.
+                pub fn sparse_matrix_superoperator_csc(&self, number_spins: usize) -> PyResult<PyCompressedSparseMatrix> {
+                    let matrix = self
+                        .internal
+                        .sparse_matrix_superoperator_csc(number_spins)
+                        .map_err(|err| match err {
+                            StruqtureError::CalculatorError(c_err) => {
+                                PyValueError::new_err(format!("{}", c_err))
+                            }
+                            _ => PyRuntimeError::new_err(format!(
+                                "Could not construct the sparse superoperator matrix: {:?}",
+                                err
+                            )),
+                        })?;
+                    to_py_compressed(matrix, 2usize.pow(number_spins as u32).pow(2))
+                }
         }
     } else {
         TokenStream::new()
@@ -72,7 +72,7 @@ fn struqture_py(_py: Python, module: &Bound<PyModule>) -> PyResult<()> {
 
 use num_complex::Complex64;
 use numpy::{IntoPyArray, PyArray1};
-use struqture::CooSparseMatrix;
+use struqture::{CooSparseMatrix, CsrSparseMatrix};
 
 pub type PyCooMatrix = (
     Py<PyArray1<Complex64>>,
@@ -90,6 +90,30 @@ fn to_py_coo(coo: CooSparseMatrix) -> PyResult<PyCooMatrix> {
     })
 }
 
+pub type PyCompressedSparseMatrix = (
+    (
+        Py<PyArray1<Complex64>>,
+        Py<PyArray1<usize>>,
+        Py<PyArray1<usize>>,
+    ),
+    (usize, usize),
+);
+
+// Simple wrapper function to convert internal CSR or CSC matrices of a given dimension to a Python compatible
+// form ((values, indices, pointers), shape) that can be unpacked into scipy.sparse.csr_matrix or
+// scipy.sparse.csc_matrix, it expects a CsrSparseMatrix so any error handling should be done before using it.
+fn to_py_compressed(
+    matrix: CsrSparseMatrix,
+    dimension: usize,
+) -> PyResult<PyCompressedSparseMatrix> {
+    Python::attach(|py| -> PyResult<PyCompressedSparseMatrix> {
+        let values: Py<PyArray1<Complex64>> = matrix.0.into_pyarray(py).into();
+        let indices: Py<PyArray1<usize>> = matrix.1.into_pyarray(py).into();
+        let pointers: Py<PyArray1<usize>> = matrix.2.into_pyarray(py).into();
+        Ok(((values, indices, pointers), (dimension, dimension)))
+    })
+}
+
 #[inline]
 fn create_subscript(char: char) -> char {
     let offset = (char as u32) - ('0' as u32);
@@ -13,7 +13,7 @@
 use super::{DecoherenceProductWrapper, PauliProductWrapper};
 use super::{PauliHamiltonianWrapper, PauliLindbladNoiseOperatorWrapper};
 use crate::fermions::FermionLindbladOpenSystemWrapper;
-use crate::{to_py_coo, PyCooMatrix};
+use crate::{to_py_compressed, to_py_coo, PyCompressedSparseMatrix, PyCooMatrix};
 use pyo3::exceptions::{PyRuntimeError, PyTypeError, PyValueError};
 use pyo3::ffi::c_str;
 use pyo3::prelude::*;
@@ -12,7 +12,7 @@
 
 use crate::fermions::FermionOperatorWrapper;
 use crate::spins::PauliProductWrapper;
-use crate::{to_py_coo, PyCooMatrix};
+use crate::{to_py_compressed, to_py_coo, PyCompressedSparseMatrix, PyCooMatrix};
 use pyo3::exceptions::{PyRuntimeError, PyTypeError, PyValueError};
 use pyo3::ffi::c_str;
 use pyo3::prelude::*;
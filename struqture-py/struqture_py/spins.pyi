@@ -778,7 +778,41 @@ class PauliOperator:
 
         Raises:
             ValueError: CalculatorError.
-            RuntimeError: Could not convert to complex superoperator matrix.
+            RuntimeError: Could not construct the sparse matrix, e.g. because number_spins is smaller than the number of spins in self.
+        """
+
+    def sparse_matrix_csr(self, number_spins: int) -> Tuple[Tuple[numpy.ndarray, numpy.ndarray, numpy.ndarray], Tuple[int, int]]:  # type: ignore
+        """
+        Constructs the sparse matrix representation of self as a scipy CSR matrix with a given number of spins.
+
+        The result can be unpacked into scipy.sparse.csr_matrix(*result), which sets the shape.
+
+        Args:
+            number_spins (int): The number of spins in self.
+
+        Returns:
+            Tuple[Tuple[np.ndarray, np.ndarray, np.ndarray], Tuple[int, int]]: The little endian matrix representation of self as ((values, column indices, row pointers), shape).
+
+        Raises:
+            ValueError: CalculatorError.
+            RuntimeError: Could not construct the sparse matrix, e.g. because number_spins is smaller than the number of spins in self.
+        """
+
+    def sparse_matrix_csc(self, number_spins: int) -> Tuple[Tuple[numpy.ndarray, numpy.ndarray, numpy.ndarray], Tuple[int, int]]:  # type: ignore
+        """
+        Constructs the sparse matrix representation of self as a scipy CSC matrix with a given number of spins.
+
+        The result can be unpacked into scipy.sparse.csc_matrix(*result), which sets the shape.
+
+        Args:
+            number_spins (int): The number of spins in self.
+
+        Returns:
+            Tuple[Tuple[np.ndarray, np.ndarray, np.ndarray], Tuple[int, int]]: The little endian matrix representation of self as ((values, row indices, column pointers), shape).
+
+        Raises:
+            ValueError: CalculatorError.
+            RuntimeError: Could not construct the sparse matrix, e.g. because number_spins is smaller than the number of spins in self.
         """
 
     def sparse_matrix_superoperator_coo(self, number_spins: int) -> Tuple[numpy.ndarray, Tuple[numpy.ndarray, numpy.ndarray]]:  # type: ignore
@@ -797,7 +831,47 @@ class PauliOperator:
 
         Raises:
             ValueError: CalculatorError.
-            RuntimeError: Could not convert to complex superoperator matrix.
+            RuntimeError: Could not construct the sparse superoperator matrix, e.g. because number_spins is smaller than the number of spins in self.
+        """
+
+    def sparse_matrix_superoperator_csr(self, number_spins: int) -> Tuple[Tuple[numpy.ndarray, numpy.ndarray, numpy.ndarray], Tuple[int, int]]:  # type: ignore
+        """
+        Construct the sparse matrix representation of the superoperator in CSR representation.
+
+        The superoperator for the operator O is defined as the Matrix S so that
+        `flatten(-i [O, p]) = S flatten(p)` wher `[,]` is the commutator, `p` is a matrix
+        and `flatten` flattens a matrix into a vector in row-major form.
+        The result can be unpacked into scipy.sparse.csr_matrix(*result), which sets the shape.
+
+        Args:
+            number_spins (int): The number of spins to construct the matrix for.
+
+        Returns:
+            Tuple[Tuple[np.ndarray, np.ndarray, np.ndarray], Tuple[int, int]]: The little endian matrix representation of self as ((values, column indices, row pointers), shape).
+
+        Raises:
+            ValueError: CalculatorError.
+            RuntimeError: Could not construct the sparse superoperator matrix, e.g. because number_spins is smaller than the number of spins in self.
+        """
+
+    def sparse_matrix_superoperator_csc(self, number_spins: int) -> Tuple[Tuple[numpy.ndarray, numpy.ndarray, numpy.ndarray], Tuple[int, int]]:  # type: ignore
+        """
+        Construct the sparse matrix representation of the superoperator in CSC representation.
+
+        The superoperator for the operator O is defined as the Matrix S so that
+        `flatten(-i [O, p]) = S flatten(p)` wher `[,]` is the commutator, `p` is a matrix
+        and `flatten` flattens a matrix into a vector in row-major form.
+        The result can be unpacked into scipy.sparse.csc_matrix(*result), which sets the shape.
+
+        Args:
+            number_spins (int): The number of spins to construct the matrix for.
+
+        Returns:
+            Tuple[Tuple[np.ndarray, np.ndarray, np.ndarray], Tuple[int, int]]: The little endian matrix representation of self as ((values, row indices, column pointers), shape).
+
+        Raises:
+            ValueError: CalculatorError.
+            RuntimeError: Could not construct the sparse superoperator matrix, e.g. because number_spins is smaller than the number of spins in self.
         """
 
     def from_json_struqture_1(self, input: Any) -> Any:  # type: ignore
@@ -1074,7 +1148,41 @@ class PauliHamiltonian:
 
         Raises:
             ValueError: CalculatorError.
-            RuntimeError: Could not convert to complex superoperator matrix.
+            RuntimeError: Could not construct the sparse matrix, e.g. because number_spins is smaller than the number of spins in self.
+        """
+
+    def sparse_matrix_csr(self, number_spins: int) -> Tuple[Tuple[numpy.ndarray, numpy.ndarray, numpy.ndarray], Tuple[int, int]]:  # type: ignore
+        """
+        Constructs the sparse matrix representation of self as a scipy CSR matrix with a given number of spins.
+
+        The result can be unpacked into scipy.sparse.csr_matrix(*result), which sets the shape.
+
+        Args:
+            number_spins (int): The number of spins in self.
+
+        Returns:
+            Tuple[Tuple[np.ndarray, np.ndarray, np.ndarray], Tuple[int, int]]: The little endian matrix representation of self as ((values, column indices, row pointers), shape).
+
+        Raises:
+            ValueError: CalculatorError.
+            RuntimeError: Could not construct the sparse matrix, e.g. because number_spins is smaller than the number of spins in self.
+        """
+
+    def sparse_matrix_csc(self, number_spins: int) -> Tuple[Tuple[numpy.ndarray, numpy.ndarray, numpy.ndarray], Tuple[int, int]]:  # type: ignore
+        """
+        Constructs the sparse matrix representation of self as a scipy CSC matrix with a given number of spins.
+
+        The result can be unpacked into scipy.sparse.csc_matrix(*result), which sets the shape.
+
+        Args:
+            number_spins (int): The number of spins in self.
+
+        Returns:
+            Tuple[Tuple[np.ndarray, np.ndarray, np.ndarray], Tuple[int, int]]: The little endian matrix representation of self as ((values, row indices, column pointers), shape).
+
+        Raises:
+            ValueError: CalculatorError.
+            RuntimeError: Could not construct the sparse matrix, e.g. because number_spins is smaller than the number of spins in self.
         """
 
     def sparse_matrix_superoperator_coo(self, number_spins: int) -> Tuple[numpy.ndarray, Tuple[numpy.ndarray, numpy.ndarray]]:  # type: ignore
@@ -1093,7 +1201,47 @@ class PauliHamiltonian:
 
         Raises:
             ValueError: CalculatorError.
-            RuntimeError: Could not convert to complex superoperator matrix.
+            RuntimeError: Could not construct the sparse superoperator matrix, e.g. because number_spins is smaller than the number of spins in self.
+        """
+
+    def sparse_matrix_superoperator_csr(self, number_spins: int) -> Tuple[Tuple[numpy.ndarray, numpy.ndarray, numpy.ndarray], Tuple[int, int]]:  # type: ignore
+        """
+        Construct the sparse matrix representation of the superoperator in CSR representation.
+
+        The superoperator for the operator O is defined as the Matrix S so that
+        `flatten(-i [O, p]) = S flatten(p)` wher `[,]` is the commutator, `p` is a matrix
+        and `flatten` flattens a matrix into a vector in row-major form.
+        The result can be unpacked into scipy.sparse.csr_matrix(*result), which sets the shape.
+
+        Args:
+            number_spins (int): The number of spins to construct the matrix for.
+
+        Returns:
+            Tuple[Tuple[np.ndarray, np.ndarray, np.ndarray], Tuple[int, int]]: The little endian matrix representation of self as ((values, column indices, row pointers), shape).
+
+        Raises:
+            ValueError: CalculatorError.
+            RuntimeError: Could not construct the sparse superoperator matrix, e.g. because number_spins is smaller than the number of spins in self.
+        """
+
+    def sparse_matrix_superoperator_csc(self, number_spins: int) -> Tuple[Tuple[numpy.ndarray, numpy.ndarray, numpy.ndarray], Tuple[int, int]]:  # type: ignore
+        """
+        Construct the sparse matrix representation of the superoperator in CSC representation.
+
+        The superoperator for the operator O is defined as the Matrix S so that
+        `flatten(-i [O, p]) = S flatten(p)` wher `[,]` is the commutator, `p` is a matrix
+        and `flatten` flattens a matrix into a vector in row-major form.
+        The result can be unpacked into scipy.sparse.csc_matrix(*result), which sets the shape.
+
+        Args:
+            number_spins (int): The number of spins to construct the matrix for.
+
+        Returns:
+            Tuple[Tuple[np.ndarray, np.ndarray, np.ndarray], Tuple[int, int]]: The little endian matrix representation of self as ((values, row indices, column pointers), shape).
+
+        Raises:
+            ValueError: CalculatorError.
+            RuntimeError: Could not construct the sparse superoperator matrix, e.g. because number_spins is smaller than the number of spins in self.
         """
 
     def from_json_struqture_1(self, input: Any) -> Any:  # type: ignore
@@ -1384,7 +1532,47 @@ class PauliLindbladNoiseOperator(NoiseType):
 
         Raises:
             ValueError: CalculatorError.
-            RuntimeError: Could not convert to complex superoperator matrix.
+            RuntimeError: Could not construct the sparse superoperator matrix, e.g. because number_spins is smaller than the number of spins in self.
+        """
+
+    def sparse_matrix_superoperator_csr(self, number_spins: int) -> Tuple[Tuple[numpy.ndarray, numpy.ndarray, numpy.ndarray], Tuple[int, int]]:  # type: ignore
+        """
+        Construct the sparse matrix representation of the superoperator in CSR representation.
+
+        The superoperator for the operator O is defined as the Matrix S so that
+        `flatten(-i [O, p]) = S flatten(p)` wher `[,]` is the commutator, `p` is a matrix
+        and `flatten` flattens a matrix into a vector in row-major form.
+        The result can be unpacked into scipy.sparse.csr_matrix(*result), which sets the shape.
+
+        Args:
+            number_spins (int): The number of spins to construct the matrix for.
+
+        Returns:
+            Tuple[Tuple[np.ndarray, np.ndarray, np.ndarray], Tuple[int, int]]: The little endian matrix representation of self as ((values, column indices, row pointers), shape).
+
+        Raises:
+            ValueError: CalculatorError.
+            RuntimeError: Could not construct the sparse superoperator matrix, e.g. because number_spins is smaller than the number of spins in self.
+        """
+
+    def sparse_matrix_superoperator_csc(self, number_spins: int) -> Tuple[Tuple[numpy.ndarray, numpy.ndarray, numpy.ndarray], Tuple[int, int]]:  # type: ignore
+        """
+        Construct the sparse matrix representation of the superoperator in CSC representation.
+
+        The superoperator for the operator O is defined as the Matrix S so that
+        `flatten(-i [O, p]) = S flatten(p)` wher `[,]` is the commutator, `p` is a matrix
+        and `flatten` flattens a matrix into a vector in row-major form.
+        The result can be unpacked into scipy.sparse.csc_matrix(*result), which sets the shape.
+
+        Args:
+            number_spins (int): The number of spins to construct the matrix for.
+
+        Returns:
+            Tuple[Tuple[np.ndarray, np.ndarray, np.ndarray], Tuple[int, int]]: The little endian matrix representation of self as ((values, row indices, column pointers), shape).
+
+        Raises:
+            ValueError: CalculatorError.
+            RuntimeError: Could not construct the sparse superoperator matrix, e.g. because number_spins is smaller than the number of spins in self.
         """
 
     def from_json_struqture_1(self, input: Any) -> Any:  # type: ignore
@@ -1707,7 +1895,47 @@ class PauliLindbladOpenSystem(SystemType):
 
         Raises:
             ValueError: CalculatorError.
-            RuntimeError: Could not convert to complex superoperator matrix.
+            RuntimeError: Could not construct the sparse superoperator matrix, e.g. because number_spins is smaller than the number of spins in self.
+        """
+
+    def sparse_matrix_superoperator_csr(self, number_spins: int) -> Tuple[Tuple[numpy.ndarray, numpy.ndarray, numpy.ndarray], Tuple[int, int]]:  # type: ignore
+        """
+        Construct the sparse matrix representation of the superoperator in CSR representation.
+
+        The superoperator for the operator O is defined as the Matrix S so that
+        `flatten(-i [O, p]) = S flatten(p)` wher `[,]` is the commutator, `p` is a matrix
+        and `flatten` flattens a matrix into a vector in row-major form.
+        The result can be unpacked into scipy.sparse.csr_matrix(*result), which sets the shape.
+
+        Args:
+            number_spins (int): The number of spins to construct the matrix for.
+
+        Returns:
+            Tuple[Tuple[np.ndarray, np.ndarray, np.ndarray], Tuple[int, int]]: The little endian matrix representation of self as ((values, column indices, row pointers), shape).
+
+        Raises:
+            ValueError: CalculatorError.
+            RuntimeError: Could not construct the sparse superoperator matrix, e.g. because number_spins is smaller than the number of spins in self.
+        """
+
+    def sparse_matrix_superoperator_csc(self, number_spins: int) -> Tuple[Tuple[numpy.ndarray, numpy.ndarray, numpy.ndarray], Tuple[int, int]]:  # type: ignore
+        """
+        Construct the sparse matrix representation of the superoperator in CSC representation.
+
+        The superoperator for the operator O is defined as the Matrix S so that
+        `flatten(-i [O, p]) = S flatten(p)` wher `[,]` is the commutator, `p` is a matrix
+        and `flatten` flattens a matrix into a vector in row-major form.
+        The result can be unpacked into scipy.sparse.csc_matrix(*result), which sets the shape.
+
+        Args:
+            number_spins (int): The number of spins to construct the matrix for.
+
+        Returns:
+            Tuple[Tuple[np.ndarray, np.ndarray, np.ndarray], Tuple[int, int]]: The little endian matrix representation of self as ((values, row indices, column pointers), shape).
+
+        Raises:
+            ValueError: CalculatorError.
+            RuntimeError: Could not construct the sparse superoperator matrix, e.g. because number_spins is smaller than the number of spins in self.
         """
 
     def from_json_struqture_1(self, input: Any) -> Any:  # type: ignore
@@ -934,6 +934,27 @@ fn test_jordan_wigner() {
     });
 }
 
+/// Test that the CSR and CSC methods of PauliOperator fail for too few spins
+#[test]
+fn test_sparse_matrix_csr_csc() {
+    Python::initialize();
+    pyo3::Python::attach(|py| {
+        let ss = new_system(py);
+        ss.call_method1("add_operator_product", ("2X", 1.0))
+            .unwrap();
+        for method in [
+            "sparse_matrix_csr",
+            "sparse_matrix_csc",
+            "sparse_matrix_superoperator_csr",
+            "sparse_matrix_superoperator_csc",
+        ] {
+            let error = ss.call_method1(method, (1,)).unwrap_err();
+            assert!(error.to_string().contains("Could not construct the sparse"));
+            assert!(error.to_string().contains("GenericError"));
+        }
+    });
+}
+
 #[cfg(feature = "json_schema")]
 #[test]
 fn test_json_schema() {
@@ -10,13 +10,22 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{BosonOperator, BosonProduct, HermitianBosonProduct, ModeIndex, OperateOnBosons};
+use super::{
+    BosonCutoff, BosonFockSpace, BosonOperator, BosonProduct, HermitianBosonProduct, ModeIndex,
+    OperateOnBosons, ToBosonSparseMatrixOperator, ToBosonSparseMatrixSuperOperator,
+};
+use crate::mappings::boson_encodings::{hermitian_part, BosonSpinRegisters};
+use crate::sparse_matrix_assembly::{add_commutator_superoperator, operator_matrix};
 use crate::{
-    mappings::BosonToSpin, spins::PauliOperator, GetValue, OperateOnDensityMatrix, OperateOnModes,
-    OperateOnState, StruqtureError, SymmetricIndex,
+    mappings::{BosonSpinEncoding, BosonToSpin, BosonToSpinEncoding},
+    spins::{PauliHamiltonian, PauliOperator},
+    GetValue, OperateOnDensityMatrix, OperateOnModes, OperateOnState, StruqtureError,
+    SymmetricIndex,
 };
+use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt::{self, Write};
 use std::iter::{FromIterator, IntoIterator};
 use std::ops;
@@ -238,6 +247,34 @@ impl OperateOnModes<'_> for BosonHamiltonian {
 
 impl OperateOnBosons<'_> for BosonHamiltonian {}
 
+impl<'a> ToBosonSparseMatrixOperator<'a> for BosonHamiltonian {
+    // From trait
+    fn sparse_matrix(
+        &'a self,
+        number_modes: usize,
+        cutoff: impl Into<BosonCutoff>,
+    ) -> Result<HashMap<(usize, usize), Complex64>, StruqtureError> {
+        let space = BosonFockSpace::new(number_modes, cutoff.into(), self.current_number_modes())?;
+        let operator = BosonOperator::from(self.clone());
+        operator_matrix(operator.iter(), &space)
+    }
+}
+
+impl<'a> ToBosonSparseMatrixSuperOperator<'a> for BosonHamiltonian {
+    // From trait
+    fn sparse_matrix_superoperator(
+        &'a self,
+        number_modes: usize,
+        cutoff: impl Into<BosonCutoff>,
+    ) -> Result<HashMap<(usize, usize), Complex64>, StruqtureError> {
+        let space = BosonFockSpace::new(number_modes, cutoff.into(), self.current_number_modes())?;
+        let mut matrix: HashMap<(usize, usize), Complex64> = HashMap::new();
+        let operator = BosonOperator::from(self.clone());
+        add_commutator_superoperator(operator.iter(), &space, &mut matrix)?;
+        Ok(matrix)
+    }
+}
+
 /// Implements the default function (Default trait) of BosonHamiltonian (an empty BosonHamiltonian).
 ///
 impl Default for BosonHamiltonian {
@@ -304,6 +341,52 @@ impl BosonHamiltonian {
         }
         Ok(new_operator)
     }
+
+    /// Returns the commutator [self, other] = self * other - other * self.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The BosonHamiltonian to take the commutator with.
+    ///
+    /// # Returns
+    ///
+    /// * `BosonOperator` - The commutator of the two BosonHamiltonians.
+    pub fn commutator(&self, other: &BosonHamiltonian) -> BosonOperator {
+        BosonOperator::from(self.clone()).commutator(&BosonOperator::from(other.clone()))
+    }
+
+    /// Returns the anticommutator {self, other} = self * other + other * self.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The BosonHamiltonian to take the anticommutator with.
+    ///
+    /// # Returns
+    ///
+    /// * `BosonOperator` - The anticommutator of the two BosonHamiltonians.
+    pub fn anticommutator(&self, other: &BosonHamiltonian) -> BosonOperator {
+        BosonOperator::from(self.clone()).anticommutator(&BosonOperator::from(other.clone()))
+    }
+
+    /// Returns whether the BosonHamiltonian conserves the number of bosons.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether every term contains as many creators as annihilators.
+    pub fn conserves_particle_number(&self) -> bool {
+        self.keys()
+            .all(|product| product.number_creators() == product.number_annihilators())
+    }
+
+    /// Returns whether the BosonHamiltonian conserves the boson parity.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether every term contains an even number of creators and annihilators.
+    pub fn conserves_parity(&self) -> bool {
+        self.keys()
+            .all(|product| (product.number_creators() + product.number_annihilators()) % 2 == 0)
+    }
 }
 
 impl BosonToSpin for BosonHamiltonian {
@@ -324,6 +407,27 @@ impl BosonToSpin for BosonHamiltonian {
     }
 }
 
+impl BosonToSpinEncoding for BosonHamiltonian {
+    type Output = PauliHamiltonian;
+
+    // From trait
+    fn encoded_boson_spin_mapping(
+        &self,
+        encoding: BosonSpinEncoding,
+        number_modes: usize,
+        cutoff: impl Into<BosonCutoff>,
+    ) -> Result<Self::Output, StruqtureError> {
+        let registers = BosonSpinRegisters::new(
+            encoding,
+            number_modes,
+            cutoff.into(),
+            self.current_number_modes(),
+        )?;
+        let operator = BosonOperator::from(self.clone());
+        hermitian_part(&registers.encode_terms(operator.iter())?)
+    }
+}
+
 impl TryFrom<BosonOperator> for BosonHamiltonian {
     type Error = StruqtureError;
     /// Tries to convert a BosonOperator into a BosonHamiltonian.
@@ -10,10 +10,11 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::BosonIndex;
+use super::{BosonCutoff, BosonIndex};
+use crate::mappings::boson_encodings::{hermitian_part, BosonSpinRegisters};
 use crate::{
-    mappings::BosonToSpin,
-    spins::{PauliOperator, PauliProduct},
+    mappings::{BosonSpinEncoding, BosonToSpin, BosonToSpinEncoding},
+    spins::{PauliHamiltonian, PauliOperator, PauliProduct},
     CorrespondsTo, CreatorsAnnihilators, GetValue, ModeIndex, OperateOnDensityMatrix, SpinIndex,
     StruqtureError, SymmetricIndex,
 };
@@ -354,6 +355,26 @@ impl BosonToSpin for BosonProduct {
     }
 }
 
+impl BosonToSpinEncoding for BosonProduct {
+    type Output = PauliOperator;
+
+    // From trait
+    fn encoded_boson_spin_mapping(
+        &self,
+        encoding: BosonSpinEncoding,
+        number_modes: usize,
+        cutoff: impl Into<BosonCutoff>,
+    ) -> Result<Self::Output, StruqtureError> {
+        let registers = BosonSpinRegisters::new(
+            encoding,
+            number_modes,
+            cutoff.into(),
+            self.current_number_modes(),
+        )?;
+        registers.encode_product(self)
+    }
+}
+
 impl CorrespondsTo<BosonProduct> for BosonProduct {
     /// Gets the BosonProduct corresponding to self (here, itself).
     ///
@@ -991,6 +1012,32 @@ impl BosonToSpin for HermitianBosonProduct {
     }
 }
 
+impl BosonToSpinEncoding for HermitianBosonProduct {
+    type Output = PauliHamiltonian;
+
+    // From trait
+    fn encoded_boson_spin_mapping(
+        &self,
+        encoding: BosonSpinEncoding,
+        number_modes: usize,
+        cutoff: impl Into<BosonCutoff>,
+    ) -> Result<Self::Output, StruqtureError> {
+        let registers = BosonSpinRegisters::new(
+            encoding,
+            number_modes,
+            cutoff.into(),
+            self.current_number_modes(),
+        )?;
+        let product = BosonProduct::new(self.creators().copied(), self.annihilators().copied())?;
+        let mut encoded = registers.encode_product(&product)?;
+        if !self.is_natural_hermitian() {
+            let (conjugate, _) = product.hermitian_conjugate();
+            encoded = encoded + registers.encode_product(&conjugate)?;
+        }
+        hermitian_part(&encoded)
+    }
+}
+
 impl CorrespondsTo<HermitianBosonProduct> for HermitianBosonProduct {
     /// Gets the HermitianBosonProduct corresponding to self (here, itself).
     ///
@@ -10,16 +10,22 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{BosonProduct, OperateOnBosons};
+use super::{
+    BosonCutoff, BosonFockSpace, BosonProduct, OperateOnBosons, ToBosonSparseMatrixSuperOperator,
+};
+use crate::mappings::boson_encodings::BosonSpinRegisters;
+use crate::sparse_matrix_assembly::add_lindblad_superoperator;
 use crate::{
-    mappings::BosonToSpin,
-    spins::{DecoherenceProduct, PauliLindbladNoiseOperator},
+    mappings::{BosonSpinEncoding, BosonToSpin, BosonToSpinEncoding},
+    spins::{DecoherenceOperator, DecoherenceProduct, PauliLindbladNoiseOperator},
     ModeIndex, OperateOnDensityMatrix, OperateOnModes, StruqtureError,
 };
 use indexmap::map::{Entry, Iter};
 use indexmap::IndexMap;
+use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt::{self, Write};
 use std::iter::{FromIterator, IntoIterator};
 use std::ops;
@@ -218,6 +224,20 @@ impl<'a> OperateOnModes<'a> for BosonLindbladNoiseOperator {
 
 impl OperateOnBosons<'_> for BosonLindbladNoiseOperator {}
 
+impl<'a> ToBosonSparseMatrixSuperOperator<'a> for BosonLindbladNoiseOperator {
+    // From trait
+    fn sparse_matrix_superoperator(
+        &'a self,
+        number_modes: usize,
+        cutoff: impl Into<BosonCutoff>,
+    ) -> Result<HashMap<(usize, usize), Complex64>, StruqtureError> {
+        let space = BosonFockSpace::new(number_modes, cutoff.into(), self.current_number_modes())?;
+        let mut matrix: HashMap<(usize, usize), Complex64> = HashMap::new();
+        add_lindblad_superoperator(self.iter(), &space, &mut matrix)?;
+        Ok(matrix)
+    }
+}
+
 /// Implements the default function (Default trait) of BosonLindbladNoiseOperator (an empty BosonLindbladNoiseOperator).
 ///
 impl Default for BosonLindbladNoiseOperator {
@@ -316,6 +336,36 @@ impl BosonToSpin for BosonLindbladNoiseOperator {
     }
 }
 
+impl BosonToSpinEncoding for BosonLindbladNoiseOperator {
+    type Output = PauliLindbladNoiseOperator;
+
+    // From trait
+    fn encoded_boson_spin_mapping(
+        &self,
+        encoding: BosonSpinEncoding,
+        number_modes: usize,
+        cutoff: impl Into<BosonCutoff>,
+    ) -> Result<Self::Output, StruqtureError> {
+        let registers = BosonSpinRegisters::new(
+            encoding,
+            number_modes,
+            cutoff.into(),
+            self.current_number_modes(),
+        )?;
+        let mut noise = PauliLindbladNoiseOperator::new();
+        for ((left, right), rate) in self.iter() {
+            let left = DecoherenceOperator::from(registers.encode_product(left)?);
+            let right = DecoherenceOperator::from(registers.encode_product(right)?);
+            // Lindblad operators vanishing in the truncated Fock space do not contribute
+            if left.is_empty() || right.is_empty() {
+                continue;
+            }
+            noise.add_noise_from_full_operators(&left, &right, rate.clone())?;
+        }
+        Ok(noise)
+    }
+}
+
 /// Implements the negative sign function of BosonLindbladNoiseOperator.
 ///
 impl ops::Neg for BosonLindbladNoiseOperator {
@@ -10,10 +10,16 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{BosonHamiltonian, BosonLindbladNoiseOperator};
+use super::{
+    BosonCutoff, BosonFockSpace, BosonHamiltonian, BosonLindbladNoiseOperator, BosonOperator,
+    ToBosonSparseMatrixSuperOperator,
+};
+use crate::sparse_matrix_assembly::{add_commutator_superoperator, add_lindblad_superoperator};
 use crate::{OpenSystem, OperateOnDensityMatrix, OperateOnModes, StruqtureError};
+use num_complex::Complex64;
 use qoqo_calculator::CalculatorFloat;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt::{self, Write};
 use std::ops;
 
@@ -117,6 +123,22 @@ impl OperateOnModes<'_> for BosonLindbladOpenSystem {
     }
 }
 
+impl<'a> ToBosonSparseMatrixSuperOperator<'a> for BosonLindbladOpenSystem {
+    // From trait
+    fn sparse_matrix_superoperator(
+        &'a self,
+        number_modes: usize,
+        cutoff: impl Into<BosonCutoff>,
+    ) -> Result<HashMap<(usize, usize), Complex64>, StruqtureError> {
+        let space = BosonFockSpace::new(number_modes, cutoff.into(), self.current_number_modes())?;
+        let mut matrix: HashMap<(usize, usize), Complex64> = HashMap::new();
+        let operator = BosonOperator::from(self.system.clone());
+        add_commutator_superoperator(operator.iter(), &space, &mut matrix)?;
+        add_lindblad_superoperator(self.noise.iter(), &space, &mut matrix)?;
+        Ok(matrix)
+    }
+}
+
 /// Functions for the BosonLindbladOpenSystem
 ///
 impl BosonLindbladOpenSystem {
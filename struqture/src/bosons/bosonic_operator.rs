@@ -10,14 +10,23 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{BosonHamiltonian, OperateOnBosons};
+use super::{
+    BosonCutoff, BosonFockSpace, BosonHamiltonian, OperateOnBosons, ToBosonSparseMatrixOperator,
+    ToBosonSparseMatrixSuperOperator,
+};
 use crate::bosons::BosonProduct;
+use crate::mappings::boson_encodings::BosonSpinRegisters;
+use crate::mappings::{BosonSpinEncoding, BosonToSpinEncoding};
+use crate::sparse_matrix_assembly::{add_commutator_superoperator, operator_matrix};
+use crate::spins::PauliOperator;
 use crate::{
-    GetValue, ModeIndex, OperateOnDensityMatrix, OperateOnModes, OperateOnState, StruqtureError,
-    SymmetricIndex,
+    mode_products_reorder_freely, GetValue, ModeIndex, OperateOnDensityMatrix, OperateOnModes,
+    OperateOnState, StruqtureError, SymmetricIndex,
 };
+use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt::{self, Write};
 use std::iter::{FromIterator, IntoIterator};
 use std::ops;
@@ -201,6 +210,32 @@ impl<'a> OperateOnModes<'a> for BosonOperator {
 
 impl OperateOnBosons<'_> for BosonOperator {}
 
+impl<'a> ToBosonSparseMatrixOperator<'a> for BosonOperator {
+    // From trait
+    fn sparse_matrix(
+        &'a self,
+        number_modes: usize,
+        cutoff: impl Into<BosonCutoff>,
+    ) -> Result<HashMap<(usize, usize), Complex64>, StruqtureError> {
+        let space = BosonFockSpace::new(number_modes, cutoff.into(), self.current_number_modes())?;
+        operator_matrix(self.iter(), &space)
+    }
+}
+
+impl<'a> ToBosonSparseMatrixSuperOperator<'a> for BosonOperator {
+    // From trait
+    fn sparse_matrix_superoperator(
+        &'a self,
+        number_modes: usize,
+        cutoff: impl Into<BosonCutoff>,
+    ) -> Result<HashMap<(usize, usize), Complex64>, StruqtureError> {
+        let space = BosonFockSpace::new(number_modes, cutoff.into(), self.current_number_modes())?;
+        let mut matrix: HashMap<(usize, usize), Complex64> = HashMap::new();
+        add_commutator_superoperator(self.iter(), &space, &mut matrix)?;
+        Ok(matrix)
+    }
+}
+
 /// Implements the default function (Default trait) of BosonOperator (an empty BosonOperator).
 ///
 impl Default for BosonOperator {
@@ -265,6 +300,84 @@ impl BosonOperator {
         }
         Ok(new_operator)
     }
+
+    /// Returns the commutator [self, other] = self * other - other * self.
+    ///
+    /// Pairs of BosonProducts that can be reordered without contractions commute and are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The BosonOperator to take the commutator with.
+    ///
+    /// # Returns
+    ///
+    /// * `BosonOperator` - The commutator of the two BosonOperators.
+    pub fn commutator(&self, other: &BosonOperator) -> BosonOperator {
+        commutator_of_boson_products(self, other, false)
+    }
+
+    /// Returns the anticommutator {self, other} = self * other + other * self.
+    ///
+    /// Pairs of BosonProducts that can be reordered without contractions contribute twice their product.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The BosonOperator to take the anticommutator with.
+    ///
+    /// # Returns
+    ///
+    /// * `BosonOperator` - The anticommutator of the two BosonOperators.
+    pub fn anticommutator(&self, other: &BosonOperator) -> BosonOperator {
+        commutator_of_boson_products(self, other, true)
+    }
+}
+
+/// Builds the (anti-)commutator of two BosonOperators.
+///
+/// # Arguments
+///
+/// * `left` - The left-hand operator.
+/// * `right` - The right-hand operator.
+/// * `anticommutator` - Whether to build the anticommutator instead of the commutator.
+///
+/// # Returns
+///
+/// * `BosonOperator` - The resulting (anti-)commutator.
+///
+/// # Panics
+///
+/// * Internal error in add_operator_product.
+fn commutator_of_boson_products(
+    left: &BosonOperator,
+    right: &BosonOperator,
+    anticommutator: bool,
+) -> BosonOperator {
+    let reversed_sign = if anticommutator { 1.0 } else { -1.0 };
+    let mut op = BosonOperator::new();
+    for (bpl, vall) in left.iter() {
+        for (bpr, valr) in right.iter() {
+            let coefficient = vall.clone() * valr;
+            if mode_products_reorder_freely(bpl, bpr) {
+                if !anticommutator {
+                    continue;
+                }
+                for prod in bpl.clone() * bpr.clone() {
+                    op.add_operator_product(prod, coefficient.clone() * 2.0)
+                        .expect("Internal bug in add_operator_product");
+                }
+            } else {
+                for prod in bpl.clone() * bpr.clone() {
+                    op.add_operator_product(prod, coefficient.clone())
+                        .expect("Internal bug in add_operator_product");
+                }
+                for prod in bpr.clone() * bpl.clone() {
+                    op.add_operator_product(prod, coefficient.clone() * reversed_sign)
+                        .expect("Internal bug in add_operator_product");
+                }
+            }
+        }
+    }
+    op
 }
 
 impl From<BosonHamiltonian> for BosonOperator {
@@ -291,7 +404,10 @@ impl From<BosonHamiltonian> for BosonOperator {
             if !key.is_natural_hermitian() {
                 let bp_conj = bp.hermitian_conjugate();
                 internal
-                    .add_operator_product(BosonProduct::get_key(&bp_conj.0), value * bp_conj.1)
+                    .add_operator_product(
+                        BosonProduct::get_key(&bp_conj.0),
+                        value.conj() * bp_conj.1,
+                    )
                     .expect("Internal error in add_operator_product.");
             }
         }
@@ -524,6 +640,26 @@ impl fmt::Display for BosonOperator {
     }
 }
 
+impl BosonToSpinEncoding for BosonOperator {
+    type Output = PauliOperator;
+
+    // From trait
+    fn encoded_boson_spin_mapping(
+        &self,
+        encoding: BosonSpinEncoding,
+        number_modes: usize,
+        cutoff: impl Into<BosonCutoff>,
+    ) -> Result<Self::Output, StruqtureError> {
+        let registers = BosonSpinRegisters::new(
+            encoding,
+            number_modes,
+            cutoff.into(),
+            self.current_number_modes(),
+        )?;
+        registers.encode_terms(self.iter())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -0,0 +1,183 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::BosonProduct;
+use crate::sparse_matrix_assembly::{ProductBasis, ProductColumns};
+use crate::{ModeIndex, StruqtureError};
+use num_complex::Complex64;
+
+/// The maximal occupation of the bosonic modes in a truncated Fock space.
+///
+/// A mode with the cutoff `c` can be occupied by `0, 1, ..., c` bosons, so its local dimension is `c + 1`.
+///
+/// # Example
+/// ```
+/// use struqture::bosons::BosonCutoff;
+///
+/// assert_eq!(BosonCutoff::from(2), BosonCutoff::Uniform(2));
+/// assert_eq!(BosonCutoff::from(vec![1, 3]), BosonCutoff::PerMode(vec![1, 3]));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BosonCutoff {
+    /// The same maximal occupation for every mode.
+    Uniform(usize),
+    /// The maximal occupation of each mode, the length has to be the number of modes.
+    PerMode(Vec<usize>),
+}
+
+impl BosonCutoff {
+    /// Returns the maximal occupation of every mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of bosonic modes.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<usize>)` - The maximal occupation of each mode.
+    /// * `Err(StruqtureError::GenericError)` - The number of cutoffs does not match the number of modes.
+    pub(crate) fn per_mode(self, number_modes: usize) -> Result<Vec<usize>, StruqtureError> {
+        match self {
+            BosonCutoff::Uniform(cutoff) => Ok(vec![cutoff; number_modes]),
+            BosonCutoff::PerMode(cutoffs) => {
+                if cutoffs.len() != number_modes {
+                    return Err(StruqtureError::GenericError {
+                        msg: format!(
+                            "Number of cutoffs {} does not match the number of modes {number_modes}.",
+                            cutoffs.len()
+                        ),
+                    });
+                }
+                Ok(cutoffs)
+            }
+        }
+    }
+}
+
+impl From<usize> for BosonCutoff {
+    fn from(cutoff: usize) -> Self {
+        BosonCutoff::Uniform(cutoff)
+    }
+}
+
+impl From<Vec<usize>> for BosonCutoff {
+    fn from(cutoffs: Vec<usize>) -> Self {
+        BosonCutoff::PerMode(cutoffs)
+    }
+}
+
+/// A truncated bosonic Fock space.
+///
+/// The basis state with the occupations `n_0, n_1, ...` has the index `n_0 + (c_0 + 1) (n_1 + (c_1 + 1) (n_2 + ...))`,
+/// where `c_i` is the cutoff of mode `i`, so that the occupation of mode 0 changes fastest.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct BosonFockSpace {
+    cutoffs: Vec<usize>,
+    strides: Vec<usize>,
+    dimension: usize,
+}
+
+impl BosonFockSpace {
+    /// Creates the truncated Fock space for the matrices of an object.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of bosonic modes of the Fock space.
+    /// * `cutoff` - The maximal occupation of the modes.
+    /// * `current_number_modes` - The number of modes the object acts on.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The truncated Fock space.
+    /// * `Err(StruqtureError::GenericError)` - The number of modes is smaller than the number of modes the object acts on, the number of cutoffs does not match the number of modes or the dimension is too large.
+    pub(crate) fn new(
+        number_modes: usize,
+        cutoff: BosonCutoff,
+        current_number_modes: usize,
+    ) -> Result<Self, StruqtureError> {
+        if number_modes < current_number_modes {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "Cannot construct a Fock space matrix for {number_modes} modes of an object acting on {current_number_modes} modes."
+                ),
+            });
+        }
+        let cutoffs = cutoff.per_mode(number_modes)?;
+        let mut strides = Vec::with_capacity(number_modes);
+        let mut dimension: usize = 1;
+        for cutoff in cutoffs.iter() {
+            strides.push(dimension);
+            dimension = cutoff
+                .checked_add(1)
+                .and_then(|local_dimension| dimension.checked_mul(local_dimension))
+                .ok_or_else(|| StruqtureError::GenericError {
+                    msg: format!("Cannot construct the Fock space with the cutoffs {cutoffs:?}."),
+                })?;
+        }
+        Ok(BosonFockSpace {
+            cutoffs,
+            strides,
+            dimension,
+        })
+    }
+
+    /// Applies a BosonProduct to a basis state.
+    ///
+    /// # Arguments
+    ///
+    /// * `product` - The normal ordered product of creators and annihilators.
+    /// * `state` - The index of the basis state the product is applied to.
+    ///
+    /// # Returns
+    ///
+    /// * `Some((usize, f64))` - The index of the resulting basis state and the product of the √n factors.
+    /// * `None` - The product annihilates the basis state or leaves the truncated Fock space.
+    fn apply_boson_product(&self, product: &BosonProduct, state: usize) -> Option<(usize, f64)> {
+        let mut state = state;
+        // The product of the occupations is accumulated exactly before taking the square root
+        let mut value = 1.0;
+        for mode in product.annihilators() {
+            let occupation = (state / self.strides[*mode]) % (self.cutoffs[*mode] + 1);
+            if occupation == 0 {
+                return None;
+            }
+            value *= occupation as f64;
+            state -= self.strides[*mode];
+        }
+        for mode in product.creators() {
+            let occupation = (state / self.strides[*mode]) % (self.cutoffs[*mode] + 1);
+            if occupation == self.cutoffs[*mode] {
+                return None;
+            }
+            value *= (occupation + 1) as f64;
+            state += self.strides[*mode];
+        }
+        Some((state, value.sqrt()))
+    }
+}
+
+impl ProductBasis<BosonProduct> for BosonFockSpace {
+    // From trait
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    // From trait
+    fn product_columns(&self, product: &BosonProduct) -> Result<ProductColumns, StruqtureError> {
+        Ok((0..self.dimension)
+            .map(|state| {
+                self.apply_boson_product(product, state)
+                    .map(|(new_state, value)| (new_state, Complex64::new(value, 0.0)))
+            })
+            .collect())
+    }
+}
@@ -24,15 +24,24 @@ mod bosonic_indices;
 mod bosonic_noise_operator;
 mod bosonic_open_system;
 mod bosonic_operator;
+mod fock_space;
+use std::collections::HashMap;
 use std::str::FromStr;
 
 pub use bosonic_hamiltonian::BosonHamiltonian;
 pub use bosonic_noise_operator::BosonLindbladNoiseOperator;
 pub use bosonic_open_system::BosonLindbladOpenSystem;
 pub use bosonic_operator::BosonOperator;
+pub use fock_space::BosonCutoff;
+pub(crate) use fock_space::BosonFockSpace;
 
-use crate::{ModeIndex, OperateOnDensityMatrix, SymmetricIndex};
+use crate::sparse_matrix_assembly::hash_map_to_coo;
+use crate::{
+    CooSparseMatrix, ModeIndex, OperateOnDensityMatrix, OperateOnModes, StruqtureError,
+    SymmetricIndex,
+};
 pub use bosonic_indices::{BosonProduct, HermitianBosonProduct};
+use num_complex::Complex64;
 use qoqo_calculator::CalculatorComplex;
 
 /// Signal Trait for specifying that a type can be used a bosonic index.
@@ -88,3 +97,113 @@ where
     &'a Self: IntoIterator,
 {
 }
+
+/// Trait for constructing the matrix of bosonic operators in a truncated occupation-number basis.
+///
+/// The occupation of every mode is truncated at a cutoff given by [crate::bosons::BosonCutoff].
+/// Creators and annihilators act as `b^† |n> = √(n+1) |n+1>` and `b |n> = √n |n-1>`,
+/// where a creator acting on a mode occupied up to its cutoff gives zero.
+/// The basis state with the occupations `n_0, n_1, ...` has the index `n_0 + (c_0 + 1) (n_1 + (c_1 + 1) (n_2 + ...))`,
+/// where `c_i` is the cutoff of mode `i`.
+///
+/// # Example
+/// ```
+/// use struqture::prelude::*;
+/// use qoqo_calculator::CalculatorComplex;
+/// use struqture::bosons::{BosonOperator, BosonProduct, ToBosonSparseMatrixOperator};
+///
+/// let mut bo = BosonOperator::new();
+/// bo.set(BosonProduct::new([], [0]).unwrap(), CalculatorComplex::from(1.0)).unwrap();
+///
+/// // The annihilator of a mode with at most two bosons
+/// let matrix = bo.sparse_matrix(1, 2).unwrap();
+/// assert_eq!(matrix.get(&(1, 2)), Some(&2.0_f64.sqrt().into()));
+/// ```
+pub trait ToBosonSparseMatrixOperator<'a>: OperateOnModes<'a> {
+    /// Constructs the sparse matrix representation of Self in the truncated occupation-number basis as a HashMap.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of bosonic modes for which to construct the sparse matrix.
+    /// * `cutoff` - The maximal occupation of all modes or a vector with the maximal occupation of each mode.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HashMap<(usize, usize), Complex64>)` - The matrix of Self in the truncated Fock space.
+    /// * `Err(StruqtureError::GenericError)` - The number of modes is too small for Self, does not match the number of cutoffs or the dimension is too large.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn sparse_matrix(
+        &'a self,
+        number_modes: usize,
+        cutoff: impl Into<BosonCutoff>,
+    ) -> Result<HashMap<(usize, usize), Complex64>, StruqtureError>;
+
+    /// Constructs the sparse matrix representation of Self in the truncated occupation-number basis in COO representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of bosonic modes for which to construct the sparse matrix.
+    /// * `cutoff` - The maximal occupation of all modes or a vector with the maximal occupation of each mode.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Vec<Complex64>, (Vec<usize>, Vec<usize>)))` - The matrix sorted by rows and columns.
+    /// * `Err(StruqtureError::GenericError)` - The number of modes is too small for Self, does not match the number of cutoffs or the dimension is too large.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn sparse_matrix_coo(
+        &'a self,
+        number_modes: usize,
+        cutoff: impl Into<BosonCutoff>,
+    ) -> Result<CooSparseMatrix, StruqtureError> {
+        Ok(hash_map_to_coo(self.sparse_matrix(number_modes, cutoff)?))
+    }
+}
+
+/// Trait for constructing the Lindblad superoperator of bosonic objects in a truncated occupation-number basis.
+///
+/// The superoperator for the operator O is defined as the Matrix S so that
+/// `flatten(-i [O, p]) = S flatten(p)` where `[,]` is the commutator, `p` is a matrix
+/// and `flatten` flattens a matrix into a vector in row-major form.
+/// Noise terms with the rate γ and the Lindblad operators (L, R) contribute `γ (L p R^† - 1/2 {R^† L, p})`.
+/// The operators are truncated as described for [crate::bosons::ToBosonSparseMatrixOperator] before the products are formed.
+pub trait ToBosonSparseMatrixSuperOperator<'a>: OperateOnModes<'a> {
+    /// Constructs the sparse matrix representation of the superoperator in the truncated occupation-number basis as a HashMap.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of bosonic modes for which to construct the sparse matrix.
+    /// * `cutoff` - The maximal occupation of all modes or a vector with the maximal occupation of each mode.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HashMap<(usize, usize), Complex64>)` - The superoperator acting on the row-major flattened density matrix.
+    /// * `Err(StruqtureError::GenericError)` - The number of modes is too small for Self, does not match the number of cutoffs or the dimension is too large.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn sparse_matrix_superoperator(
+        &'a self,
+        number_modes: usize,
+        cutoff: impl Into<BosonCutoff>,
+    ) -> Result<HashMap<(usize, usize), Complex64>, StruqtureError>;
+
+    /// Constructs the sparse matrix representation of the superoperator in the truncated occupation-number basis in COO representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of bosonic modes for which to construct the sparse matrix.
+    /// * `cutoff` - The maximal occupation of all modes or a vector with the maximal occupation of each mode.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Vec<Complex64>, (Vec<usize>, Vec<usize>)))` - The superoperator sorted by rows and columns.
+    /// * `Err(StruqtureError::GenericError)` - The number of modes is too small for Self, does not match the number of cutoffs or the dimension is too large.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn sparse_matrix_superoperator_coo(
+        &'a self,
+        number_modes: usize,
+        cutoff: impl Into<BosonCutoff>,
+    ) -> Result<CooSparseMatrix, StruqtureError> {
+        Ok(hash_map_to_coo(
+            self.sparse_matrix_superoperator(number_modes, cutoff)?,
+        ))
+    }
+}
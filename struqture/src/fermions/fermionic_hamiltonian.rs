@@ -11,16 +11,25 @@
 // limitations under the License.
 
 use super::{
-    FermionOperator, FermionProduct, HermitianFermionProduct, ModeIndex, OperateOnFermions,
+    fermionic_sz_change, FermionOperator, FermionProduct, FockSpace, HermitianFermionProduct,
+    ModeIndex, OperateOnFermions, ToFermionSparseMatrixOperator,
+    ToFermionSparseMatrixSuperOperator,
 };
-use crate::mappings::JordanWignerFermionToSpin;
-use crate::spins::PauliHamiltonian;
+use crate::mappings::bravyi_kitaev::{bravyi_kitaev_gates, conjugate_with_controlled_nots};
+use crate::mappings::parity::{parity_gates, two_qubit_reduced_operator};
+use crate::mappings::{
+    hermitian_terms, BravyiKitaevFermionToSpin, JordanWignerFermionToSpin, ParityFermionToSpin,
+};
+use crate::sparse_matrix_assembly::{add_commutator_superoperator, operator_matrix};
+use crate::spins::{PauliHamiltonian, PauliOperator};
 use crate::{
     GetValue, OperateOnDensityMatrix, OperateOnModes, OperateOnState, StruqtureError,
     SymmetricIndex,
 };
+use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt::{self, Write};
 use std::iter::{FromIterator, IntoIterator};
 use std::ops;
@@ -246,6 +255,34 @@ impl OperateOnModes<'_> for FermionHamiltonian {
 
 impl OperateOnFermions<'_> for FermionHamiltonian {}
 
+impl<'a> ToFermionSparseMatrixOperator<'a> for FermionHamiltonian {
+    // From trait
+    fn sparse_matrix(
+        &'a self,
+        number_modes: usize,
+        number_particles: Option<usize>,
+    ) -> Result<HashMap<(usize, usize), Complex64>, StruqtureError> {
+        let operator = FermionOperator::from(self.clone());
+        let space = FockSpace::new(number_modes, number_particles, self.current_number_modes())?;
+        operator_matrix(operator.iter(), &space)
+    }
+}
+
+impl<'a> ToFermionSparseMatrixSuperOperator<'a> for FermionHamiltonian {
+    // From trait
+    fn sparse_matrix_superoperator(
+        &'a self,
+        number_modes: usize,
+        number_particles: Option<usize>,
+    ) -> Result<HashMap<(usize, usize), Complex64>, StruqtureError> {
+        let operator = FermionOperator::from(self.clone());
+        let space = FockSpace::new(number_modes, number_particles, self.current_number_modes())?;
+        let mut matrix: HashMap<(usize, usize), Complex64> = HashMap::new();
+        add_commutator_superoperator(operator.iter(), &space, &mut matrix)?;
+        Ok(matrix)
+    }
+}
+
 /// Implements the default function (Default trait) of FermionHamiltonian (an empty FermionHamiltonian).
 ///
 impl Default for FermionHamiltonian {
@@ -337,6 +374,97 @@ impl FermionHamiltonian {
         }
         Ok(new_operator)
     }
+
+    /// Returns the commutator [self, other] = self * other - other * self.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The FermionHamiltonian to take the commutator with.
+    ///
+    /// # Returns
+    ///
+    /// * `FermionOperator` - The commutator of the two FermionHamiltonians.
+    pub fn commutator(&self, other: &FermionHamiltonian) -> FermionOperator {
+        FermionOperator::from(self.clone()).commutator(&FermionOperator::from(other.clone()))
+    }
+
+    /// Returns the anticommutator {self, other} = self * other + other * self.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The FermionHamiltonian to take the anticommutator with.
+    ///
+    /// # Returns
+    ///
+    /// * `FermionOperator` - The anticommutator of the two FermionHamiltonians.
+    pub fn anticommutator(&self, other: &FermionHamiltonian) -> FermionOperator {
+        FermionOperator::from(self.clone()).anticommutator(&FermionOperator::from(other.clone()))
+    }
+
+    /// Returns whether the FermionHamiltonian conserves the number of fermions.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether every term contains as many creators as annihilators.
+    pub fn conserves_particle_number(&self) -> bool {
+        self.keys()
+            .all(|product| product.number_creators() == product.number_annihilators())
+    }
+
+    /// Returns whether the FermionHamiltonian conserves the fermion parity.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether every term contains an even number of creators and annihilators.
+    pub fn conserves_parity(&self) -> bool {
+        self.keys()
+            .all(|product| (product.number_creators() + product.number_annihilators()) % 2 == 0)
+    }
+
+    /// Returns whether the FermionHamiltonian conserves the total Sz of the fermions.
+    ///
+    /// The modes follow the interleaved spin-orbital convention: the mode `2k` is the spin-up
+    /// and the mode `2k + 1` the spin-down spin-orbital of the orbital `k`.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether every term creates as many spin-up minus spin-down fermions as it annihilates.
+    pub fn conserves_fermionic_sz(&self) -> bool {
+        self.keys().all(|product| fermionic_sz_change(product) == 0)
+    }
+
+    /// Returns the particle-hole transformation of the FermionHamiltonian on the given modes.
+    ///
+    /// On every transformed mode the creator and annihilator are swapped, c_i^{dagger} <-> c_i,
+    /// and the products are brought back into normal order. The anticommutation relations
+    /// give the constant and lower order shifts of the transformed terms.
+    ///
+    /// # Arguments
+    ///
+    /// * `modes` - The modes on which the particles and holes are exchanged.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(FermionHamiltonian)` - The normal ordered FermionHamiltonian after the transformation.
+    /// * `Err(StruqtureError::NonHermitianOperator)` - The imaginary part of the coefficient of a naturally hermitian product does not cancel.
+    pub fn particle_hole_transformation(
+        &self,
+        modes: &[usize],
+    ) -> Result<FermionHamiltonian, StruqtureError> {
+        let transformed = FermionOperator::from(self.clone()).particle_hole_transformation(modes);
+        let terms = hermitian_terms(transformed, |product| {
+            HermitianFermionProduct::new(
+                product.creators().copied(),
+                product.annihilators().copied(),
+            )
+            .ok()
+        })?;
+        let mut hamiltonian = FermionHamiltonian::with_capacity(terms.len());
+        for (hermitian_product, value) in terms {
+            hamiltonian.add_operator_product(hermitian_product, value)?;
+        }
+        Ok(hamiltonian)
+    }
 }
 
 impl TryFrom<FermionOperator> for FermionHamiltonian {
@@ -672,6 +800,62 @@ impl JordanWignerFermionToSpin for FermionHamiltonian {
     }
 }
 
+impl BravyiKitaevFermionToSpin for FermionHamiltonian {
+    type Output = PauliHamiltonian;
+
+    /// Implements BravyiKitaevFermionToSpin for a FermionHamiltonian.
+    ///
+    /// The convention used is that |0> represents an even and |1> an odd parity of the modes
+    /// stored in a qubit.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of fermionic modes (and spins) of the mapping.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PauliHamiltonian)` - The spin operator that results from the transformation.
+    /// * `Err(StruqtureError::GenericError)` - The FermionHamiltonian acts on more than `number_modes` modes.
+    fn bravyi_kitaev(&self, number_modes: usize) -> Result<Self::Output, StruqtureError> {
+        let gates = bravyi_kitaev_gates(number_modes, self.current_number_modes())?;
+        conjugate_with_controlled_nots(self.jordan_wigner(), &gates)
+    }
+}
+
+impl ParityFermionToSpin for FermionHamiltonian {
+    type Output = PauliHamiltonian;
+
+    /// Implements ParityFermionToSpin for a FermionHamiltonian.
+    ///
+    /// The convention used is that |0> represents an even and |1> an odd parity of the modes
+    /// up to a qubit.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of fermionic modes (and spins before the reduction) of the mapping.
+    /// * `particle_numbers` - The numbers of spin-up and spin-down fermions for the two-qubit reduction, no reduction if None.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PauliHamiltonian)` - The spin operator that results from the transformation.
+    /// * `Err(StruqtureError::GenericError)` - The FermionHamiltonian acts on more than `number_modes` modes.
+    /// * `Err(StruqtureError::GenericError)` - The two-qubit reduction is not possible.
+    fn parity_mapping(
+        &self,
+        number_modes: usize,
+        particle_numbers: Option<(usize, usize)>,
+    ) -> Result<Self::Output, StruqtureError> {
+        let gates = parity_gates(number_modes, self.current_number_modes())?;
+        let hamiltonian = conjugate_with_controlled_nots(self.jordan_wigner(), &gates)?;
+        // The reduction only changes the signs of the real coefficients
+        PauliHamiltonian::try_from(two_qubit_reduced_operator(
+            PauliOperator::from(hamiltonian),
+            number_modes,
+            particle_numbers,
+        )?)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
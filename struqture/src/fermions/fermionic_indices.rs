@@ -11,7 +11,9 @@
 // limitations under the License.
 
 use super::FermionIndex;
-use crate::mappings::JordanWignerFermionToSpin;
+use crate::mappings::bravyi_kitaev::{bravyi_kitaev_gates, conjugate_with_controlled_nots};
+use crate::mappings::parity::{parity_gates, two_qubit_reduced_operator};
+use crate::mappings::{BravyiKitaevFermionToSpin, JordanWignerFermionToSpin, ParityFermionToSpin};
 use crate::prelude::*;
 use crate::spins::{PauliHamiltonian, PauliOperator, PauliProduct, SinglePauliOperator};
 use crate::{
@@ -1408,6 +1410,62 @@ impl JordanWignerFermionToSpin for FermionProduct {
     }
 }
 
+impl BravyiKitaevFermionToSpin for FermionProduct {
+    type Output = PauliOperator;
+
+    /// Implements BravyiKitaevFermionToSpin for a FermionProduct.
+    ///
+    /// The convention used is that |0> represents an even and |1> an odd parity of the modes
+    /// stored in a qubit.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of fermionic modes (and spins) of the mapping.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PauliOperator)` - The spin operator that results from the transformation.
+    /// * `Err(StruqtureError::GenericError)` - The FermionProduct acts on more than `number_modes` modes.
+    fn bravyi_kitaev(&self, number_modes: usize) -> Result<Self::Output, StruqtureError> {
+        let gates = bravyi_kitaev_gates(number_modes, self.current_number_modes())?;
+        self.jordan_wigner()
+            .conjugate_with_clifford_sequence(&gates)
+    }
+}
+
+impl ParityFermionToSpin for FermionProduct {
+    type Output = PauliOperator;
+
+    /// Implements ParityFermionToSpin for a FermionProduct.
+    ///
+    /// The convention used is that |0> represents an even and |1> an odd parity of the modes
+    /// up to a qubit.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of fermionic modes (and spins before the reduction) of the mapping.
+    /// * `particle_numbers` - The numbers of spin-up and spin-down fermions for the two-qubit reduction, no reduction if None.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PauliOperator)` - The spin operator that results from the transformation.
+    /// * `Err(StruqtureError::GenericError)` - The FermionProduct acts on more than `number_modes` modes.
+    /// * `Err(StruqtureError::GenericError)` - The two-qubit reduction is not possible.
+    fn parity_mapping(
+        &self,
+        number_modes: usize,
+        particle_numbers: Option<(usize, usize)>,
+    ) -> Result<Self::Output, StruqtureError> {
+        let gates = parity_gates(number_modes, self.current_number_modes())?;
+        two_qubit_reduced_operator(
+            self.jordan_wigner()
+                .conjugate_with_clifford_sequence(&gates)?,
+            number_modes,
+            particle_numbers,
+        )
+    }
+}
+
 impl JordanWignerFermionToSpin for HermitianFermionProduct {
     type Output = PauliHamiltonian;
 
@@ -1479,6 +1537,62 @@ transform is hermitian.",
     }
 }
 
+impl BravyiKitaevFermionToSpin for HermitianFermionProduct {
+    type Output = PauliHamiltonian;
+
+    /// Implements BravyiKitaevFermionToSpin for a HermitianFermionProduct.
+    ///
+    /// The convention used is that |0> represents an even and |1> an odd parity of the modes
+    /// stored in a qubit.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of fermionic modes (and spins) of the mapping.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PauliHamiltonian)` - The spin operator that results from the transformation.
+    /// * `Err(StruqtureError::GenericError)` - The HermitianFermionProduct acts on more than `number_modes` modes.
+    fn bravyi_kitaev(&self, number_modes: usize) -> Result<Self::Output, StruqtureError> {
+        let gates = bravyi_kitaev_gates(number_modes, self.current_number_modes())?;
+        conjugate_with_controlled_nots(self.jordan_wigner(), &gates)
+    }
+}
+
+impl ParityFermionToSpin for HermitianFermionProduct {
+    type Output = PauliHamiltonian;
+
+    /// Implements ParityFermionToSpin for a HermitianFermionProduct.
+    ///
+    /// The convention used is that |0> represents an even and |1> an odd parity of the modes
+    /// up to a qubit.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of fermionic modes (and spins before the reduction) of the mapping.
+    /// * `particle_numbers` - The numbers of spin-up and spin-down fermions for the two-qubit reduction, no reduction if None.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PauliHamiltonian)` - The spin operator that results from the transformation.
+    /// * `Err(StruqtureError::GenericError)` - The HermitianFermionProduct acts on more than `number_modes` modes.
+    /// * `Err(StruqtureError::GenericError)` - The two-qubit reduction is not possible.
+    fn parity_mapping(
+        &self,
+        number_modes: usize,
+        particle_numbers: Option<(usize, usize)>,
+    ) -> Result<Self::Output, StruqtureError> {
+        let gates = parity_gates(number_modes, self.current_number_modes())?;
+        let hamiltonian = conjugate_with_controlled_nots(self.jordan_wigner(), &gates)?;
+        // The reduction only changes the signs of the real coefficients
+        PauliHamiltonian::try_from(two_qubit_reduced_operator(
+            PauliOperator::from(hamiltonian),
+            number_modes,
+            particle_numbers,
+        )?)
+    }
+}
+
 fn _lowering_operator(i: &usize) -> PauliOperator {
     let mut out = PauliOperator::new();
     out.add_operator_product(PauliProduct::new().x(*i), CalculatorComplex::new(0.5, 0.0))
@@ -10,13 +10,24 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{FermionOperator, FermionProduct, OperateOnFermions};
-use crate::mappings::JordanWignerFermionToSpin;
+use super::fermionic_operator::particle_hole_product;
+use super::{
+    FermionOperator, FermionProduct, FockSpace, OperateOnFermions,
+    ToFermionSparseMatrixSuperOperator,
+};
+use crate::mappings::bravyi_kitaev::bravyi_kitaev_gates;
+use crate::mappings::parity::{parity_gates, two_qubit_reduced_noise};
+use crate::mappings::{
+    BravyiKitaevFermionToSpin, IdentityNoiseTerms, JordanWignerFermionToSpin, ParityFermionToSpin,
+};
+use crate::sparse_matrix_assembly::add_lindblad_superoperator;
 use crate::spins::{DecoherenceOperator, PauliLindbladNoiseOperator};
 use crate::{ModeIndex, OperateOnDensityMatrix, OperateOnModes, StruqtureError};
 use itertools::Itertools;
+use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt::{self, Write};
 use std::iter::{FromIterator, IntoIterator};
 use std::ops;
@@ -226,6 +237,20 @@ impl<'a> OperateOnModes<'a> for FermionLindbladNoiseOperator {
 
 impl OperateOnFermions<'_> for FermionLindbladNoiseOperator {}
 
+impl<'a> ToFermionSparseMatrixSuperOperator<'a> for FermionLindbladNoiseOperator {
+    // From trait
+    fn sparse_matrix_superoperator(
+        &'a self,
+        number_modes: usize,
+        number_particles: Option<usize>,
+    ) -> Result<HashMap<(usize, usize), Complex64>, StruqtureError> {
+        let space = FockSpace::new(number_modes, number_particles, self.current_number_modes())?;
+        let mut matrix: HashMap<(usize, usize), Complex64> = HashMap::new();
+        add_lindblad_superoperator(self.iter(), &space, &mut matrix)?;
+        Ok(matrix)
+    }
+}
+
 /// Implements the default function (Default trait) of FermionLindbladNoiseOperator (an empty FermionLindbladNoiseOperator).
 ///
 impl Default for FermionLindbladNoiseOperator {
@@ -336,6 +361,52 @@ impl FermionLindbladNoiseOperator {
         }
         Ok(new_operator)
     }
+
+    /// Returns the particle-hole transformation of the FermionLindbladNoiseOperator on the given modes.
+    ///
+    /// The left and right Lindblad operators are transformed as FermionOperators, see
+    /// [crate::fermions::FermionOperator::particle_hole_transformation]. The terms with an identity
+    /// on one side are dropped, which is only possible if they have no coherent part.
+    ///
+    /// # Arguments
+    ///
+    /// * `modes` - The modes on which the particles and holes are exchanged.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(FermionLindbladNoiseOperator)` - The FermionLindbladNoiseOperator after the transformation.
+    /// * `Err(StruqtureError::GenericError)` - The transformed terms with an identity on one side have a coherent part.
+    /// * `Err(StruqtureError)` - Error adding an operator product.
+    pub fn particle_hole_transformation(
+        &self,
+        modes: &[usize],
+    ) -> Result<FermionLindbladNoiseOperator, StruqtureError> {
+        let is_identity = |product: &FermionProduct| {
+            product.number_creators() + product.number_annihilators() == 0
+        };
+        let mut transformed = FermionLindbladNoiseOperator::with_capacity(self.len());
+        let mut identity_terms = IdentityNoiseTerms::new();
+        for ((left, right), rate) in self.iter() {
+            let left_operator = particle_hole_product(left, modes);
+            let right_operator = particle_hole_product(right, modes);
+            for ((left_product, left_value), (right_product, right_value)) in
+                left_operator.iter().cartesian_product(&right_operator)
+            {
+                let value = right_value.conj() * left_value * rate;
+                match (is_identity(left_product), is_identity(right_product)) {
+                    (true, true) => {}
+                    (true, false) => identity_terms.add_left_identity(right_product, &value),
+                    (false, true) => identity_terms.add_right_identity(left_product, &value),
+                    (false, false) => transformed.add_operator_product(
+                        (left_product.clone(), right_product.clone()),
+                        value,
+                    )?,
+                }
+            }
+        }
+        identity_terms.check_dropped()?;
+        Ok(transformed)
+    }
 }
 
 /// Implements the negative sign function of FermionLindbladNoiseOperator.
@@ -588,6 +659,63 @@ impl JordanWignerFermionToSpin for FermionLindbladNoiseOperator {
     }
 }
 
+impl BravyiKitaevFermionToSpin for FermionLindbladNoiseOperator {
+    type Output = PauliLindbladNoiseOperator;
+
+    /// Implements BravyiKitaevFermionToSpin for a FermionLindbladNoiseOperator.
+    ///
+    /// The convention used is that |0> represents an even and |1> an odd parity of the modes
+    /// stored in a qubit.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of fermionic modes (and spins) of the mapping.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PauliLindbladNoiseOperator)` - The spin noise operator that results from the transformation.
+    /// * `Err(StruqtureError::GenericError)` - The FermionLindbladNoiseOperator acts on more than `number_modes` modes.
+    fn bravyi_kitaev(&self, number_modes: usize) -> Result<Self::Output, StruqtureError> {
+        let gates = bravyi_kitaev_gates(number_modes, self.current_number_modes())?;
+        self.jordan_wigner()
+            .conjugate_with_clifford_sequence(&gates)
+    }
+}
+
+impl ParityFermionToSpin for FermionLindbladNoiseOperator {
+    type Output = PauliLindbladNoiseOperator;
+
+    /// Implements ParityFermionToSpin for a FermionLindbladNoiseOperator.
+    ///
+    /// The convention used is that |0> represents an even and |1> an odd parity of the modes
+    /// up to a qubit.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of fermionic modes (and spins before the reduction) of the mapping.
+    /// * `particle_numbers` - The numbers of spin-up and spin-down fermions for the two-qubit reduction, no reduction if None.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PauliLindbladNoiseOperator)` - The spin noise operator that results from the transformation.
+    /// * `Err(StruqtureError::GenericError)` - The FermionLindbladNoiseOperator acts on more than `number_modes` modes.
+    /// * `Err(StruqtureError::GenericError)` - The two-qubit reduction is not possible.
+    /// * `Err(StruqtureError::GenericError)` - The reduced noise terms with an identity on one side have a coherent part.
+    fn parity_mapping(
+        &self,
+        number_modes: usize,
+        particle_numbers: Option<(usize, usize)>,
+    ) -> Result<Self::Output, StruqtureError> {
+        let gates = parity_gates(number_modes, self.current_number_modes())?;
+        two_qubit_reduced_noise(
+            self.jordan_wigner()
+                .conjugate_with_clifford_sequence(&gates)?,
+            number_modes,
+            particle_numbers,
+        )
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -10,12 +10,18 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{FermionHamiltonian, FermionLindbladNoiseOperator};
-use crate::mappings::JordanWignerFermionToSpin;
+use super::{
+    FermionHamiltonian, FermionLindbladNoiseOperator, FermionOperator, FockSpace,
+    ToFermionSparseMatrixSuperOperator,
+};
+use crate::mappings::{BravyiKitaevFermionToSpin, JordanWignerFermionToSpin, ParityFermionToSpin};
+use crate::sparse_matrix_assembly::{add_commutator_superoperator, add_lindblad_superoperator};
 use crate::spins::PauliLindbladOpenSystem;
 use crate::{OpenSystem, OperateOnDensityMatrix, OperateOnModes, StruqtureError};
+use num_complex::Complex64;
 use qoqo_calculator::CalculatorFloat;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt::{self, Write};
 use std::ops;
 
@@ -118,6 +124,22 @@ impl OperateOnModes<'_> for FermionLindbladOpenSystem {
     }
 }
 
+impl<'a> ToFermionSparseMatrixSuperOperator<'a> for FermionLindbladOpenSystem {
+    // From trait
+    fn sparse_matrix_superoperator(
+        &'a self,
+        number_modes: usize,
+        number_particles: Option<usize>,
+    ) -> Result<HashMap<(usize, usize), Complex64>, StruqtureError> {
+        let space = FockSpace::new(number_modes, number_particles, self.current_number_modes())?;
+        let operator = FermionOperator::from(self.system.clone());
+        let mut matrix: HashMap<(usize, usize), Complex64> = HashMap::new();
+        add_commutator_superoperator(operator.iter(), &space, &mut matrix)?;
+        add_lindblad_superoperator(self.noise.iter(), &space, &mut matrix)?;
+        Ok(matrix)
+    }
+}
+
 /// Functions for the FermionLindbladOpenSystem
 ///
 impl FermionLindbladOpenSystem {
@@ -158,6 +180,27 @@ impl FermionLindbladOpenSystem {
         let new_noise = FermionLindbladNoiseOperator::from_struqture_1(&noise_one)?;
         Self::group(new_system, new_noise)
     }
+
+    /// Returns the particle-hole transformation of the FermionLindbladOpenSystem on the given modes.
+    ///
+    /// # Arguments
+    ///
+    /// * `modes` - The modes on which the particles and holes are exchanged.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(FermionLindbladOpenSystem)` - The FermionLindbladOpenSystem after the transformation.
+    /// * `Err(StruqtureError::GenericError)` - The transformed noise terms with an identity on one side have a coherent part.
+    /// * `Err(StruqtureError)` - Error adding an operator product.
+    pub fn particle_hole_transformation(
+        &self,
+        modes: &[usize],
+    ) -> Result<FermionLindbladOpenSystem, StruqtureError> {
+        Self::group(
+            self.system().particle_hole_transformation(modes)?,
+            self.noise().particle_hole_transformation(modes)?,
+        )
+    }
 }
 
 /// Implements the negative sign function of FermionLindbladOpenSystem.
@@ -287,3 +330,60 @@ impl JordanWignerFermionToSpin for FermionLindbladOpenSystem {
             .expect("Internal bug in jordan_wigner() for FermionHamiltonian or FermionLindbladNoiseOperator.")
     }
 }
+
+impl BravyiKitaevFermionToSpin for FermionLindbladOpenSystem {
+    type Output = PauliLindbladOpenSystem;
+
+    /// Implements BravyiKitaevFermionToSpin for a FermionLindbladOpenSystem.
+    ///
+    /// The convention used is that |0> represents an even and |1> an odd parity of the modes
+    /// stored in a qubit.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of fermionic modes (and spins) of the mapping.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PauliLindbladOpenSystem)` - The spin open system that results from the transformation.
+    /// * `Err(StruqtureError::GenericError)` - The FermionLindbladOpenSystem acts on more than `number_modes` modes.
+    fn bravyi_kitaev(&self, number_modes: usize) -> Result<Self::Output, StruqtureError> {
+        PauliLindbladOpenSystem::group(
+            self.system().bravyi_kitaev(number_modes)?,
+            self.noise().bravyi_kitaev(number_modes)?,
+        )
+    }
+}
+
+impl ParityFermionToSpin for FermionLindbladOpenSystem {
+    type Output = PauliLindbladOpenSystem;
+
+    /// Implements ParityFermionToSpin for a FermionLindbladOpenSystem.
+    ///
+    /// The convention used is that |0> represents an even and |1> an odd parity of the modes
+    /// up to a qubit.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of fermionic modes (and spins before the reduction) of the mapping.
+    /// * `particle_numbers` - The numbers of spin-up and spin-down fermions for the two-qubit reduction, no reduction if None.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PauliLindbladOpenSystem)` - The spin open system that results from the transformation.
+    /// * `Err(StruqtureError::GenericError)` - The FermionLindbladOpenSystem acts on more than `number_modes` modes.
+    /// * `Err(StruqtureError::GenericError)` - The two-qubit reduction is not possible.
+    /// * `Err(StruqtureError::GenericError)` - The reduced noise terms with an identity on one side have a coherent part.
+    fn parity_mapping(
+        &self,
+        number_modes: usize,
+        particle_numbers: Option<(usize, usize)>,
+    ) -> Result<Self::Output, StruqtureError> {
+        PauliLindbladOpenSystem::group(
+            self.system()
+                .parity_mapping(number_modes, particle_numbers)?,
+            self.noise()
+                .parity_mapping(number_modes, particle_numbers)?,
+        )
+    }
+}
@@ -10,17 +10,26 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{FermionHamiltonian, OperateOnFermions};
+use super::{
+    FermionHamiltonian, FockSpace, OperateOnFermions, ToFermionSparseMatrixOperator,
+    ToFermionSparseMatrixSuperOperator,
+};
 use crate::fermions::FermionProduct;
-use crate::mappings::JordanWignerFermionToSpin;
+use crate::mappings::bravyi_kitaev::bravyi_kitaev_gates;
+use crate::mappings::parity::{parity_gates, two_qubit_reduced_operator};
+use crate::mappings::{BravyiKitaevFermionToSpin, JordanWignerFermionToSpin, ParityFermionToSpin};
+use crate::parallel_iteration::multiply_terms;
+use crate::sparse_matrix_assembly::{add_commutator_superoperator, operator_matrix};
 use crate::spins::PauliOperator;
 use crate::{
-    GetValue, ModeIndex, OperateOnDensityMatrix, OperateOnModes, OperateOnState, StruqtureError,
-    SymmetricIndex,
+    mode_products_reorder_freely, GetValue, ModeIndex, OperateOnDensityMatrix, OperateOnModes,
+    OperateOnState, StruqtureError, SymmetricIndex,
 };
 // use itertools::Itertools;
+use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt::{self, Write};
 use std::iter::{FromIterator, IntoIterator};
 use std::ops;
@@ -205,6 +214,32 @@ impl<'a> OperateOnModes<'a> for FermionOperator {
 
 impl OperateOnFermions<'_> for FermionOperator {}
 
+impl<'a> ToFermionSparseMatrixOperator<'a> for FermionOperator {
+    // From trait
+    fn sparse_matrix(
+        &'a self,
+        number_modes: usize,
+        number_particles: Option<usize>,
+    ) -> Result<HashMap<(usize, usize), Complex64>, StruqtureError> {
+        let space = FockSpace::new(number_modes, number_particles, self.current_number_modes())?;
+        operator_matrix(self.iter(), &space)
+    }
+}
+
+impl<'a> ToFermionSparseMatrixSuperOperator<'a> for FermionOperator {
+    // From trait
+    fn sparse_matrix_superoperator(
+        &'a self,
+        number_modes: usize,
+        number_particles: Option<usize>,
+    ) -> Result<HashMap<(usize, usize), Complex64>, StruqtureError> {
+        let space = FockSpace::new(number_modes, number_particles, self.current_number_modes())?;
+        let mut matrix: HashMap<(usize, usize), Complex64> = HashMap::new();
+        add_commutator_superoperator(self.iter(), &space, &mut matrix)?;
+        Ok(matrix)
+    }
+}
+
 /// Implements the default function (Default trait) of FermionOperator (an empty FermionOperator).
 ///
 impl Default for FermionOperator {
@@ -269,6 +304,115 @@ impl FermionOperator {
         }
         Ok(new_operator)
     }
+
+    /// Returns the commutator [self, other] = self * other - other * self.
+    ///
+    /// Pairs of FermionProducts that can be reordered without contractions only contribute
+    /// when reordering them changes the sign, in which case they contribute twice their product.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The FermionOperator to take the commutator with.
+    ///
+    /// # Returns
+    ///
+    /// * `FermionOperator` - The commutator of the two FermionOperators.
+    pub fn commutator(&self, other: &FermionOperator) -> FermionOperator {
+        commutator_of_fermion_products(self, other, false)
+    }
+
+    /// Returns the anticommutator {self, other} = self * other + other * self.
+    ///
+    /// Pairs of FermionProducts that can be reordered without contractions only contribute
+    /// when reordering them does not change the sign, in which case they contribute twice their product.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The FermionOperator to take the anticommutator with.
+    ///
+    /// # Returns
+    ///
+    /// * `FermionOperator` - The anticommutator of the two FermionOperators.
+    pub fn anticommutator(&self, other: &FermionOperator) -> FermionOperator {
+        commutator_of_fermion_products(self, other, true)
+    }
+
+    /// Returns the particle-hole transformation of the FermionOperator on the given modes.
+    ///
+    /// On every transformed mode the creator and annihilator are swapped, c_i^{dagger} <-> c_i,
+    /// and the products are brought back into normal order. The anticommutation relations
+    /// give the constant and lower order shifts of the transformed terms.
+    ///
+    /// # Arguments
+    ///
+    /// * `modes` - The modes on which the particles and holes are exchanged.
+    ///
+    /// # Returns
+    ///
+    /// * `FermionOperator` - The normal ordered FermionOperator after the transformation.
+    pub fn particle_hole_transformation(&self, modes: &[usize]) -> FermionOperator {
+        let mut transformed = FermionOperator::with_capacity(self.len());
+        for (product, value) in self.iter() {
+            transformed = transformed + particle_hole_product(product, modes) * value.clone();
+        }
+        transformed
+    }
+}
+
+/// Builds the (anti-)commutator of two FermionOperators.
+///
+/// # Arguments
+///
+/// * `left` - The left-hand operator.
+/// * `right` - The right-hand operator.
+/// * `anticommutator` - Whether to build the anticommutator instead of the commutator.
+///
+/// # Returns
+///
+/// * `FermionOperator` - The resulting (anti-)commutator.
+///
+/// # Panics
+///
+/// * Internal error in add_operator_product.
+fn commutator_of_fermion_products(
+    left: &FermionOperator,
+    right: &FermionOperator,
+    anticommutator: bool,
+) -> FermionOperator {
+    let reversed_sign = if anticommutator { 1.0 } else { -1.0 };
+    let mut op = FermionOperator::new();
+    for (fpl, vall) in left.iter() {
+        for (fpr, valr) in right.iter() {
+            let coefficient = vall.clone() * valr;
+            if mode_products_reorder_freely(fpl, fpr) {
+                let number_left = fpl.number_creators() + fpl.number_annihilators();
+                let number_right = fpr.number_creators() + fpr.number_annihilators();
+                let swap_sign = if (number_left * number_right) % 2 == 0 {
+                    1.0
+                } else {
+                    -1.0
+                };
+                let prefactor = 1.0 + reversed_sign * swap_sign;
+                if prefactor == 0.0 {
+                    continue;
+                }
+                for (prod, coeff) in fpl.clone() * fpr.clone() {
+                    op.add_operator_product(prod, coefficient.clone() * coeff * prefactor)
+                        .expect("Internal bug in add_operator_product");
+                }
+            } else {
+                for (prod, coeff) in fpl.clone() * fpr.clone() {
+                    op.add_operator_product(prod, coefficient.clone() * coeff)
+                        .expect("Internal bug in add_operator_product");
+                }
+                for (prod, coeff) in fpr.clone() * fpl.clone() {
+                    op.add_operator_product(prod, coefficient.clone() * coeff * reversed_sign)
+                        .expect("Internal bug in add_operator_product");
+                }
+            }
+        }
+    }
+    op
 }
 
 impl From<FermionHamiltonian> for FermionOperator {
@@ -295,7 +439,10 @@ impl From<FermionHamiltonian> for FermionOperator {
             if !key.is_natural_hermitian() {
                 let bp_conj = bp.hermitian_conjugate();
                 internal
-                    .add_operator_product(FermionProduct::get_key(&bp_conj.0), value * bp_conj.1)
+                    .add_operator_product(
+                        FermionProduct::get_key(&bp_conj.0),
+                        value.conj() * bp_conj.1,
+                    )
                     .expect("Internal error in add_operator_product");
             }
         }
@@ -422,16 +569,30 @@ impl ops::Mul<FermionOperator> for FermionOperator {
     /// * Internal error in add_operator_product.
     fn mul(self, other: FermionOperator) -> Self {
         let mut op = FermionOperator::with_capacity(self.len() * other.len());
-        for (bps, vals) in self {
-            for (bpo, valo) in other.iter() {
-                let fermion_products = bps.clone() * bpo.clone();
-                let coefficient = Into::<CalculatorComplex>::into(valo) * vals.clone();
-                for (prod, coeff) in fermion_products {
-                    op.add_operator_product(prod, coefficient.clone() * coeff)
-                        .expect("Internal bug in add_operator_product");
+        let left: Vec<(&FermionProduct, &CalculatorComplex)> = self.iter().collect();
+        let right: Vec<(&FermionProduct, &CalculatorComplex)> = other.iter().collect();
+        multiply_terms(
+            &left,
+            &right,
+            |(bps, vals), right| {
+                let mut partial = FermionOperator::with_capacity(right.len());
+                for (bpo, valo) in right {
+                    let fermion_products = (*bps).clone() * (*bpo).clone();
+                    let coefficient = Into::<CalculatorComplex>::into(*valo) * (*vals).clone();
+                    for (prod, coeff) in fermion_products {
+                        partial.add_operator_product(prod, coefficient.clone() * coeff)?;
+                    }
                 }
-            }
-        }
+                Ok(partial)
+            },
+            |partial| {
+                for (prod, coefficient) in partial {
+                    op.add_operator_product(prod, coefficient)?;
+                }
+                Ok(())
+            },
+        )
+        .expect("Internal bug in add_operator_product");
         op
     }
 }
@@ -556,6 +717,109 @@ impl JordanWignerFermionToSpin for FermionOperator {
     }
 }
 
+impl BravyiKitaevFermionToSpin for FermionOperator {
+    type Output = PauliOperator;
+
+    /// Implements BravyiKitaevFermionToSpin for a FermionOperator.
+    ///
+    /// The convention used is that |0> represents an even and |1> an odd parity of the modes
+    /// stored in a qubit.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of fermionic modes (and spins) of the mapping.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PauliOperator)` - The spin operator that results from the transformation.
+    /// * `Err(StruqtureError::GenericError)` - The FermionOperator acts on more than `number_modes` modes.
+    fn bravyi_kitaev(&self, number_modes: usize) -> Result<Self::Output, StruqtureError> {
+        let gates = bravyi_kitaev_gates(number_modes, self.current_number_modes())?;
+        self.jordan_wigner()
+            .conjugate_with_clifford_sequence(&gates)
+    }
+}
+
+impl ParityFermionToSpin for FermionOperator {
+    type Output = PauliOperator;
+
+    /// Implements ParityFermionToSpin for a FermionOperator.
+    ///
+    /// The convention used is that |0> represents an even and |1> an odd parity of the modes
+    /// up to a qubit.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of fermionic modes (and spins before the reduction) of the mapping.
+    /// * `particle_numbers` - The numbers of spin-up and spin-down fermions for the two-qubit reduction, no reduction if None.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PauliOperator)` - The spin operator that results from the transformation.
+    /// * `Err(StruqtureError::GenericError)` - The FermionOperator acts on more than `number_modes` modes.
+    /// * `Err(StruqtureError::GenericError)` - The two-qubit reduction is not possible.
+    fn parity_mapping(
+        &self,
+        number_modes: usize,
+        particle_numbers: Option<(usize, usize)>,
+    ) -> Result<Self::Output, StruqtureError> {
+        let gates = parity_gates(number_modes, self.current_number_modes())?;
+        two_qubit_reduced_operator(
+            self.jordan_wigner()
+                .conjugate_with_clifford_sequence(&gates)?,
+            number_modes,
+            particle_numbers,
+        )
+    }
+}
+
+/// Returns the particle-hole transformation of a FermionProduct on the given modes.
+///
+/// # Arguments
+///
+/// * `product` - The FermionProduct to transform.
+/// * `modes` - The modes on which the particles and holes are exchanged.
+///
+/// # Returns
+///
+/// * `FermionOperator` - The normal ordered FermionOperator after the transformation.
+///
+/// # Panics
+///
+/// * Internal error in add_operator_product.
+pub(crate) fn particle_hole_product(product: &FermionProduct, modes: &[usize]) -> FermionOperator {
+    let single_operator = |creators: &[usize], annihilators: &[usize]| {
+        let mut operator = FermionOperator::new();
+        operator
+            .add_operator_product(
+                FermionProduct::new(creators.iter().copied(), annihilators.iter().copied())
+                    .expect("Internal bug in FermionProduct::new"),
+                CalculatorComplex::new(1.0, 0.0),
+            )
+            .expect("Internal bug in add_operator_product");
+        operator
+    };
+    // Multiplying the single operators in their original order normal orders the product
+    let mut transformed = single_operator(&[], &[]);
+    for creator in product.creators() {
+        transformed = transformed
+            * if modes.contains(creator) {
+                single_operator(&[], &[*creator])
+            } else {
+                single_operator(&[*creator], &[])
+            };
+    }
+    for annihilator in product.annihilators() {
+        transformed = transformed
+            * if modes.contains(annihilator) {
+                single_operator(&[*annihilator], &[])
+            } else {
+                single_operator(&[], &[*annihilator])
+            };
+    }
+    transformed
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -0,0 +1,167 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::FermionProduct;
+use crate::sparse_matrix_assembly::{basis_states_with_set_bits, ProductBasis, ProductColumns};
+use crate::{ModeIndex, StruqtureError};
+use num_complex::Complex64;
+
+/// Returns the occupation-number basis states of a fermionic Fock space.
+///
+/// Every basis state is encoded as an integer where bit `i` is the occupation of mode `i`.
+/// The basis state `n` corresponds to `(c_0^†)^n_0 (c_1^†)^n_1 ... |0>`, which is the same
+/// little endian convention used by the Jordan-Wigner transformation.
+/// The basis states are sorted in ascending order, the position of a basis state in the returned vector
+/// is the row (column) index used by the Fock-space matrices of fermionic operators.
+///
+/// # Arguments
+///
+/// * `number_modes` - The number of fermionic modes.
+/// * `number_particles` - The number of particles of the sector, or None for the full Fock space.
+///
+/// # Returns
+///
+/// * `Ok(Vec<usize>)` - The sorted basis states.
+/// * `Err(StruqtureError::GenericError)` - The number of modes is too large to encode the basis states.
+///
+/// # Example
+/// ```
+/// use struqture::fermions::fermionic_fock_basis;
+///
+/// assert_eq!(fermionic_fock_basis(3, Some(2)).unwrap(), vec![3, 5, 6]);
+/// ```
+pub fn fermionic_fock_basis(
+    number_modes: usize,
+    number_particles: Option<usize>,
+) -> Result<Vec<usize>, StruqtureError> {
+    if number_modes >= usize::BITS as usize {
+        return Err(StruqtureError::GenericError {
+            msg: format!("Cannot construct the Fock space of {number_modes} fermionic modes."),
+        });
+    }
+    Ok(basis_states_with_set_bits(number_modes, number_particles))
+}
+
+/// Applies a FermionProduct to an occupation-number basis state.
+///
+/// # Arguments
+///
+/// * `product` - The normal ordered product of creators and annihilators.
+/// * `state` - The basis state the product is applied to.
+///
+/// # Returns
+///
+/// * `Some((usize, f64))` - The resulting basis state and the fermionic sign.
+/// * `None` - The product annihilates the basis state.
+fn apply_fermion_product(product: &FermionProduct, state: usize) -> Option<(usize, f64)> {
+    let mut state = state;
+    let mut sign = 1.0;
+    // The rightmost operator acts first
+    for (mode, creator) in product
+        .annihilators()
+        .rev()
+        .map(|mode| (*mode, false))
+        .chain(product.creators().rev().map(|mode| (*mode, true)))
+    {
+        let bit = 1usize << mode;
+        if (state & bit != 0) == creator {
+            return None;
+        }
+        if (state & (bit - 1)).count_ones() % 2 == 1 {
+            sign = -sign;
+        }
+        state ^= bit;
+    }
+    Some((state, sign))
+}
+
+/// A basis of the fermionic Fock space or of one of its particle-number sectors.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct FockSpace {
+    basis: Vec<usize>,
+    sector: bool,
+}
+
+impl FockSpace {
+    /// Creates the Fock space basis for the matrices of an object.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of fermionic modes of the Fock space.
+    /// * `number_particles` - The number of particles of the sector, or None for the full Fock space.
+    /// * `current_number_modes` - The number of modes the object acts on.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The Fock space basis.
+    /// * `Err(StruqtureError::GenericError)` - The number of modes is smaller than the number of modes the object acts on or too large.
+    pub(crate) fn new(
+        number_modes: usize,
+        number_particles: Option<usize>,
+        current_number_modes: usize,
+    ) -> Result<Self, StruqtureError> {
+        if number_modes < current_number_modes {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "Cannot construct a Fock space matrix for {number_modes} modes of an object acting on {current_number_modes} modes."
+                ),
+            });
+        }
+        Ok(FockSpace {
+            basis: fermionic_fock_basis(number_modes, number_particles)?,
+            sector: number_particles.is_some(),
+        })
+    }
+}
+
+impl ProductBasis<FermionProduct> for FockSpace {
+    // From trait
+    fn dimension(&self) -> usize {
+        self.basis.len()
+    }
+
+    /// Returns the non-zero entry in each column of the matrix of a FermionProduct.
+    ///
+    /// # Arguments
+    ///
+    /// * `product` - The FermionProduct.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ProductColumns)` - The row and fermionic sign of the non-zero entry of each column.
+    /// * `Err(StruqtureError::GenericError)` - The product does not conserve the particle number in a particle-number sector.
+    fn product_columns(&self, product: &FermionProduct) -> Result<ProductColumns, StruqtureError> {
+        if self.sector && product.number_creators() != product.number_annihilators() {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "FermionProduct {product} does not conserve the particle number and cannot be restricted to a particle-number sector."
+                ),
+            });
+        }
+        Ok(self
+            .basis
+            .iter()
+            .map(|state| {
+                apply_fermion_product(product, *state).map(|(new_state, sign)| {
+                    let row = if self.sector {
+                        self.basis.binary_search(&new_state).expect(
+                            "Internal error: particle-number conserving product left the sector",
+                        )
+                    } else {
+                        new_state
+                    };
+                    (row, Complex64::new(sign, 0.0))
+                })
+            })
+            .collect())
+    }
+}
@@ -24,15 +24,24 @@ mod fermionic_indices;
 mod fermionic_noise_operator;
 mod fermionic_open_system;
 mod fermionic_operator;
+mod fock_space;
+use std::collections::HashMap;
 use std::str::FromStr;
 
 pub use fermionic_hamiltonian::FermionHamiltonian;
 pub use fermionic_noise_operator::FermionLindbladNoiseOperator;
 pub use fermionic_open_system::FermionLindbladOpenSystem;
 pub use fermionic_operator::FermionOperator;
+pub use fock_space::fermionic_fock_basis;
+pub(crate) use fock_space::FockSpace;
 
-use crate::{ModeIndex, OperateOnDensityMatrix, SymmetricIndex};
+use crate::sparse_matrix_assembly::hash_map_to_coo;
+use crate::{
+    CooSparseMatrix, ModeIndex, OperateOnDensityMatrix, OperateOnModes, StruqtureError,
+    SymmetricIndex,
+};
 pub use fermionic_indices::{FermionProduct, HermitianFermionProduct};
+use num_complex::Complex64;
 use qoqo_calculator::CalculatorComplex;
 
 /// Signal Trait for specifying that a type can be used a fermionic index.
@@ -88,3 +97,130 @@ where
     &'a Self: IntoIterator,
 {
 }
+
+/// Trait for constructing the matrix of fermionic operators in the occupation-number basis.
+///
+/// The matrices are constructed directly in the fermionic Fock space with the basis returned by
+/// [crate::fermions::fermionic_fock_basis], optionally restricted to a fixed particle-number sector.
+/// Without a particle-number sector the matrices are identical to the matrices of the Jordan-Wigner transformed operators.
+///
+/// # Example
+/// ```
+/// use struqture::prelude::*;
+/// use qoqo_calculator::CalculatorComplex;
+/// use struqture::fermions::{FermionOperator, FermionProduct, ToFermionSparseMatrixOperator};
+///
+/// let mut fo = FermionOperator::new();
+/// fo.set(FermionProduct::new([0], [1]).unwrap(), CalculatorComplex::from(0.5)).unwrap();
+///
+/// // The hopping from mode 1 to mode 0 in the one-particle sector with the basis [1, 2]
+/// let matrix = fo.sparse_matrix(2, Some(1)).unwrap();
+/// assert_eq!(matrix.get(&(0, 1)), Some(&0.5.into()));
+/// ```
+pub trait ToFermionSparseMatrixOperator<'a>: OperateOnModes<'a> {
+    /// Constructs the sparse matrix representation of Self in the occupation-number basis as a HashMap.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of fermionic modes for which to construct the sparse matrix.
+    /// * `number_particles` - The number of particles of the sector the matrix is restricted to, or None for the full Fock space.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HashMap<(usize, usize), Complex64>)` - The matrix with rows and columns indexing the states of [crate::fermions::fermionic_fock_basis].
+    /// * `Err(StruqtureError::GenericError)` - The number of modes is too small for Self or a term does not conserve the particle number in a particle-number sector.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn sparse_matrix(
+        &'a self,
+        number_modes: usize,
+        number_particles: Option<usize>,
+    ) -> Result<HashMap<(usize, usize), Complex64>, StruqtureError>;
+
+    /// Constructs the sparse matrix representation of Self in the occupation-number basis in COO representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of fermionic modes for which to construct the sparse matrix.
+    /// * `number_particles` - The number of particles of the sector the matrix is restricted to, or None for the full Fock space.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Vec<Complex64>, (Vec<usize>, Vec<usize>)))` - The matrix sorted by rows and columns, indexing the states of [crate::fermions::fermionic_fock_basis].
+    /// * `Err(StruqtureError::GenericError)` - The number of modes is too small for Self or a term does not conserve the particle number in a particle-number sector.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn sparse_matrix_coo(
+        &'a self,
+        number_modes: usize,
+        number_particles: Option<usize>,
+    ) -> Result<CooSparseMatrix, StruqtureError> {
+        Ok(hash_map_to_coo(
+            self.sparse_matrix(number_modes, number_particles)?,
+        ))
+    }
+}
+
+/// Trait for constructing the Lindblad superoperator of fermionic objects in the occupation-number basis.
+///
+/// The superoperator for the operator O is defined as the Matrix S so that
+/// `flatten(-i [O, p]) = S flatten(p)` where `[,]` is the commutator, `p` is a matrix
+/// and `flatten` flattens a matrix into a vector in row-major form.
+/// Noise terms with the rate γ and the Lindblad operators (L, R) contribute `γ (L p R^† - 1/2 {R^† L, p})`.
+pub trait ToFermionSparseMatrixSuperOperator<'a>: OperateOnModes<'a> {
+    /// Constructs the sparse matrix representation of the superoperator in the occupation-number basis as a HashMap.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of fermionic modes for which to construct the sparse matrix.
+    /// * `number_particles` - The number of particles of the sector the density matrix is restricted to, or None for the full Fock space.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HashMap<(usize, usize), Complex64>)` - The superoperator acting on the row-major flattened density matrix.
+    /// * `Err(StruqtureError::GenericError)` - The number of modes is too small for Self or a term does not conserve the particle number in a particle-number sector.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn sparse_matrix_superoperator(
+        &'a self,
+        number_modes: usize,
+        number_particles: Option<usize>,
+    ) -> Result<HashMap<(usize, usize), Complex64>, StruqtureError>;
+
+    /// Constructs the sparse matrix representation of the superoperator in the occupation-number basis in COO representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of fermionic modes for which to construct the sparse matrix.
+    /// * `number_particles` - The number of particles of the sector the density matrix is restricted to, or None for the full Fock space.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Vec<Complex64>, (Vec<usize>, Vec<usize>)))` - The superoperator sorted by rows and columns.
+    /// * `Err(StruqtureError::GenericError)` - The number of modes is too small for Self or a term does not conserve the particle number in a particle-number sector.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn sparse_matrix_superoperator_coo(
+        &'a self,
+        number_modes: usize,
+        number_particles: Option<usize>,
+    ) -> Result<CooSparseMatrix, StruqtureError> {
+        Ok(hash_map_to_coo(self.sparse_matrix_superoperator(
+            number_modes,
+            number_particles,
+        )?))
+    }
+}
+
+/// Returns twice the change of the fermionic Sz caused by a product of creators and annihilators.
+///
+/// The modes follow the interleaved spin-orbital convention: the mode `2k` is the spin-up
+/// and the mode `2k + 1` the spin-down spin-orbital of the orbital `k`.
+///
+/// # Arguments
+///
+/// * `product` - The product of creators and annihilators.
+///
+/// # Returns
+///
+/// * `isize` - The number of spin-up minus spin-down creators, minus the same for the annihilators.
+pub(crate) fn fermionic_sz_change(product: &impl ModeIndex) -> isize {
+    let spin = |mode: &usize| if mode % 2 == 0 { 1 } else { -1 };
+    product.creators().map(spin).sum::<isize>() - product.annihilators().map(spin).sum::<isize>()
+}
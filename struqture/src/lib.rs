@@ -17,11 +17,13 @@
 // #![warn(private_doc_tests)]
 // #![deny(missing_debug_implementations)]
 
+use ndarray::{Array1, Array2};
 use num_complex::Complex64;
+use qoqo_calculator::Calculator;
 use qoqo_calculator::CalculatorComplex;
 use qoqo_calculator::CalculatorError;
 use qoqo_calculator::CalculatorFloat;
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::fmt::Debug;
 use std::ops::Add;
 use std::ops::Mul;
@@ -29,7 +31,10 @@ use std::ops::Sub;
 use std::str::FromStr;
 use thiserror::Error;
 
+mod parallel_iteration;
 mod serialisation_meta_information;
+mod sparse_matrix_assembly;
+mod symbolic_sum;
 pub use serialisation_meta_information::{
     check_can_be_deserialised, SerializationSupport, StruqtureSerialisationMeta, StruqtureType,
 };
@@ -140,6 +145,14 @@ pub enum StruqtureError {
     /// Error when trying to insert identities into noise operators
     #[error("Lindblad operators need to be traceless.")]
     InvalidLindbladTerms,
+    /// Error when a dense matrix would exceed the maximum supported dimension.
+    #[error("Dense matrix of dimension {dimension} exceeds the maximum dense matrix dimension {maximum_dimension}.")]
+    DenseMatrixTooLarge {
+        /// Dimension of the requested dense matrix.
+        dimension: usize,
+        /// Maximum supported dimension of a dense matrix.
+        maximum_dimension: usize,
+    },
     /// Gerneric Error in struqture.
     #[error("Error occured: {msg}")]
     GenericError {
@@ -153,11 +166,93 @@ pub enum StruqtureError {
 /// Input in the form (value_vector, (row_index_vector, column_index_vector))
 pub type CooSparseMatrix = (Vec<Complex64>, (Vec<usize>, Vec<usize>));
 
+/// Maximum dimension of dense matrices constructed by struqture.
+///
+/// A dense complex matrix of this dimension occupies 1 GiB of memory.
+pub const MAXIMUM_DENSE_MATRIX_DIMENSION: usize = 8192;
+
 /// Real sparse matrix in coordinate (COO) format.
 ///
 /// Input in the form (value_vector, (row_index_vector, column_index_vector))
 pub type CooSparseMatrixReal = (Vec<f64>, (Vec<usize>, Vec<usize>));
 
+/// Complex sparse matrix in compressed sparse row (CSR) format.
+///
+/// Input in the form (value_vector, column_index_vector, row_pointer_vector), the same layout as
+/// the (data, indices, indptr) input of scipy.sparse.csr_matrix.
+/// The column indices of every row are sorted and contain no duplicates.
+pub type CsrSparseMatrix = (Vec<Complex64>, Vec<usize>, Vec<usize>);
+
+/// Complex sparse matrix in compressed sparse column (CSC) format.
+///
+/// Input in the form (value_vector, row_index_vector, column_pointer_vector), the same layout as
+/// the (data, indices, indptr) input of scipy.sparse.csc_matrix.
+/// The row indices of every column are sorted and contain no duplicates.
+pub type CscSparseMatrix = (Vec<Complex64>, Vec<usize>, Vec<usize>);
+
+/// Complex sparse matrix restricted to a symmetry sector.
+///
+/// Input in the form (entries, basis_states), where the rows and columns of the entries are the
+/// positions of the basis states of the sector in basis_states.
+pub type SectorSparseMatrix = (HashMap<(usize, usize), Complex64>, Vec<usize>);
+
+/// Binary symplectic representation of a Pauli operator.
+///
+/// Input in the form (x_matrix, z_matrix, coefficient_vector), where row k of the X and Z matrices
+/// is the binary symplectic representation of the k-th term and entry k of the coefficient vector its coefficient.
+pub type SymplecticMatrices = (Array2<bool>, Array2<bool>, Array1<Complex64>);
+
+/// Appends a row to a CSR matrix.
+///
+/// # Arguments
+///
+/// * `csr` - The matrix in CSR format, with at least one row pointer.
+/// * `entries` - The entries of the new row, indexed by column.
+pub(crate) fn push_csr_row(csr: &mut CsrSparseMatrix, entries: HashMap<usize, Complex64>) {
+    let (values, columns, row_pointers) = csr;
+    let mut entries: Vec<(usize, Complex64)> = entries.into_iter().collect();
+    entries.sort_unstable_by_key(|(column, _)| *column);
+    for (column, value) in entries {
+        columns.push(column);
+        values.push(value);
+    }
+    row_pointers.push(columns.len());
+}
+
+/// Converts a CSR matrix into a CSC matrix.
+///
+/// # Arguments
+///
+/// * `csr` - The matrix in CSR format.
+/// * `number_columns` - The number of columns of the matrix.
+///
+/// # Returns
+///
+/// * `CscSparseMatrix` - The matrix in CSC format with sorted row indices.
+pub(crate) fn csr_to_csc(csr: CsrSparseMatrix, number_columns: usize) -> CscSparseMatrix {
+    let (csr_values, csr_columns, csr_row_pointers) = csr;
+    let mut column_pointers: Vec<usize> = vec![0; number_columns + 1];
+    for column in csr_columns.iter() {
+        column_pointers[column + 1] += 1;
+    }
+    for column in 0..number_columns {
+        column_pointers[column + 1] += column_pointers[column];
+    }
+    let mut next_position = column_pointers.clone();
+    let mut values = vec![Complex64::new(0.0, 0.0); csr_values.len()];
+    let mut rows = vec![0; csr_values.len()];
+    // Iterating over the rows in ascending order keeps the row indices in every column sorted
+    for (row, window) in csr_row_pointers.windows(2).enumerate() {
+        for index in window[0]..window[1] {
+            let position = &mut next_position[csr_columns[index]];
+            values[*position] = csr_values[index];
+            rows[*position] = row;
+            *position += 1;
+        }
+    }
+    (values, rows, column_pointers)
+}
+
 /// Trait for all hermitian indices
 pub trait SymmetricIndex:
     std::hash::Hash + Eq + Sized + Clone + std::fmt::Debug + std::fmt::Display + FromStr + Default
@@ -435,6 +530,29 @@ pub trait ModeIndex:
     }
 }
 
+/// Returns whether two mode products can be reordered without creating contraction terms.
+///
+/// This is the case when no annihilator of one product acts on a mode on which the other product has a creator.
+/// For bosons the two products then commute, for fermions they commute up to the sign
+/// (-1)^(n_left * n_right), where n is the total number of creators and annihilators in a product.
+///
+/// # Arguments
+///
+/// * `left` - The left-hand mode product.
+/// * `right` - The right-hand mode product.
+///
+/// # Returns
+///
+/// * `bool` - Whether the two products can be reordered freely.
+pub(crate) fn mode_products_reorder_freely<T: ModeIndex>(left: &T, right: &T) -> bool {
+    !left
+        .annihilators()
+        .any(|annihilator| right.creators().any(|creator| creator == annihilator))
+        && !right
+            .annihilators()
+            .any(|annihilator| left.creators().any(|creator| creator == annihilator))
+}
+
 /// Trait for transforming value stored at index I when using index of different type T to read out value
 /// e.g. Hermitian Hamiltonian H but we access H[NOIndex(2,1)] -> H[HermitianIndex(1,2)].conj()
 pub trait GetValue<T> {
@@ -528,6 +646,128 @@ impl TruncateTrait for CalculatorFloat {
     }
 }
 
+/// Helper trait to allow the handling of symbolic parameters in values.
+/// Should eventually be ported to qoqo_calculator like this
+/// and be implemented for CalculatorFloat and CalculatorComplex
+pub trait SymbolicParametersTrait: Sized {
+    /// Substitutes the symbolic parameters in Self with the values set in a calculator.
+    ///
+    /// Expressions may assign variables, as in `a=2; a * theta`, which only affects the expression itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `calculator` - The Calculator holding the values of the symbolic parameters.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The numeric version of Self.
+    /// * `Err(StruqtureError::CalculatorError)` - A symbolic expression could not be evaluated, e.g. because a parameter was not set.
+    fn substitute_parameters(&self, calculator: &Calculator) -> Result<Self, StruqtureError>;
+
+    /// Returns the names of the symbolic parameters Self depends on.
+    ///
+    /// Names of functions and of variables that are assigned inside the expression are not included.
+    ///
+    /// # Returns
+    ///
+    /// * `BTreeSet<String>` - The names of the free parameters.
+    fn free_parameters(&self) -> BTreeSet<String>;
+}
+
+impl SymbolicParametersTrait for CalculatorComplex {
+    fn substitute_parameters(&self, calculator: &Calculator) -> Result<Self, StruqtureError> {
+        Ok(CalculatorComplex {
+            re: self.re.substitute_parameters(calculator)?,
+            im: self.im.substitute_parameters(calculator)?,
+        })
+    }
+
+    fn free_parameters(&self) -> BTreeSet<String> {
+        let mut free_parameters = self.re.free_parameters();
+        free_parameters.extend(self.im.free_parameters());
+        free_parameters
+    }
+}
+
+impl SymbolicParametersTrait for CalculatorFloat {
+    fn substitute_parameters(&self, calculator: &Calculator) -> Result<Self, StruqtureError> {
+        match self {
+            CalculatorFloat::Float(_) => Ok(self.clone()),
+            CalculatorFloat::Str(expression) => {
+                // Variables assigned inside the expression must not leak into other expressions
+                let mut calculator = calculator.clone();
+                Ok(CalculatorFloat::Float(
+                    calculator.parse_str_assign(expression)?,
+                ))
+            }
+        }
+    }
+
+    fn free_parameters(&self) -> BTreeSet<String> {
+        match self {
+            CalculatorFloat::Float(_) => BTreeSet::new(),
+            CalculatorFloat::Str(expression) => free_parameters_in_expression(expression),
+        }
+    }
+}
+
+/// Collects the names of the free variables in a symbolic expression.
+///
+/// The expression is lexed following the same rules as the TokenIterator of qoqo_calculator, which is not public:
+/// names start with a letter and continue with letters, digits and underscores, names followed by an opening
+/// bracket are functions and names followed by `=` are assigned variables. A variable is free when it is used
+/// before it is assigned.
+///
+/// # Arguments
+///
+/// * `expression` - The symbolic expression.
+///
+/// # Returns
+///
+/// * `BTreeSet<String>` - The names of the variables that are used before they are assigned in the expression.
+fn free_parameters_in_expression(expression: &str) -> BTreeSet<String> {
+    let mut assigned: BTreeSet<&str> = BTreeSet::new();
+    let mut free_parameters: BTreeSet<String> = BTreeSet::new();
+    let mut remaining = expression;
+    while let Some(first) = remaining.chars().next() {
+        if first == '#' {
+            // Comments run until the end of the line
+            remaining = remaining.find('\n').map_or("", |end| &remaining[end + 1..]);
+        } else if first.is_alphabetic() {
+            let end = remaining
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(remaining.len());
+            let name = &remaining[..end];
+            remaining = &remaining[end..];
+            // As in the calculator, only spaces are skipped when looking at the next token
+            let next = remaining.trim_start_matches(' ');
+            if next.starts_with('(') {
+                continue;
+            } else if next.starts_with('=') {
+                assigned.insert(name);
+            } else if !assigned.contains(name) {
+                free_parameters.insert(name.to_string());
+            }
+        } else if first.is_ascii_digit() || first == '.' {
+            let end = remaining
+                .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+                .unwrap_or(remaining.len());
+            remaining = &remaining[end..];
+            // Skip the exponent of numbers in scientific notation
+            if let Some(exponent) = remaining.strip_prefix(['e', 'E']) {
+                let exponent = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+                let end = exponent
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(exponent.len());
+                remaining = &exponent[end..];
+            }
+        } else {
+            remaining = &remaining[first.len_utf8()..];
+        }
+    }
+    free_parameters
+}
+
 /// Helper trait to allow hermitian conjugation of values
 /// Should eventually be ported to qoqo_calculator like this
 /// and be implemented for CalculatorFloat, CaclulatorComplex, f64 and Complexf64
@@ -719,6 +959,53 @@ where
         }));
         new_self
     }
+
+    /// Substitutes the symbolic parameters in the coefficients of Self.
+    ///
+    /// Returns a copy of Self in which all symbolic coefficients are evaluated with the given parameter values.
+    /// Terms whose coefficient evaluates to zero are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `parameters` - The values of the symbolic parameters.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The copy of Self with numeric coefficients.
+    /// * `Err(StruqtureError::CalculatorError)` - A coefficient could not be evaluated, e.g. because a parameter was not set.
+    fn substitute_parameters(
+        &'a self,
+        parameters: &HashMap<String, f64>,
+    ) -> Result<Self, StruqtureError>
+    where
+        Self::Value: SymbolicParametersTrait,
+    {
+        let mut calculator = Calculator::new();
+        for (name, value) in parameters.iter() {
+            calculator.set_variable(name, *value);
+        }
+        let mut new_self = self.empty_clone(Some(self.len()));
+        for (key, value) in self.iter() {
+            new_self.set(key.clone(), value.substitute_parameters(&calculator)?)?;
+        }
+        Ok(new_self)
+    }
+
+    /// Returns the names of the symbolic parameters the coefficients of Self depend on.
+    ///
+    /// # Returns
+    ///
+    /// * `BTreeSet<String>` - The names of the free parameters.
+    fn free_parameters(&'a self) -> BTreeSet<String>
+    where
+        Self::Value: SymbolicParametersTrait,
+    {
+        let mut free_parameters = BTreeSet::new();
+        for value in self.values() {
+            free_parameters.extend(value.free_parameters());
+        }
+        free_parameters
+    }
 }
 
 /// Trait for representing complete open systems
@@ -839,6 +1126,50 @@ where
         Self::group(new_system, new_noise)
             .expect("Internal error: System and Noise size unexpectedly do not match")
     }
+
+    /// Substitutes the symbolic parameters in the coefficients of the system and the noise of Self.
+    ///
+    /// Terms whose coefficient evaluates to zero are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `parameters` - The values of the symbolic parameters.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The copy of Self with numeric coefficients.
+    /// * `Err(StruqtureError::CalculatorError)` - A coefficient could not be evaluated, e.g. because a parameter was not set.
+    fn substitute_parameters(
+        &'a self,
+        parameters: &HashMap<String, f64>,
+    ) -> Result<Self, StruqtureError>
+    where
+        <<Self as OpenSystem<'a>>::System as OperateOnDensityMatrix<'a>>::Value:
+            SymbolicParametersTrait,
+        <<Self as OpenSystem<'a>>::Noise as OperateOnDensityMatrix<'a>>::Value:
+            SymbolicParametersTrait,
+    {
+        let new_system = self.system().substitute_parameters(parameters)?;
+        let new_noise = self.noise().substitute_parameters(parameters)?;
+        Self::group(new_system, new_noise)
+    }
+
+    /// Returns the names of the symbolic parameters the system and the noise of Self depend on.
+    ///
+    /// # Returns
+    ///
+    /// * `BTreeSet<String>` - The names of the free parameters.
+    fn free_parameters(&'a self) -> BTreeSet<String>
+    where
+        <<Self as OpenSystem<'a>>::System as OperateOnDensityMatrix<'a>>::Value:
+            SymbolicParametersTrait,
+        <<Self as OpenSystem<'a>>::Noise as OperateOnDensityMatrix<'a>>::Value:
+            SymbolicParametersTrait,
+    {
+        let mut free_parameters = self.system().free_parameters();
+        free_parameters.extend(self.noise().free_parameters());
+        free_parameters
+    }
 }
 
 /// Trait for all objects that can act on a quantum state like an operator.
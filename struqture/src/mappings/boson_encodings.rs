@@ -0,0 +1,356 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Encodings of truncated bosonic modes in registers of spins.
+//!
+//! Every bosonic mode with the cutoff c is encoded in its own register of spins, the registers
+//! of the modes follow each other in the order of the modes. The occupation n = 0, 1, ..., c is stored as
+//!
+//! * unary (one-hot): the spin n of the c + 1 spins of the register is in $|1\rangle$, all others are in $|0\rangle$,
+//! * binary: the spin k of the register stores the bit k of n,
+//! * Gray code: the spin k of the register stores the bit k of n XOR (n >> 1),
+//!
+//! where the binary and Gray-code registers contain as many spins as there are bits in c.
+//! Any product of creators and annihilators is mapped to the spin operator with the same matrix
+//! elements between the encoded occupations. Spin states outside of the code space can be penalised
+//! with [BosonSpinEncoding::code_space_penalty].
+
+use crate::bosons::{BosonCutoff, BosonProduct};
+use crate::mappings::hermitian_terms;
+use crate::spins::{PauliHamiltonian, PauliOperator, PauliProduct};
+use crate::{ModeIndex, OperateOnDensityMatrix, SpinIndex, StruqtureError};
+use qoqo_calculator::CalculatorComplex;
+
+/// The encoding of the occupation of a truncated bosonic mode in a register of spins.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BosonSpinEncoding {
+    /// One spin per occupation, the spin of the occupation is in the state |1>.
+    Unary,
+    /// The occupation is stored as a binary number in little endian order.
+    Binary,
+    /// The Gray code of the occupation is stored in little endian order.
+    Gray,
+}
+
+impl BosonSpinEncoding {
+    /// Returns the number of spins encoding a bosonic mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `cutoff` - The maximal occupation of the mode.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of spins of the register of the mode.
+    pub fn number_spins_per_mode(&self, cutoff: usize) -> usize {
+        match self {
+            BosonSpinEncoding::Unary => cutoff + 1,
+            BosonSpinEncoding::Binary | BosonSpinEncoding::Gray => {
+                (usize::BITS - cutoff.leading_zeros()) as usize
+            }
+        }
+    }
+
+    /// Returns the state of a spin of the register encoding an occupation.
+    fn code_bit(&self, occupation: usize, spin: usize) -> bool {
+        match self {
+            BosonSpinEncoding::Unary => occupation == spin,
+            BosonSpinEncoding::Binary => (occupation >> spin) & 1 == 1,
+            BosonSpinEncoding::Gray => ((occupation ^ (occupation >> 1)) >> spin) & 1 == 1,
+        }
+    }
+
+    /// Returns the penalty of the spin states outside of the code space.
+    ///
+    /// The penalty is the sum over all modes of one minus the projector onto the encoded occupations
+    /// of the mode. It vanishes on the code space and is one for every mode whose register is not in an encoded state,
+    /// so that it can be added with a large prefactor to the result of a mapping.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of bosonic modes.
+    /// * `cutoff` - The maximal occupation of the modes.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PauliHamiltonian)` - The penalty.
+    /// * `Err(StruqtureError::GenericError)` - The number of cutoffs does not match the number of modes.
+    pub fn code_space_penalty(
+        &self,
+        number_modes: usize,
+        cutoff: impl Into<BosonCutoff>,
+    ) -> Result<PauliHamiltonian, StruqtureError> {
+        let registers = BosonSpinRegisters::new(*self, number_modes, cutoff.into(), 0)?;
+        let mut penalty = PauliOperator::new();
+        for mode in 0..number_modes {
+            penalty.add_operator_product(PauliProduct::new(), 1.0.into())?;
+            for occupation in 0..=registers.cutoffs[mode] {
+                penalty = penalty - registers.transition(mode, occupation, occupation, true);
+            }
+        }
+        hermitian_part(&penalty)
+    }
+}
+
+pub trait BosonToSpinEncoding {
+    /// The Output type for the encoding of bosons in spins
+    ///
+    /// For a BosonProduct or BosonOperator it will be a PauliOperator
+    /// For a HermitianBosonProduct or BosonHamiltonian it will be a PauliHamiltonian
+    /// For a BosonLindbladNoiseOperator it will be a PauliLindbladNoiseOperator
+    type Output;
+
+    /// Transforms the given bosonic object into a spin object by encoding every truncated mode in a register of spins.
+    ///
+    /// # Arguments
+    ///
+    /// * `encoding` - The encoding of the occupation of a mode.
+    /// * `number_modes` - The number of bosonic modes.
+    /// * `cutoff` - The maximal occupation of the modes.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self::Output)` - The spin object that results from the encoding.
+    /// * `Err(StruqtureError::GenericError)` - The object acts on more than `number_modes` modes.
+    /// * `Err(StruqtureError::GenericError)` - The number of cutoffs does not match the number of modes.
+    fn encoded_boson_spin_mapping(
+        &self,
+        encoding: BosonSpinEncoding,
+        number_modes: usize,
+        cutoff: impl Into<BosonCutoff>,
+    ) -> Result<Self::Output, StruqtureError>;
+}
+
+/// The registers of spins encoding truncated bosonic modes.
+pub(crate) struct BosonSpinRegisters {
+    /// The encoding of the occupations.
+    encoding: BosonSpinEncoding,
+    /// The maximal occupation of each mode.
+    cutoffs: Vec<usize>,
+    /// The first spin of the register of each mode.
+    offsets: Vec<usize>,
+}
+
+impl BosonSpinRegisters {
+    /// Creates the registers for the encoding of an object.
+    ///
+    /// # Arguments
+    ///
+    /// * `encoding` - The encoding of the occupation of a mode.
+    /// * `number_modes` - The number of bosonic modes.
+    /// * `cutoff` - The maximal occupation of the modes.
+    /// * `current_number_modes` - The number of modes the object acts on.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The registers.
+    /// * `Err(StruqtureError::GenericError)` - The number of modes is smaller than the number of modes the object acts on or the number of cutoffs does not match the number of modes.
+    pub(crate) fn new(
+        encoding: BosonSpinEncoding,
+        number_modes: usize,
+        cutoff: BosonCutoff,
+        current_number_modes: usize,
+    ) -> Result<Self, StruqtureError> {
+        if number_modes < current_number_modes {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "Cannot encode {number_modes} modes of an object acting on {current_number_modes} modes."
+                ),
+            });
+        }
+        let cutoffs = cutoff.per_mode(number_modes)?;
+        let offsets = cutoffs
+            .iter()
+            .scan(0, |offset, cutoff| {
+                let first = *offset;
+                *offset += encoding.number_spins_per_mode(*cutoff);
+                Some(first)
+            })
+            .collect();
+        Ok(Self {
+            encoding,
+            cutoffs,
+            offsets,
+        })
+    }
+
+    /// Returns the spin operator of the transition between two occupations of a mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The bosonic mode.
+    /// * `from` - The occupation before the transition.
+    /// * `to` - The occupation after the transition.
+    /// * `full_register` - Whether to act on every spin of a unary register instead of only on the spins of the two occupations.
+    ///
+    /// # Returns
+    ///
+    /// * `PauliOperator` - The operator |to><from| on the register of the mode.
+    fn transition(
+        &self,
+        mode: usize,
+        from: usize,
+        to: usize,
+        full_register: bool,
+    ) -> PauliOperator {
+        let spins: Vec<usize> = match (self.encoding, full_register) {
+            // Within the code space a unary transition only changes the spins of the two occupations
+            (BosonSpinEncoding::Unary, false) if from == to => vec![from],
+            (BosonSpinEncoding::Unary, false) => vec![from, to],
+            _ => (0..self.encoding.number_spins_per_mode(self.cutoffs[mode])).collect(),
+        };
+        let mut operator = PauliOperator::new();
+        operator
+            .add_operator_product(PauliProduct::new(), 1.0.into())
+            .expect("Internal bug in add_operator_product.");
+        for spin in spins {
+            let qubit = self.offsets[mode] + spin;
+            let mut factor = PauliOperator::new();
+            let terms = match (
+                self.encoding.code_bit(from, spin),
+                self.encoding.code_bit(to, spin),
+            ) {
+                (false, false) => [
+                    (PauliProduct::new(), CalculatorComplex::new(0.5, 0.0)),
+                    (
+                        PauliProduct::new().z(qubit),
+                        CalculatorComplex::new(0.5, 0.0),
+                    ),
+                ],
+                (true, true) => [
+                    (PauliProduct::new(), CalculatorComplex::new(0.5, 0.0)),
+                    (
+                        PauliProduct::new().z(qubit),
+                        CalculatorComplex::new(-0.5, 0.0),
+                    ),
+                ],
+                (false, true) => [
+                    (
+                        PauliProduct::new().x(qubit),
+                        CalculatorComplex::new(0.5, 0.0),
+                    ),
+                    (
+                        PauliProduct::new().y(qubit),
+                        CalculatorComplex::new(0.0, -0.5),
+                    ),
+                ],
+                (true, false) => [
+                    (
+                        PauliProduct::new().x(qubit),
+                        CalculatorComplex::new(0.5, 0.0),
+                    ),
+                    (
+                        PauliProduct::new().y(qubit),
+                        CalculatorComplex::new(0.0, 0.5),
+                    ),
+                ],
+            };
+            for (product, value) in terms {
+                factor
+                    .add_operator_product(product, value)
+                    .expect("Internal bug in add_operator_product.");
+            }
+            operator = operator * factor;
+        }
+        operator
+    }
+
+    /// Encodes a BosonProduct.
+    ///
+    /// # Arguments
+    ///
+    /// * `product` - The normal ordered product of creators and annihilators.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PauliOperator)` - The encoded product.
+    /// * `Err(StruqtureError)` - Error adding an operator product.
+    pub(crate) fn encode_product(
+        &self,
+        product: &BosonProduct,
+    ) -> Result<PauliOperator, StruqtureError> {
+        let mut modes: Vec<usize> = product
+            .creators()
+            .chain(product.annihilators())
+            .copied()
+            .collect();
+        modes.sort_unstable();
+        modes.dedup();
+        let mut encoded = PauliOperator::new();
+        encoded.add_operator_product(PauliProduct::new(), 1.0.into())?;
+        for mode in modes {
+            let number_creators = product.creators().filter(|index| **index == mode).count();
+            let number_annihilators = product
+                .annihilators()
+                .filter(|index| **index == mode)
+                .count();
+            let cutoff = self.cutoffs[mode];
+            let mut mode_operator = PauliOperator::new();
+            for from in number_annihilators..=cutoff {
+                let to = from - number_annihilators + number_creators;
+                if to > cutoff {
+                    break;
+                }
+                // The product of the occupations is accumulated exactly before taking the square root
+                let value: f64 = ((from - number_annihilators + 1)..=from)
+                    .chain((from - number_annihilators + 1)..=to)
+                    .map(|occupation| occupation as f64)
+                    .product();
+                mode_operator =
+                    mode_operator + self.transition(mode, from, to, false) * value.sqrt();
+            }
+            encoded = encoded * mode_operator;
+        }
+        Ok(encoded)
+    }
+
+    /// Encodes a sum of BosonProducts.
+    ///
+    /// # Arguments
+    ///
+    /// * `terms` - The BosonProducts and their coefficients.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PauliOperator)` - The encoded sum.
+    /// * `Err(StruqtureError)` - Error adding an operator product.
+    pub(crate) fn encode_terms<'a>(
+        &self,
+        terms: impl Iterator<Item = (&'a BosonProduct, &'a CalculatorComplex)>,
+    ) -> Result<PauliOperator, StruqtureError> {
+        let mut encoded = PauliOperator::new();
+        for (product, value) in terms {
+            for (pauli_product, pauli_value) in self.encode_product(product)? {
+                encoded.add_operator_product(pauli_product, pauli_value * value)?;
+            }
+        }
+        Ok(encoded)
+    }
+}
+
+/// Returns the hermitian PauliOperator as a PauliHamiltonian.
+///
+/// # Arguments
+///
+/// * `operator` - The hermitian PauliOperator.
+///
+/// # Returns
+///
+/// * `Ok(PauliHamiltonian)` - The PauliHamiltonian with the real parts of the coefficients.
+/// * `Err(StruqtureError::NonHermitianOperator)` - The imaginary part of a coefficient does not cancel.
+pub(crate) fn hermitian_part(operator: &PauliOperator) -> Result<PauliHamiltonian, StruqtureError> {
+    let mut hamiltonian = PauliHamiltonian::with_capacity(operator.len());
+    for (product, value) in hermitian_terms(operator.clone(), Some)? {
+        hamiltonian.add_operator_product(product, value.re)?;
+    }
+    Ok(hamiltonian)
+}
@@ -23,13 +23,18 @@
 //!
 //! For a direct mapping, N is set to 1. For a Dicke mapping, N > 1.
 
+use crate::bosons::BosonProduct;
+use crate::spins::PauliProduct;
 use crate::StruqtureError;
+use qoqo_calculator::CalculatorComplex;
 
 pub trait BosonToSpin {
     /// The Output type for the BosonToSpin transformation
     ///
     /// For a HermitianBosonProduct it will be a PauliOperator.
     /// For a BosonHamiltonian it will be a PauliOperator.
+    /// For a MixedOperator, MixedHamiltonian, MixedLindbladNoiseOperator or MixedLindbladOpenSystem it will be
+    /// the same type without bosonic subsystems.
     type Output;
 
     /// Transforms the given bosonic object into a spin object using the direct mapping.
@@ -64,3 +69,56 @@ pub trait BosonToSpin {
         number_spins_per_bosonic_mode: usize,
     ) -> Result<Self::Output, StruqtureError>;
 }
+
+/// Checks that a number of spins per bosonic mode is given for every bosonic subsystem of a mixed object.
+///
+/// # Arguments
+///
+/// * `number_spins_per_bosonic_mode` - The number of spins representing each mode, for every bosonic subsystem.
+/// * `number_bosonic_subsystems` - The number of bosonic subsystems of the mixed object.
+///
+/// # Returns
+///
+/// * `Ok(())` - The numbers of spins match the bosonic subsystems.
+/// * `Err(StruqtureError::GenericError)` - The number of bosonic subsystems does not match.
+pub(crate) fn check_dicke_subsystems(
+    number_spins_per_bosonic_mode: &[usize],
+    number_bosonic_subsystems: usize,
+) -> Result<(), StruqtureError> {
+    if number_spins_per_bosonic_mode.len() != number_bosonic_subsystems {
+        return Err(StruqtureError::GenericError {
+            msg: format!(
+                "The number of spins per bosonic mode is given for {} bosonic subsystems, but the object has {number_bosonic_subsystems} bosonic subsystems.",
+                number_spins_per_bosonic_mode.len(),
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// Maps the bosonic subsystems of a mixed index to spin subsystems with the Dicke mapping.
+///
+/// # Arguments
+///
+/// * `bosons` - The bosonic subsystems of the mixed index.
+/// * `number_spins_per_bosonic_mode` - The number of spins representing each mode, for every bosonic subsystem.
+///
+/// # Returns
+///
+/// * `Ok(Vec<Vec<(PauliProduct, CalculatorComplex)>>)` - The terms of the spin image of every bosonic subsystem.
+/// * `Err(StruqtureError)` - The boson -> spin transformation is only available for
+///   terms such as b†b or (b† + b).
+pub(crate) fn dicke_mapped_subsystems<'a>(
+    bosons: impl Iterator<Item = &'a BosonProduct>,
+    number_spins_per_bosonic_mode: &[usize],
+) -> Result<Vec<Vec<(PauliProduct, CalculatorComplex)>>, StruqtureError> {
+    bosons
+        .zip(number_spins_per_bosonic_mode)
+        .map(|(boson, number_spins)| {
+            Ok(boson
+                .dicke_boson_spin_mapping(*number_spins)?
+                .into_iter()
+                .collect())
+        })
+        .collect()
+}
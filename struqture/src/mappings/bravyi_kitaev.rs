@@ -0,0 +1,150 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Bravyi-Kitaev mapping between fermionic operators and spin operators.
+//!
+//! The qubit j stores the parity of the occupations of the modes (j & (j + 1)) to j, which
+//! are the modes of the binary (Fenwick) tree below j. For a fermionic occupation n the qubit state is
+//!
+//! b_j = \sum_{k = j & (j + 1)}^{j} n_k mod 2
+//!
+//! so that both the parity of all modes below a mode and the occupation of a mode are stored in
+//! a logarithmic number of qubits. As in the Jordan-Wigner mapping, an empty mode corresponds to
+//! $|0 \rangle$ and an occupied mode to $|1\rangle$ when the parity is trivial.
+//!
+//! The update set of a mode depends on the total number of modes, so the mapping is always done
+//! for a given number of modes (spins), which has to be at least the number of modes (spins) the
+//! object acts on.
+
+use crate::spins::{CliffordGate, PauliHamiltonian, PauliOperator};
+use crate::StruqtureError;
+
+pub trait BravyiKitaevFermionToSpin {
+    /// The Output type for the BravyiKitaev transformation
+    ///
+    /// For a FermionProduct or FermionOperator it will be a PauliOperator
+    /// For a HermitianFermionProduct or FermionHamiltonian it will be a PauliHamiltonian
+    /// For a FermionLindbladNoiseOperator it will be a PauliLindbladNoiseOperator
+    /// For a FermionLindbladOpenSystem it will be a PauliLindbladOpenSystem
+    type Output;
+
+    /// Transform the given fermionic object into a spin object using
+    /// the Bravyi Kitaev mapping.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of fermionic modes (and spins) of the mapping.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self::Output)` - The spin object that results from the transformation.
+    /// * `Err(StruqtureError::GenericError)` - The object acts on more than `number_modes` modes.
+    fn bravyi_kitaev(&self, number_modes: usize) -> Result<Self::Output, StruqtureError>;
+}
+
+pub trait BravyiKitaevSpinToFermion {
+    /// The Output type for the BravyiKitaev transformation
+    ///
+    /// For a PauliProduct or PauliOperator it will be a FermionOperator
+    /// For a PauliHamiltonian it will be a FermionHamiltonian
+    /// For a PauliLindbladNoiseOperator it will be a FermionLindbladNoiseOperator
+    /// For a PauliLindbladOpenSystem it will be a FermionLindbladOpenSystem
+    type Output;
+
+    /// Transform the given spin object into a fermionic object using
+    /// the Bravyi Kitaev mapping.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins (and fermionic modes) of the mapping.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self::Output)` - The fermionic object that results from the transformation.
+    /// * `Err(StruqtureError::GenericError)` - The object acts on more than `number_spins` spins.
+    fn bravyi_kitaev(&self, number_spins: usize) -> Result<Self::Output, StruqtureError>;
+}
+
+/// Returns the controlled NOT gates transforming the Jordan-Wigner basis into the Bravyi-Kitaev basis.
+///
+/// Conjugating the Jordan-Wigner image of an operator with the gates gives its Bravyi-Kitaev image.
+///
+/// # Arguments
+///
+/// * `number_modes` - The number of modes of the mapping.
+/// * `current_number_modes` - The number of modes the mapped object acts on.
+///
+/// # Returns
+///
+/// * `Ok(Vec<CliffordGate>)` - The gates in the order they are applied.
+/// * `Err(StruqtureError::GenericError)` - The object acts on more than `number_modes` modes.
+pub(crate) fn bravyi_kitaev_gates(
+    number_modes: usize,
+    current_number_modes: usize,
+) -> Result<Vec<CliffordGate>, StruqtureError> {
+    if current_number_modes > number_modes {
+        return Err(StruqtureError::GenericError {
+            msg: format!("Cannot apply the Bravyi-Kitaev mapping for {number_modes} modes to an object acting on {current_number_modes} modes or spins."),
+        });
+    }
+    // Targets are processed from the last qubit, so every control still holds its own occupation
+    let mut gates = Vec::new();
+    for target in (0..number_modes).rev() {
+        for control in (target & (target + 1))..target {
+            gates.push(CliffordGate::ControlledNot { control, target });
+        }
+    }
+    Ok(gates)
+}
+
+/// Conjugates a PauliHamiltonian with a sequence of controlled NOT gates.
+///
+/// Controlled NOT gates map pauli products onto pauli products with a real sign, so the
+/// conjugated operator is a PauliHamiltonian again.
+///
+/// # Arguments
+///
+/// * `hamiltonian` - The PauliHamiltonian to conjugate.
+/// * `gates` - The controlled NOT gates in the order they are applied.
+///
+/// # Returns
+///
+/// * `Ok(PauliHamiltonian)` - The conjugated PauliHamiltonian.
+/// * `Err(StruqtureError::GenericError)` - A gate acts twice on the same spin.
+pub(crate) fn conjugate_with_controlled_nots(
+    hamiltonian: PauliHamiltonian,
+    gates: &[CliffordGate],
+) -> Result<PauliHamiltonian, StruqtureError> {
+    let conjugated = PauliOperator::from(hamiltonian).conjugate_with_clifford_sequence(gates)?;
+    PauliHamiltonian::try_from(conjugated)
+}
+
+/// Returns the controlled NOT gates transforming the Bravyi-Kitaev basis into the Jordan-Wigner basis.
+///
+/// # Arguments
+///
+/// * `number_spins` - The number of spins of the mapping.
+/// * `current_number_spins` - The number of spins the mapped object acts on.
+///
+/// # Returns
+///
+/// * `Ok(Vec<CliffordGate>)` - The gates in the order they are applied.
+/// * `Err(StruqtureError::GenericError)` - The object acts on more than `number_spins` spins.
+pub(crate) fn inverse_bravyi_kitaev_gates(
+    number_spins: usize,
+    current_number_spins: usize,
+) -> Result<Vec<CliffordGate>, StruqtureError> {
+    let mut gates = bravyi_kitaev_gates(number_spins, current_number_spins)?;
+    // Controlled NOT gates are their own inverse
+    gates.reverse();
+    Ok(gates)
+}
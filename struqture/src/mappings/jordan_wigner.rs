@@ -25,6 +25,9 @@ pub trait JordanWignerFermionToSpin {
     /// For a FermionHamiltonian it will be a PauliHamiltonian
     /// For a FermionLindbladNoiseOperator it will be a PauliLindbladNoiseOperator
     /// For a FermionLindbladOpenSystem it will be a PauliLindbladOpenSystem etc.
+    /// For a MixedProduct or MixedOperator it will be a MixedOperator
+    /// For a HermitianMixedProduct or MixedHamiltonian it will be a MixedHamiltonian
+    /// For mixed noise operators and open systems it will be the same type without fermionic subsystems
     type Output;
 
     /// Transform the given fermionic object into a spin object using
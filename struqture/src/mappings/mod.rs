@@ -12,9 +12,266 @@
 
 //! Module for representing mappings between systems of bosons, fermions and spins.
 
+use crate::symbolic_sum::{SymbolicSum, RELATIVE_CANCELLATION_TOLERANCE};
+use crate::{StruqtureError, SymmetricIndex};
+use indexmap::IndexMap;
+use num_complex::Complex64;
+use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
+use std::fmt::Display;
+use std::hash::Hash;
+
 pub mod jordan_wigner;
 pub use jordan_wigner::JordanWignerFermionToSpin;
 pub use jordan_wigner::JordanWignerSpinToFermion;
 
+pub mod bravyi_kitaev;
+pub use bravyi_kitaev::BravyiKitaevFermionToSpin;
+pub use bravyi_kitaev::BravyiKitaevSpinToFermion;
+
+pub mod parity;
+pub use parity::ParityFermionToSpin;
+
 pub mod boson_spin;
 pub use boson_spin::BosonToSpin;
+
+pub mod spin_boson;
+pub use spin_boson::SpinToBoson;
+
+pub mod boson_encodings;
+pub use boson_encodings::{BosonSpinEncoding, BosonToSpinEncoding};
+
+/// Combines the spin subsystems of a mixed index with the spin images of its mapped subsystems.
+///
+/// The images of the mapped (fermionic or bosonic) subsystems are appended to the spin subsystems in
+/// their order, so that every combination of terms gives the spin subsystems of one term of the mapped
+/// mixed object.
+///
+/// # Arguments
+///
+/// * `spins` - The spin subsystems of the mixed index.
+/// * `mapped_subsystems` - The terms of the spin image of every mapped subsystem.
+///
+/// # Returns
+///
+/// * `Vec<(Vec<T>, CalculatorComplex)>` - The spin subsystems and coefficients of the mapped terms.
+pub(crate) fn combine_mapped_subsystems<T: Clone>(
+    spins: Vec<T>,
+    mapped_subsystems: Vec<Vec<(T, CalculatorComplex)>>,
+) -> Vec<(Vec<T>, CalculatorComplex)> {
+    let mut terms = vec![(spins, CalculatorComplex::new(1.0, 0.0))];
+    for mapped_subsystem in mapped_subsystems {
+        let mut new_terms = Vec::with_capacity(terms.len() * mapped_subsystem.len());
+        for (spins, value) in terms.iter() {
+            for (product, mapped_value) in mapped_subsystem.iter() {
+                let mut new_spins = spins.clone();
+                new_spins.push(product.clone());
+                new_terms.push((new_spins, value.clone() * mapped_value));
+            }
+        }
+        terms = new_terms;
+    }
+    terms
+}
+
+/// Returns the terms of a hermitian operator that are stored in the corresponding Hamiltonian.
+///
+/// A Hamiltonian only stores one product of every pair of hermitian conjugated products, the one for
+/// which `hermitian_product` returns a hermitian product. The coefficients of naturally hermitian
+/// products are real up to rounding errors, so only their real parts are kept. Symbolic imaginary
+/// parts cannot be compared with zero and are assumed to cancel.
+///
+/// # Arguments
+///
+/// * `terms` - The products and coefficients of the hermitian operator.
+/// * `hermitian_product` - Returns the hermitian product of a product or None for the dropped product of a pair.
+///
+/// # Returns
+///
+/// * `Ok(Vec<(H, CalculatorComplex)>)` - The hermitian products and coefficients of the Hamiltonian.
+/// * `Err(StruqtureError::NonHermitianOperator)` - The imaginary part of the coefficient of a naturally hermitian product does not cancel.
+pub(crate) fn hermitian_terms<P, H: SymmetricIndex>(
+    terms: impl IntoIterator<Item = (P, CalculatorComplex)>,
+    hermitian_product: impl Fn(P) -> Option<H>,
+) -> Result<Vec<(H, CalculatorComplex)>, StruqtureError> {
+    let mut hermitian_terms = Vec::new();
+    for (product, value) in terms {
+        let Some(hermitian_product) = hermitian_product(product) else {
+            continue;
+        };
+        if !hermitian_product.is_natural_hermitian() {
+            hermitian_terms.push((hermitian_product, value));
+            continue;
+        }
+        let imaginary_part_cancels = match (&value.re, &value.im) {
+            (CalculatorFloat::Float(re), CalculatorFloat::Float(im)) => {
+                im.abs() <= RELATIVE_CANCELLATION_TOLERANCE * re.abs()
+            }
+            (CalculatorFloat::Str(_), CalculatorFloat::Float(im)) => *im == 0.0,
+            (_, CalculatorFloat::Str(_)) => true,
+        };
+        if !imaginary_part_cancels {
+            return Err(StruqtureError::NonHermitianOperator);
+        }
+        hermitian_terms.push((hermitian_product, CalculatorComplex::new(value.re, 0.0)));
+    }
+    Ok(hermitian_terms)
+}
+
+/// The coherent part of the noise terms with an identity on one side.
+///
+/// The noise operators only contain terms with non-identity operators on both sides. The term with
+/// the rate γ and the operators (I, R) together with the term with the rate γ' and the operators (R, I)
+/// acts on the density matrix as the commutator with the Hamiltonian i (γ' R - γ R^†) / 2. For a
+/// noise operator with real rates this Hamiltonian vanishes and the terms can be dropped.
+#[derive(Debug, Clone)]
+pub(crate) struct IdentityNoiseTerms<T> {
+    /// The coefficient of every product in the Hamiltonian, up to the factor i / 2.
+    coherent_part: IndexMap<T, SymbolicSum>,
+}
+
+impl<T: SymmetricIndex + Hash + Eq + Display> IdentityNoiseTerms<T> {
+    /// Creates an empty collection of noise terms with an identity on one side.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The empty IdentityNoiseTerms.
+    pub(crate) fn new() -> Self {
+        Self {
+            coherent_part: IndexMap::new(),
+        }
+    }
+
+    /// Adds a noise term with the identity on the left side.
+    ///
+    /// # Arguments
+    ///
+    /// * `right` - The operator on the right side of the noise term.
+    /// * `rate` - The rate of the noise term.
+    pub(crate) fn add_left_identity(&mut self, right: &T, rate: &CalculatorComplex) {
+        let (conjugate, sign) = right.hermitian_conjugate();
+        self.coherent_part
+            .entry(conjugate)
+            .or_default()
+            .add_complex(rate, Complex64::new(-sign, 0.0));
+    }
+
+    /// Adds a noise term with the identity on the right side.
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - The operator on the left side of the noise term.
+    /// * `rate` - The rate of the noise term.
+    pub(crate) fn add_right_identity(&mut self, left: &T, rate: &CalculatorComplex) {
+        self.coherent_part
+            .entry(left.clone())
+            .or_default()
+            .add_complex(rate, Complex64::new(1.0, 0.0));
+    }
+
+    /// Checks that the noise terms with an identity on one side can be dropped.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The coherent part of the noise terms vanishes.
+    /// * `Err(StruqtureError::GenericError)` - The noise terms with an identity on one side have a coherent part.
+    pub(crate) fn check_dropped(&self) -> Result<(), StruqtureError> {
+        match self
+            .coherent_part
+            .iter()
+            .find(|(_, coefficient)| !coefficient.cancels())
+        {
+            Some((product, _)) => Err(StruqtureError::GenericError {
+                msg: format!(
+                    "The noise terms with an identity on one side act as a Hamiltonian on {product}, which cannot be represented in the noise operator."
+                ),
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fermions::{FermionOperator, FermionProduct, HermitianFermionProduct};
+    use crate::{ModeIndex, OperateOnDensityMatrix};
+
+    fn hermitian_fermion_terms(
+        operator: FermionOperator,
+    ) -> Result<Vec<(HermitianFermionProduct, CalculatorComplex)>, StruqtureError> {
+        hermitian_terms(operator, |product| {
+            HermitianFermionProduct::new(
+                product.creators().copied(),
+                product.annihilators().copied(),
+            )
+            .ok()
+        })
+    }
+
+    // Test that hermitian_terms keeps one product of every hermitian conjugated pair
+    #[test]
+    fn hermitian_terms_pairs() {
+        let mut operator = FermionOperator::new();
+        operator
+            .add_operator_product(
+                FermionProduct::new([0], [1]).unwrap(),
+                CalculatorComplex::new(1.0, 2.0),
+            )
+            .unwrap();
+        operator
+            .add_operator_product(
+                FermionProduct::new([1], [0]).unwrap(),
+                CalculatorComplex::new(1.0, -2.0),
+            )
+            .unwrap();
+        operator
+            .add_operator_product(
+                FermionProduct::new([0], [0]).unwrap(),
+                CalculatorComplex::new(0.1 + 0.2, 1e-17),
+            )
+            .unwrap();
+        operator
+            .add_operator_product(
+                FermionProduct::new([1], [1]).unwrap(),
+                CalculatorComplex::new("J", "K"),
+            )
+            .unwrap();
+
+        assert_eq!(
+            hermitian_fermion_terms(operator).unwrap(),
+            vec![
+                (
+                    HermitianFermionProduct::new([0], [1]).unwrap(),
+                    CalculatorComplex::new(1.0, 2.0)
+                ),
+                (
+                    HermitianFermionProduct::new([0], [0]).unwrap(),
+                    CalculatorComplex::new(0.1 + 0.2, 0.0)
+                ),
+                (
+                    HermitianFermionProduct::new([1], [1]).unwrap(),
+                    CalculatorComplex::new("J", 0.0)
+                ),
+            ]
+        );
+    }
+
+    // Test that hermitian_terms fails if the imaginary part of a naturally hermitian product does not cancel
+    #[test]
+    fn hermitian_terms_non_hermitian() {
+        for value in [
+            CalculatorComplex::new(1.0, 1e-10),
+            CalculatorComplex::new(0.0, 1e-20),
+            CalculatorComplex::new("J", 1.0),
+        ] {
+            let mut operator = FermionOperator::new();
+            operator
+                .add_operator_product(FermionProduct::new([0], [0]).unwrap(), value)
+                .unwrap();
+            assert_eq!(
+                hermitian_fermion_terms(operator),
+                Err(StruqtureError::NonHermitianOperator)
+            );
+        }
+    }
+}
@@ -0,0 +1,290 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Parity mapping from fermionic operators to spin operators.
+//!
+//! The qubit j stores the parity of the occupations of the modes 0 to j,
+//!
+//! b_j = \sum_{k = 0}^{j} n_k mod 2
+//!
+//! so that $|0 \rangle$ represents an even and $|1\rangle$ an odd parity.
+//!
+//! For N modes the two-qubit reduction uses the block spin-orbital convention: the modes 0 to N/2 - 1
+//! are the spin-up and the modes N/2 to N - 1 the spin-down spin-orbitals. The qubit N/2 - 1 then stores
+//! the parity of the spin-up fermions and the qubit N - 1 the total parity. For an operator that
+//! conserves both parities these qubits are only acted on with Z operators, which are replaced by their
+//! eigenvalues for the given particle numbers, and the qubits are removed. The qubits N/2 to N - 2 are
+//! moved to N/2 - 1 to N - 3.
+
+use crate::mappings::IdentityNoiseTerms;
+use crate::spins::{
+    CliffordGate, DecoherenceProduct, PauliLindbladNoiseOperator, PauliOperator, PauliProduct,
+    SingleDecoherenceOperator, SinglePauliOperator,
+};
+use crate::{OperateOnDensityMatrix, SpinIndex, StruqtureError};
+
+pub trait ParityFermionToSpin {
+    /// The Output type for the parity transformation
+    ///
+    /// For a FermionProduct or FermionOperator it will be a PauliOperator
+    /// For a HermitianFermionProduct or FermionHamiltonian it will be a PauliHamiltonian
+    /// For a FermionLindbladNoiseOperator it will be a PauliLindbladNoiseOperator
+    /// For a FermionLindbladOpenSystem it will be a PauliLindbladOpenSystem
+    type Output;
+
+    /// Transform the given fermionic object into a spin object using the parity mapping.
+    ///
+    /// The two-qubit reduction of a noise operator drops the terms that have an identity on one side
+    /// after the reduction. These terms only cancel for real rates, otherwise they act as a Hamiltonian
+    /// and the reduction returns an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of fermionic modes (and spins before the reduction) of the mapping.
+    /// * `particle_numbers` - The numbers of spin-up and spin-down fermions for the two-qubit reduction, no reduction if None.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self::Output)` - The spin object that results from the transformation.
+    /// * `Err(StruqtureError::GenericError)` - The object acts on more than `number_modes` modes.
+    /// * `Err(StruqtureError::GenericError)` - The two-qubit reduction is not possible.
+    /// * `Err(StruqtureError::GenericError)` - The reduced noise terms with an identity on one side have a coherent part.
+    fn parity_mapping(
+        &self,
+        number_modes: usize,
+        particle_numbers: Option<(usize, usize)>,
+    ) -> Result<Self::Output, StruqtureError>;
+}
+
+/// Returns the controlled NOT gates transforming the Jordan-Wigner basis into the parity basis.
+///
+/// Conjugating the Jordan-Wigner image of an operator with the gates gives its parity image.
+///
+/// # Arguments
+///
+/// * `number_modes` - The number of modes of the mapping.
+/// * `current_number_modes` - The number of modes the mapped object acts on.
+///
+/// # Returns
+///
+/// * `Ok(Vec<CliffordGate>)` - The gates in the order they are applied.
+/// * `Err(StruqtureError::GenericError)` - The object acts on more than `number_modes` modes.
+pub(crate) fn parity_gates(
+    number_modes: usize,
+    current_number_modes: usize,
+) -> Result<Vec<CliffordGate>, StruqtureError> {
+    if current_number_modes > number_modes {
+        return Err(StruqtureError::GenericError {
+            msg: format!("Cannot apply the parity mapping for {number_modes} modes to an object acting on {current_number_modes} modes."),
+        });
+    }
+    // Every qubit adds the parity of the modes below, which the previous qubit already holds
+    Ok((1..number_modes)
+        .map(|target| CliffordGate::ControlledNot {
+            control: target - 1,
+            target,
+        })
+        .collect())
+}
+
+/// The qubits removed by the two-qubit reduction together with the eigenvalues of their Z operators.
+struct TwoQubitReduction {
+    /// The qubit storing the parity of the spin-up fermions.
+    spin_up_qubit: usize,
+    /// The qubit storing the total parity.
+    total_qubit: usize,
+    /// The eigenvalue of Z on the spin-up parity qubit.
+    spin_up_sign: f64,
+    /// The eigenvalue of Z on the total parity qubit.
+    total_sign: f64,
+}
+
+impl TwoQubitReduction {
+    /// Creates the reduction for the given number of modes and particle numbers.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of modes of the mapping.
+    /// * `particle_numbers` - The numbers of spin-up and spin-down fermions.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The two-qubit reduction.
+    /// * `Err(StruqtureError::GenericError)` - The number of modes is odd or zero.
+    /// * `Err(StruqtureError::GenericError)` - There are more fermions of one spin than spin-orbitals.
+    fn new(number_modes: usize, particle_numbers: (usize, usize)) -> Result<Self, StruqtureError> {
+        if number_modes == 0 || number_modes % 2 != 0 {
+            return Err(StruqtureError::GenericError {
+                msg: format!("The two-qubit reduction requires a positive even number of modes, got {number_modes}."),
+            });
+        }
+        let (number_up, number_down) = particle_numbers;
+        if number_up > number_modes / 2 || number_down > number_modes / 2 {
+            return Err(StruqtureError::GenericError {
+                msg: format!("Cannot place {number_up} spin-up and {number_down} spin-down fermions in {} spin-orbitals of each spin.", number_modes / 2),
+            });
+        }
+        let sign = |number: usize| if number % 2 == 0 { 1.0 } else { -1.0 };
+        Ok(Self {
+            spin_up_qubit: number_modes / 2 - 1,
+            total_qubit: number_modes - 1,
+            spin_up_sign: sign(number_up),
+            total_sign: sign(number_up + number_down),
+        })
+    }
+
+    /// Returns the eigenvalue of Z on a removed qubit, or None for a remaining qubit.
+    fn removed_sign(&self, qubit: usize) -> Option<f64> {
+        if qubit == self.spin_up_qubit {
+            Some(self.spin_up_sign)
+        } else if qubit == self.total_qubit {
+            Some(self.total_sign)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the new index of a remaining qubit.
+    fn new_qubit(&self, qubit: usize) -> usize {
+        if qubit > self.spin_up_qubit {
+            qubit - 1
+        } else {
+            qubit
+        }
+    }
+
+    /// Returns the error for a qubit that is not only acted on with Z operators.
+    fn not_conserved(&self, qubit: usize) -> StruqtureError {
+        StruqtureError::GenericError {
+            msg: format!("Cannot remove qubit {qubit} in the two-qubit reduction, the operator does not conserve the parity stored in it."),
+        }
+    }
+
+    /// Reduces a PauliProduct.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((PauliProduct, f64))` - The PauliProduct on the remaining qubits and the eigenvalue of the removed Z operators.
+    /// * `Err(StruqtureError::GenericError)` - The PauliProduct acts with X or Y on a removed qubit.
+    fn reduce_pauli_product(
+        &self,
+        product: &PauliProduct,
+    ) -> Result<(PauliProduct, f64), StruqtureError> {
+        let mut reduced = PauliProduct::new();
+        let mut sign = 1.0;
+        for (qubit, pauli) in product.iter() {
+            match (self.removed_sign(*qubit), pauli) {
+                (Some(removed_sign), SinglePauliOperator::Z) => sign *= removed_sign,
+                (Some(_), SinglePauliOperator::Identity) => (),
+                (Some(_), _) => return Err(self.not_conserved(*qubit)),
+                (None, pauli) => reduced = reduced.set_pauli(self.new_qubit(*qubit), *pauli),
+            }
+        }
+        Ok((reduced, sign))
+    }
+
+    /// Reduces a DecoherenceProduct.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((DecoherenceProduct, f64))` - The DecoherenceProduct on the remaining qubits and the eigenvalue of the removed Z operators.
+    /// * `Err(StruqtureError::GenericError)` - The DecoherenceProduct acts with X or iY on a removed qubit.
+    fn reduce_decoherence_product(
+        &self,
+        product: &DecoherenceProduct,
+    ) -> Result<(DecoherenceProduct, f64), StruqtureError> {
+        let mut reduced = DecoherenceProduct::new();
+        let mut sign = 1.0;
+        for (qubit, operator) in product.iter() {
+            match (self.removed_sign(*qubit), operator) {
+                (Some(removed_sign), SingleDecoherenceOperator::Z) => sign *= removed_sign,
+                (Some(_), SingleDecoherenceOperator::Identity) => (),
+                (Some(_), _) => return Err(self.not_conserved(*qubit)),
+                (None, operator) => reduced = reduced.set_pauli(self.new_qubit(*qubit), *operator),
+            }
+        }
+        Ok((reduced, sign))
+    }
+}
+
+/// Applies the two-qubit reduction to the parity image of a fermionic operator.
+///
+/// # Arguments
+///
+/// * `operator` - The parity image of the fermionic operator.
+/// * `number_modes` - The number of modes of the mapping.
+/// * `particle_numbers` - The numbers of spin-up and spin-down fermions, the operator is returned unchanged if None.
+///
+/// # Returns
+///
+/// * `Ok(PauliOperator)` - The reduced PauliOperator.
+/// * `Err(StruqtureError::GenericError)` - The two-qubit reduction is not possible.
+pub(crate) fn two_qubit_reduced_operator(
+    operator: PauliOperator,
+    number_modes: usize,
+    particle_numbers: Option<(usize, usize)>,
+) -> Result<PauliOperator, StruqtureError> {
+    let Some(particle_numbers) = particle_numbers else {
+        return Ok(operator);
+    };
+    let reduction = TwoQubitReduction::new(number_modes, particle_numbers)?;
+    let mut reduced = PauliOperator::with_capacity(operator.len());
+    for (product, value) in operator.iter() {
+        let (reduced_product, sign) = reduction.reduce_pauli_product(product)?;
+        reduced.add_operator_product(reduced_product, value.clone() * sign)?;
+    }
+    Ok(reduced)
+}
+
+/// Applies the two-qubit reduction to the parity image of a fermionic noise operator.
+///
+/// The terms that have an identity on one side after the reduction are dropped, which is only
+/// possible if they have no coherent part.
+///
+/// # Arguments
+///
+/// * `noise` - The parity image of the fermionic noise operator.
+/// * `number_modes` - The number of modes of the mapping.
+/// * `particle_numbers` - The numbers of spin-up and spin-down fermions, the noise operator is returned unchanged if None.
+///
+/// # Returns
+///
+/// * `Ok(PauliLindbladNoiseOperator)` - The reduced PauliLindbladNoiseOperator.
+/// * `Err(StruqtureError::GenericError)` - The two-qubit reduction is not possible.
+/// * `Err(StruqtureError::GenericError)` - The terms with an identity on one side after the reduction have a coherent part.
+pub(crate) fn two_qubit_reduced_noise(
+    noise: PauliLindbladNoiseOperator,
+    number_modes: usize,
+    particle_numbers: Option<(usize, usize)>,
+) -> Result<PauliLindbladNoiseOperator, StruqtureError> {
+    let Some(particle_numbers) = particle_numbers else {
+        return Ok(noise);
+    };
+    let reduction = TwoQubitReduction::new(number_modes, particle_numbers)?;
+    let mut reduced = PauliLindbladNoiseOperator::with_capacity(noise.len());
+    let mut identity_terms = IdentityNoiseTerms::new();
+    for ((left, right), rate) in noise.iter() {
+        let (reduced_left, left_sign) = reduction.reduce_decoherence_product(left)?;
+        let (reduced_right, right_sign) = reduction.reduce_decoherence_product(right)?;
+        let reduced_rate = rate.clone() * (left_sign * right_sign);
+        match (reduced_left.is_empty(), reduced_right.is_empty()) {
+            (true, true) => {}
+            (true, false) => identity_terms.add_left_identity(&reduced_right, &reduced_rate),
+            (false, true) => identity_terms.add_right_identity(&reduced_left, &reduced_rate),
+            (false, false) => {
+                reduced.add_operator_product((reduced_left, reduced_right), reduced_rate)?
+            }
+        }
+    }
+    identity_terms.check_dropped()?;
+    Ok(reduced)
+}
@@ -0,0 +1,283 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Mappings from spin operators to bosonic operators.
+//!
+//! The Pauli matrices of spin i are replaced by the spin-S operators $ \sigma^a_i \rightarrow 2 S^a_i $ and
+//! $ \sigma^{\pm}_i \rightarrow S^{\pm}_i $, which is exact for S = 1/2.
+//!
+//! In the Holstein-Primakoff mapping, the bosonic vacuum of mode i is the state $|0\rangle$ of spin i
+//! (the eigenstate of $ \sigma^z_i $ with eigenvalue +1) and every boson lowers the spin by one:
+//!
+//! $ S^z_i = S - b_i^{\dagger} b_i $
+//! $ S^+_i = \sqrt{2S - b_i^{\dagger} b_i} b_i $
+//! $ S^-_i = b_i^{\dagger} \sqrt{2S - b_i^{\dagger} b_i} $
+//!
+//! The square root is expanded in powers of $ b_i^{\dagger} b_i / 2S $ up to the chosen order, the order 0
+//! giving linear spin-wave theory.
+//!
+//! In the Schwinger-boson mapping, spin i is represented exactly by the modes 2i and 2i + 1 in the
+//! subspace with 2S bosons in the two modes:
+//!
+//! $ S^z_i = (b_{2i}^{\dagger} b_{2i} - b_{2i+1}^{\dagger} b_{2i+1}) / 2 $
+//! $ S^+_i = b_{2i}^{\dagger} b_{2i+1} $
+//! $ S^-_i = b_{2i+1}^{\dagger} b_{2i} $
+
+use crate::bosons::{BosonHamiltonian, BosonOperator, BosonProduct, HermitianBosonProduct};
+use crate::mappings::hermitian_terms;
+use crate::spins::{
+    PauliOperator, PauliProduct, PlusMinusOperator, PlusMinusProduct, SinglePauliOperator,
+    SinglePlusMinusOperator,
+};
+use crate::{ModeIndex, OperateOnDensityMatrix, OperateOnState, SpinIndex, StruqtureError};
+use qoqo_calculator::CalculatorComplex;
+
+pub trait SpinToBoson {
+    /// The Output type for the SpinToBoson transformation
+    ///
+    /// For a PauliProduct, PlusMinusProduct, PauliOperator or PlusMinusOperator it will be a BosonOperator.
+    /// For a PauliHamiltonian it will be a BosonHamiltonian.
+    type Output;
+
+    /// Transforms the given spin object into a bosonic object using the Holstein-Primakoff mapping.
+    ///
+    /// Spin i is mapped to the bosonic mode i.
+    ///
+    /// # Arguments
+    ///
+    /// * `spin` - The spin S, a positive multiple of 1/2.
+    /// * `order` - The highest power of the occupation in the expansion of the square root.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self::Output)` - The bosonic object that results from the transformation.
+    /// * `Err(StruqtureError::GenericError)` - The spin is not a positive multiple of 1/2.
+    fn holstein_primakoff_mapping(
+        &self,
+        spin: f64,
+        order: usize,
+    ) -> Result<Self::Output, StruqtureError>;
+
+    /// Transforms the given spin object into a bosonic object using the Schwinger-boson mapping.
+    ///
+    /// Spin i is mapped to the bosonic modes 2i and 2i + 1.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self::Output)` - The bosonic object that results from the transformation.
+    /// * `Err(StruqtureError)` - Error adding an operator product.
+    fn schwinger_boson_mapping(&self) -> Result<Self::Output, StruqtureError>;
+}
+
+/// The bosonic representation of the spin operators used by a SpinToBoson mapping.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum SpinBosonRepresentation {
+    /// The Holstein-Primakoff expansion for spin S up to the given order.
+    HolsteinPrimakoff { spin: f64, order: usize },
+    /// The Schwinger bosons with two modes per spin.
+    SchwingerBoson,
+}
+
+impl SpinBosonRepresentation {
+    /// Creates the Holstein-Primakoff representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `spin` - The spin S.
+    /// * `order` - The highest power of the occupation in the expansion of the square root.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The Holstein-Primakoff representation.
+    /// * `Err(StruqtureError::GenericError)` - The spin is not a positive multiple of 1/2.
+    pub(crate) fn holstein_primakoff(spin: f64, order: usize) -> Result<Self, StruqtureError> {
+        if !(spin > 0.0 && (2.0 * spin).fract() == 0.0) {
+            return Err(StruqtureError::GenericError {
+                msg: format!("The spin of the Holstein-Primakoff mapping must be a positive multiple of 1/2, got {spin}."),
+            });
+        }
+        Ok(Self::HolsteinPrimakoff { spin, order })
+    }
+
+    /// Returns the image of $ S^+ $ of the given spin.
+    fn raising(&self, index: usize) -> Result<BosonOperator, StruqtureError> {
+        match self {
+            Self::HolsteinPrimakoff { spin, order } => {
+                let number = single_term(&[index], &[index], 1.0)?;
+                let mut power = single_term(&[], &[index], 1.0)?;
+                let mut raising = BosonOperator::new();
+                // The binomial coefficients of the square root (1 - x)^{1/2}
+                let mut coefficient = (2.0 * spin).sqrt();
+                for k in 0..=*order {
+                    raising = raising + power.clone() * coefficient;
+                    coefficient *= -(0.5 - k as f64) / ((k + 1) as f64 * 2.0 * spin);
+                    power = number.clone() * power;
+                }
+                Ok(raising)
+            }
+            Self::SchwingerBoson => single_term(&[2 * index], &[2 * index + 1], 1.0),
+        }
+    }
+
+    /// Returns the image of $ S^- $ of the given spin.
+    fn lowering(&self, index: usize) -> Result<BosonOperator, StruqtureError> {
+        Ok(self.raising(index)?.hermitian_conjugate())
+    }
+
+    /// Returns the image of $ \sigma^z = 2 S^z $ of the given spin.
+    fn polarisation(&self, index: usize) -> Result<BosonOperator, StruqtureError> {
+        match self {
+            Self::HolsteinPrimakoff { spin, .. } => {
+                Ok(single_term(&[], &[], 2.0 * spin)? + single_term(&[index], &[index], -2.0)?)
+            }
+            Self::SchwingerBoson => Ok(single_term(&[2 * index], &[2 * index], 1.0)?
+                + single_term(&[2 * index + 1], &[2 * index + 1], -1.0)?),
+        }
+    }
+
+    /// Maps a PauliProduct to a BosonOperator.
+    ///
+    /// # Arguments
+    ///
+    /// * `product` - The PauliProduct to map.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BosonOperator)` - The normal ordered image of the PauliProduct.
+    /// * `Err(StruqtureError)` - Error adding an operator product.
+    pub(crate) fn map_pauli_product(
+        &self,
+        product: &PauliProduct,
+    ) -> Result<BosonOperator, StruqtureError> {
+        let mut mapped = single_term(&[], &[], 1.0)?;
+        for (index, pauli) in product.iter() {
+            let single = match pauli {
+                SinglePauliOperator::Identity => continue,
+                SinglePauliOperator::X => self.raising(*index)? + self.lowering(*index)?,
+                SinglePauliOperator::Y => {
+                    (self.raising(*index)? - self.lowering(*index)?)
+                        * CalculatorComplex::new(0.0, -1.0)
+                }
+                SinglePauliOperator::Z => self.polarisation(*index)?,
+            };
+            mapped = mapped * single;
+        }
+        Ok(mapped)
+    }
+
+    /// Maps a PlusMinusProduct to a BosonOperator.
+    ///
+    /// # Arguments
+    ///
+    /// * `product` - The PlusMinusProduct to map.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BosonOperator)` - The normal ordered image of the PlusMinusProduct.
+    /// * `Err(StruqtureError)` - Error adding an operator product.
+    pub(crate) fn map_plus_minus_product(
+        &self,
+        product: &PlusMinusProduct,
+    ) -> Result<BosonOperator, StruqtureError> {
+        let mut mapped = single_term(&[], &[], 1.0)?;
+        for (index, operator) in product.iter() {
+            let single = match operator {
+                SinglePlusMinusOperator::Identity => continue,
+                SinglePlusMinusOperator::Plus => self.raising(*index)?,
+                SinglePlusMinusOperator::Minus => self.lowering(*index)?,
+                SinglePlusMinusOperator::Z => self.polarisation(*index)?,
+            };
+            mapped = mapped * single;
+        }
+        Ok(mapped)
+    }
+
+    /// Maps a PauliOperator to a BosonOperator.
+    ///
+    /// # Arguments
+    ///
+    /// * `operator` - The PauliOperator to map.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BosonOperator)` - The normal ordered image of the PauliOperator.
+    /// * `Err(StruqtureError)` - Error adding an operator product.
+    pub(crate) fn map_pauli_operator(
+        &self,
+        operator: &PauliOperator,
+    ) -> Result<BosonOperator, StruqtureError> {
+        let mut mapped = BosonOperator::new();
+        for (product, value) in operator.iter() {
+            mapped = mapped + self.map_pauli_product(product)? * value.clone();
+        }
+        Ok(mapped)
+    }
+
+    /// Maps a PlusMinusOperator to a BosonOperator.
+    ///
+    /// # Arguments
+    ///
+    /// * `operator` - The PlusMinusOperator to map.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BosonOperator)` - The normal ordered image of the PlusMinusOperator.
+    /// * `Err(StruqtureError)` - Error adding an operator product.
+    pub(crate) fn map_plus_minus_operator(
+        &self,
+        operator: &PlusMinusOperator,
+    ) -> Result<BosonOperator, StruqtureError> {
+        let mut mapped = BosonOperator::new();
+        for (product, value) in operator.iter() {
+            mapped = mapped + self.map_plus_minus_product(product)? * value.clone();
+        }
+        Ok(mapped)
+    }
+}
+
+/// Returns the BosonOperator with a single BosonProduct.
+fn single_term(
+    creators: &[usize],
+    annihilators: &[usize],
+    value: f64,
+) -> Result<BosonOperator, StruqtureError> {
+    let mut operator = BosonOperator::new();
+    operator.add_operator_product(
+        BosonProduct::new(creators.iter().copied(), annihilators.iter().copied())?,
+        value.into(),
+    )?;
+    Ok(operator)
+}
+
+/// Returns the hermitian BosonOperator as a BosonHamiltonian.
+///
+/// # Arguments
+///
+/// * `operator` - The hermitian BosonOperator.
+///
+/// # Returns
+///
+/// * `Ok(BosonHamiltonian)` - The BosonHamiltonian of the operator.
+/// * `Err(StruqtureError::NonHermitianOperator)` - The imaginary part of the coefficient of a naturally hermitian product does not cancel.
+pub(crate) fn hermitian_boson_part(
+    operator: &BosonOperator,
+) -> Result<BosonHamiltonian, StruqtureError> {
+    let terms = hermitian_terms(operator.clone(), |product| {
+        HermitianBosonProduct::new(product.creators().copied(), product.annihilators().copied())
+            .ok()
+    })?;
+    let mut hamiltonian = BosonHamiltonian::with_capacity(terms.len());
+    for (hermitian_product, value) in terms {
+        hamiltonian.add_operator_product(hermitian_product, value)?;
+    }
+    Ok(hamiltonian)
+}
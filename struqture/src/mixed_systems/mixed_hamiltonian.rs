@@ -11,14 +11,24 @@
 // limitations under the License.
 
 use super::{
-    HermitianMixedProduct, HermitianOperateOnMixedSystems, MixedIndex, MixedOperator,
-    OperateOnMixedSystems,
+    HermitianMixedProduct, HermitianOperateOnMixedSystems, MixedHilbertSpace, MixedIndex,
+    MixedOperator, MixedPlusMinusProduct, MixedProduct, OperateOnMixedSystems,
+    ToMixedSparseMatrixOperator, ToMixedSparseMatrixSuperOperator,
 };
+use crate::fermions::fermionic_sz_change;
+use crate::mappings::boson_spin::{check_dicke_subsystems, dicke_mapped_subsystems};
+use crate::mappings::{
+    combine_mapped_subsystems, hermitian_terms, BosonToSpin, JordanWignerFermionToSpin,
+};
+use crate::sparse_matrix_assembly::{add_commutator_superoperator, operator_matrix};
+use crate::symbolic_sum::SymbolicSum;
 use crate::{
     ModeIndex, OperateOnDensityMatrix, OperateOnState, SpinIndex, StruqtureError, SymmetricIndex,
 };
+use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt::{self, Write};
 use std::iter::{FromIterator, IntoIterator};
 use std::ops;
@@ -268,6 +278,32 @@ impl OperateOnMixedSystems<'_> for MixedHamiltonian {
     }
 }
 
+impl<'a> ToMixedSparseMatrixOperator<'a> for MixedHamiltonian {
+    // From trait
+    fn sparse_matrix(
+        &'a self,
+        space: &MixedHilbertSpace,
+    ) -> Result<HashMap<(usize, usize), Complex64>, StruqtureError> {
+        space.check_object(self)?;
+        let operator = self.expanded_operator()?;
+        operator_matrix(operator.iter(), space)
+    }
+}
+
+impl<'a> ToMixedSparseMatrixSuperOperator<'a> for MixedHamiltonian {
+    // From trait
+    fn sparse_matrix_superoperator(
+        &'a self,
+        space: &MixedHilbertSpace,
+    ) -> Result<HashMap<(usize, usize), Complex64>, StruqtureError> {
+        space.check_object(self)?;
+        let mut matrix: HashMap<(usize, usize), Complex64> = HashMap::new();
+        let operator = self.expanded_operator()?;
+        add_commutator_superoperator(operator.iter(), space, &mut matrix)?;
+        Ok(matrix)
+    }
+}
+
 /// Implements the default function (Default trait) of MixedHamiltonian (an empty MixedHamiltonian).
 ///
 impl Default for MixedHamiltonian {
@@ -366,6 +402,248 @@ impl MixedHamiltonian {
         }
         Ok(new_operator)
     }
+
+    /// Returns the commutator [self, other] = self * other - other * self.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The MixedHamiltonian to take the commutator with.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(MixedOperator)` - The commutator of the two MixedHamiltonians.
+    /// * `Err(StruqtureError::MismatchedNumberSubsystems)` - Number of subsystems in self and other do not match.
+    pub fn commutator(&self, other: &MixedHamiltonian) -> Result<MixedOperator, StruqtureError> {
+        self.expanded_operator()?
+            .commutator(&other.expanded_operator()?)
+    }
+
+    /// Returns the anticommutator {self, other} = self * other + other * self.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The MixedHamiltonian to take the anticommutator with.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(MixedOperator)` - The anticommutator of the two MixedHamiltonians.
+    /// * `Err(StruqtureError::MismatchedNumberSubsystems)` - Number of subsystems in self and other do not match.
+    pub fn anticommutator(
+        &self,
+        other: &MixedHamiltonian,
+    ) -> Result<MixedOperator, StruqtureError> {
+        self.expanded_operator()?
+            .anticommutator(&other.expanded_operator()?)
+    }
+
+    /// Returns whether the MixedHamiltonian conserves the total Sz of each spin subsystem.
+    ///
+    /// The spin parts are rewritten in terms of raising, lowering and Z operators and every term
+    /// with a non-vanishing coefficient has to contain as many raising as lowering operators in the subsystem.
+    /// The coefficients are summed per expression, so that terms cancel when their symbolic coefficients
+    /// are written identically.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<bool>` - Whether the total Sz of each spin subsystem is conserved.
+    pub fn conserves_total_sz(&self) -> Vec<bool> {
+        let mut sums: HashMap<MixedPlusMinusProduct, SymbolicSum> = HashMap::new();
+        for (key, value) in self.iter() {
+            let product = MixedProduct::new(
+                key.spins().cloned(),
+                key.bosons().cloned(),
+                key.fermions().cloned(),
+            )
+            .expect("Internal error: the parts of a HermitianMixedProduct form a MixedProduct.");
+            if !product.is_natural_hermitian() {
+                let (conjugate, sign) = product.hermitian_conjugate();
+                let expansion: Vec<(MixedPlusMinusProduct, Complex64)> = conjugate.into();
+                for (plus_minus_product, prefactor) in expansion {
+                    sums.entry(plus_minus_product)
+                        .or_default()
+                        .add_complex_conjugate(value, prefactor * sign);
+                }
+            }
+            let expansion: Vec<(MixedPlusMinusProduct, Complex64)> = product.into();
+            for (plus_minus_product, prefactor) in expansion {
+                sums.entry(plus_minus_product)
+                    .or_default()
+                    .add_complex(value, prefactor);
+            }
+        }
+        conserved_in_subsystems(
+            self.n_spins,
+            sums.iter()
+                .filter(|(_, sum)| !sum.cancels())
+                .map(|(product, _)| product.spins()),
+            |product| product.conserves_total_sz(),
+        )
+    }
+
+    /// Returns whether the MixedHamiltonian conserves the Z-parity of each spin subsystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<bool>` - Whether every spin part of each subsystem contains an even number of X and Y operators.
+    pub fn conserves_z_parity(&self) -> Vec<bool> {
+        conserved_in_subsystems(
+            self.n_spins,
+            self.keys().map(|product| product.spins()),
+            |product| product.conserves_z_parity(),
+        )
+    }
+
+    /// Returns whether the MixedHamiltonian conserves the number of bosons of each boson subsystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<bool>` - Whether every boson part of each subsystem contains as many creators as annihilators.
+    pub fn conserves_bosonic_particle_number(&self) -> Vec<bool> {
+        conserved_in_subsystems(
+            self.n_bosons,
+            self.keys().map(|product| product.bosons()),
+            |product| product.number_creators() == product.number_annihilators(),
+        )
+    }
+
+    /// Returns whether the MixedHamiltonian conserves the boson parity of each boson subsystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<bool>` - Whether every boson part of each subsystem contains an even number of creators and annihilators.
+    pub fn conserves_bosonic_parity(&self) -> Vec<bool> {
+        conserved_in_subsystems(
+            self.n_bosons,
+            self.keys().map(|product| product.bosons()),
+            |product| (product.number_creators() + product.number_annihilators()) % 2 == 0,
+        )
+    }
+
+    /// Returns whether the MixedHamiltonian conserves the number of fermions of each fermion subsystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<bool>` - Whether every fermion part of each subsystem contains as many creators as annihilators.
+    pub fn conserves_fermionic_particle_number(&self) -> Vec<bool> {
+        conserved_in_subsystems(
+            self.n_fermions,
+            self.keys().map(|product| product.fermions()),
+            |product| product.number_creators() == product.number_annihilators(),
+        )
+    }
+
+    /// Returns whether the MixedHamiltonian conserves the fermion parity of each fermion subsystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<bool>` - Whether every fermion part of each subsystem contains an even number of creators and annihilators.
+    pub fn conserves_fermionic_parity(&self) -> Vec<bool> {
+        conserved_in_subsystems(
+            self.n_fermions,
+            self.keys().map(|product| product.fermions()),
+            |product| (product.number_creators() + product.number_annihilators()) % 2 == 0,
+        )
+    }
+
+    /// Returns whether the MixedHamiltonian conserves the total Sz of the fermions of each fermion subsystem.
+    ///
+    /// The modes follow the interleaved spin-orbital convention of [crate::fermions::FermionHamiltonian::conserves_fermionic_sz].
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<bool>` - Whether the fermionic Sz of each fermion subsystem is conserved.
+    pub fn conserves_fermionic_sz(&self) -> Vec<bool> {
+        conserved_in_subsystems(
+            self.n_fermions,
+            self.keys().map(|product| product.fermions()),
+            |product| fermionic_sz_change(product) == 0,
+        )
+    }
+
+    /// Expands the MixedHamiltonian into a MixedOperator, adding the hermitian conjugate of every
+    /// term that is not naturally hermitian.
+    pub(crate) fn expanded_operator(&self) -> Result<MixedOperator, StruqtureError> {
+        let mut op = MixedOperator::with_capacity(
+            self.n_spins,
+            self.n_bosons,
+            self.n_fermions,
+            2 * self.len(),
+        );
+        for (key, value) in self.iter() {
+            let mp = MixedProduct::new(
+                key.spins().cloned(),
+                key.bosons().cloned(),
+                key.fermions().cloned(),
+            )?;
+            if !mp.is_natural_hermitian() {
+                let (mp_conj, sign) = mp.hermitian_conjugate();
+                op.add_operator_product(mp_conj, value.conj() * sign)?;
+            }
+            op.add_operator_product(mp, value.clone())?;
+        }
+        Ok(op)
+    }
+
+    /// Transforms the bosonic subsystems into spin subsystems using the Dicke mapping.
+    ///
+    /// Every bosonic subsystem is mapped to a spin subsystem that is appended to the spin subsystems,
+    /// with the number of spins per bosonic mode chosen for every bosonic subsystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins_per_bosonic_mode` - The number of spins representing each mode, for every bosonic subsystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(MixedHamiltonian)` - The mixed Hamiltonian without bosonic subsystems.
+    /// * `Err(StruqtureError::GenericError)` - The number of spins per bosonic mode is not given for every bosonic subsystem.
+    /// * `Err(StruqtureError)` - The boson -> spin transformation is only available for
+    ///   terms such as b†b or (b† + b).
+    pub fn dicke_boson_spin_mapping_per_subsystem(
+        &self,
+        number_spins_per_bosonic_mode: &[usize],
+    ) -> Result<MixedHamiltonian, StruqtureError> {
+        check_dicke_subsystems(number_spins_per_bosonic_mode, self.n_bosons)?;
+        let mut out = MixedHamiltonian::new(self.n_spins + self.n_bosons, 0, self.n_fermions);
+        for (key, value) in self.iter() {
+            let mapped_bosons =
+                dicke_mapped_subsystems(key.bosons(), number_spins_per_bosonic_mode)?;
+            for (spins, mapped_value) in
+                combine_mapped_subsystems(key.spins().cloned().collect(), mapped_bosons)
+            {
+                out.add_operator_product(
+                    HermitianMixedProduct::new(spins, [], key.fermions().cloned())?,
+                    mapped_value * value,
+                )?;
+            }
+        }
+        Ok(out)
+    }
+}
+
+/// Checks term by term whether a quantity of each subsystem is conserved.
+///
+/// # Arguments
+///
+/// * `number_subsystems` - The number of subsystems of the checked type.
+/// * `terms` - The subsystem parts of every term.
+/// * `conserves` - Whether a subsystem part conserves the quantity.
+///
+/// # Returns
+///
+/// * `Vec<bool>` - Whether the quantity is conserved by all terms in each subsystem.
+fn conserved_in_subsystems<'b, T: 'b>(
+    number_subsystems: usize,
+    terms: impl Iterator<Item = std::slice::Iter<'b, T>>,
+    conserves: impl Fn(&T) -> bool,
+) -> Vec<bool> {
+    let mut conserved = vec![true; number_subsystems];
+    for parts in terms {
+        for (subsystem_conserved, part) in conserved.iter_mut().zip(parts) {
+            *subsystem_conserved &= conserves(part);
+        }
+    }
+    conserved
 }
 
 /// Implements the negative sign function of MixedHamiltonian.
@@ -634,6 +912,57 @@ impl fmt::Display for MixedHamiltonian {
     }
 }
 
+impl JordanWignerFermionToSpin for MixedHamiltonian {
+    type Output = MixedHamiltonian;
+
+    /// Implements JordanWignerFermionToSpin for a MixedHamiltonian.
+    ///
+    /// Every fermionic subsystem is mapped to a spin subsystem that is appended to the spin subsystems,
+    /// the resulting MixedHamiltonian has no fermionic subsystems.
+    /// The convention used is that |0> represents an empty fermionic state (spin-orbital),
+    /// and |1> represents an occupied fermionic state.
+    ///
+    /// # Returns
+    ///
+    /// `MixedHamiltonian` - The mixed Hamiltonian that results from the transformation.
+    ///
+    /// # Panics
+    ///
+    /// * Internal bug in `expanded_operator`.
+    /// * Internal bug in `hermitian_terms`.
+    /// * Internal bug in `add_operator_product`.
+    fn jordan_wigner(&self) -> Self::Output {
+        let mut out = MixedHamiltonian::new(self.n_spins + self.n_fermions, self.n_bosons, 0);
+        let expanded = self
+            .expanded_operator()
+            .expect("Internal bug in expanded_operator.");
+        let terms = hermitian_terms(expanded.jordan_wigner(), |mapped| {
+            HermitianMixedProduct::new(mapped.spins, mapped.bosons, []).ok()
+        })
+        .expect("Internal bug in hermitian_terms.");
+        for (hermitian_product, value) in terms {
+            out.add_operator_product(hermitian_product, value)
+                .expect("Internal bug in add_operator_product.");
+        }
+        out
+    }
+}
+
+impl BosonToSpin for MixedHamiltonian {
+    type Output = MixedHamiltonian;
+
+    // From trait
+    fn dicke_boson_spin_mapping(
+        &self,
+        number_spins_per_bosonic_mode: usize,
+    ) -> Result<Self::Output, StruqtureError> {
+        self.dicke_boson_spin_mapping_per_subsystem(&vec![
+            number_spins_per_bosonic_mode;
+            self.n_bosons
+        ])
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -10,8 +10,10 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::MixedHamiltonian;
 use super::{GetValueMixed, MixedIndex, MixedProduct};
 use crate::fermions::FermionProduct;
+use crate::mappings::JordanWignerFermionToSpin;
 use crate::prelude::*;
 use crate::CorrespondsTo;
 use crate::{bosons::BosonProduct, spins::PauliProduct, StruqtureError, SymmetricIndex};
@@ -891,6 +893,33 @@ impl std::fmt::Display for HermitianMixedProduct {
     }
 }
 
+impl JordanWignerFermionToSpin for HermitianMixedProduct {
+    type Output = MixedHamiltonian;
+
+    /// Implements JordanWignerFermionToSpin for a HermitianMixedProduct.
+    ///
+    /// Every fermionic subsystem is mapped to a spin subsystem that is appended to the spin subsystems,
+    /// the resulting MixedHamiltonian has no fermionic subsystems.
+    /// The convention used is that |0> represents an empty fermionic state (spin-orbital),
+    /// and |1> represents an occupied fermionic state.
+    ///
+    /// # Returns
+    ///
+    /// `MixedHamiltonian` - The mixed Hamiltonian that results from the transformation.
+    ///
+    /// # Panics
+    ///
+    /// * Internal bug in `add_operator_product`.
+    fn jordan_wigner(&self) -> Self::Output {
+        let mut hamiltonian =
+            MixedHamiltonian::new(self.spins.len(), self.bosons.len(), self.fermions.len());
+        hamiltonian
+            .add_operator_product(self.clone(), 1.0.into())
+            .expect("Internal bug in add_operator_product.");
+        hamiltonian.jordan_wigner()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
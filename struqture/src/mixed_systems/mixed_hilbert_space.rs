@@ -0,0 +1,267 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{MixedDecoherenceProduct, MixedIndex, MixedProduct, OperateOnMixedSystems};
+use crate::bosons::{BosonCutoff, BosonFockSpace, BosonProduct};
+use crate::fermions::{FermionProduct, FockSpace};
+use crate::sparse_matrix_assembly::{ProductBasis, ProductColumns};
+use crate::spins::BasisStateAction;
+use crate::StruqtureError;
+use num_complex::Complex64;
+
+/// The tensor-product Hilbert space of the spin, boson and fermion subsystems of a mixed system.
+///
+/// Every spin subsystem is represented in the little endian computational basis, every boson subsystem
+/// in the truncated occupation-number basis of [crate::bosons::ToBosonSparseMatrixOperator] and every
+/// fermion subsystem in the full occupation-number basis of [crate::fermions::fermionic_fock_basis].
+///
+/// The subsystems are ordered as spin subsystems, boson subsystems and fermion subsystems, each in the order of
+/// their indices, and the basis of the Hilbert space is little endian with respect to that order:
+/// the index of the basis state with the subsystem indices `i_0, i_1, ...` with dimensions `d_0, d_1, ...` is
+/// `i_0 + d_0 (i_1 + d_1 (i_2 + ...))`.
+/// The matrix of a product is therefore the Kronecker product `A_last ⊗ ... ⊗ A_1 ⊗ A_0` of the matrices of its subsystem parts.
+///
+/// # Example
+/// ```
+/// use struqture::bosons::BosonCutoff;
+/// use struqture::mixed_systems::MixedHilbertSpace;
+///
+/// // Two spins, one bosonic mode with at most three bosons and two fermionic modes
+/// let space = MixedHilbertSpace::new(vec![2], vec![(1, BosonCutoff::Uniform(3))], vec![2]).unwrap();
+/// assert_eq!(space.subsystem_dimensions(), vec![4, 4, 4]);
+/// assert_eq!(space.dimension(), 64);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct MixedHilbertSpace {
+    number_spins: Vec<usize>,
+    bosons: Vec<(usize, BosonFockSpace)>,
+    fermions: Vec<(usize, FockSpace)>,
+    subsystem_dimensions: Vec<usize>,
+    dimension: usize,
+}
+
+impl MixedHilbertSpace {
+    /// Creates a new MixedHilbertSpace.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins of each spin subsystem.
+    /// * `bosons` - The number of modes and the occupation cutoff of each boson subsystem.
+    /// * `number_fermionic_modes` - The number of modes of each fermion subsystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The new MixedHilbertSpace.
+    /// * `Err(StruqtureError::GenericError)` - The number of cutoffs of a boson subsystem does not match its number of modes or the dimension is too large.
+    pub fn new(
+        number_spins: Vec<usize>,
+        bosons: Vec<(usize, BosonCutoff)>,
+        number_fermionic_modes: Vec<usize>,
+    ) -> Result<Self, StruqtureError> {
+        let mut subsystem_dimensions: Vec<usize> = Vec::new();
+        for number_particles in number_spins.iter().chain(number_fermionic_modes.iter()) {
+            if *number_particles >= usize::BITS as usize {
+                return Err(StruqtureError::GenericError {
+                    msg: format!("Cannot construct the Hilbert space of {number_particles} spins or fermionic modes."),
+                });
+            }
+        }
+        subsystem_dimensions.extend(number_spins.iter().map(|spins| 1usize << spins));
+        let bosons = bosons
+            .into_iter()
+            .map(|(modes, cutoff)| Ok((modes, BosonFockSpace::new(modes, cutoff, 0)?)))
+            .collect::<Result<Vec<(usize, BosonFockSpace)>, StruqtureError>>()?;
+        subsystem_dimensions.extend(bosons.iter().map(|(_, space)| space.dimension()));
+        subsystem_dimensions.extend(number_fermionic_modes.iter().map(|modes| 1usize << modes));
+        // The dimension is checked before the fermionic bases are constructed
+        let dimension = subsystem_dimensions
+            .iter()
+            .try_fold(1usize, |dimension, subsystem| {
+                dimension.checked_mul(*subsystem)
+            })
+            .ok_or_else(|| StruqtureError::GenericError {
+                msg: format!(
+                    "Cannot construct the Hilbert space with the subsystem dimensions {subsystem_dimensions:?}."
+                ),
+            })?;
+        let fermions = number_fermionic_modes
+            .into_iter()
+            .map(|modes| Ok((modes, FockSpace::new(modes, None, 0)?)))
+            .collect::<Result<Vec<(usize, FockSpace)>, StruqtureError>>()?;
+        Ok(MixedHilbertSpace {
+            number_spins,
+            bosons,
+            fermions,
+            subsystem_dimensions,
+            dimension,
+        })
+    }
+
+    /// Returns the dimension of the Hilbert space.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The product of the dimensions of all subsystems.
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    /// Returns the dimensions of the subsystems.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<usize>` - The dimensions of the spin, boson and fermion subsystems in the order of the basis.
+    pub fn subsystem_dimensions(&self) -> Vec<usize> {
+        self.subsystem_dimensions.clone()
+    }
+
+    /// Checks that an object can be represented in the Hilbert space.
+    ///
+    /// # Arguments
+    ///
+    /// * `object` - The mixed system to check.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The object can be represented in the Hilbert space.
+    /// * `Err(StruqtureError::MismatchedNumberSubsystems)` - The number of subsystems of the object and the Hilbert space do not match.
+    /// * `Err(StruqtureError::GenericError)` - A subsystem of the object acts on more spins or modes than the Hilbert space contains.
+    pub(crate) fn check_object<'a>(
+        &self,
+        object: &impl OperateOnMixedSystems<'a>,
+    ) -> Result<(), StruqtureError> {
+        let current_number_spins = object.current_number_spins();
+        let current_number_bosonic_modes = object.current_number_bosonic_modes();
+        let current_number_fermionic_modes = object.current_number_fermionic_modes();
+        if current_number_spins.len() != self.number_spins.len()
+            || current_number_bosonic_modes.len() != self.bosons.len()
+            || current_number_fermionic_modes.len() != self.fermions.len()
+        {
+            return Err(StruqtureError::MismatchedNumberSubsystems {
+                target_number_spin_subsystems: self.number_spins.len(),
+                target_number_boson_subsystems: self.bosons.len(),
+                target_number_fermion_subsystems: self.fermions.len(),
+                actual_number_spin_subsystems: current_number_spins.len(),
+                actual_number_boson_subsystems: current_number_bosonic_modes.len(),
+                actual_number_fermion_subsystems: current_number_fermionic_modes.len(),
+            });
+        }
+        let too_small = current_number_spins
+            .iter()
+            .zip(self.number_spins.iter())
+            .chain(
+                current_number_bosonic_modes
+                    .iter()
+                    .zip(self.bosons.iter().map(|(modes, _)| modes)),
+            )
+            .chain(
+                current_number_fermionic_modes
+                    .iter()
+                    .zip(self.fermions.iter().map(|(modes, _)| modes)),
+            )
+            .any(|(current, available)| current > available);
+        if too_small {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "Cannot construct a matrix for an object acting on {current_number_spins:?} spins, {current_number_bosonic_modes:?} bosonic modes and {current_number_fermionic_modes:?} fermionic modes."
+                ),
+            });
+        }
+        Ok(())
+    }
+
+    /// Combines the matrices of the subsystem parts of a product into the matrix on the full Hilbert space.
+    ///
+    /// # Arguments
+    ///
+    /// * `spins` - The actions of the spin parts on the spin subsystems.
+    /// * `bosons` - The boson parts of the product.
+    /// * `fermions` - The fermion parts of the product.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ProductColumns)` - The row and value of the non-zero entry of each column.
+    /// * `Err(StruqtureError)` - A part of the product cannot be represented in its subsystem.
+    fn combined_product_columns<'b>(
+        &self,
+        spins: impl Iterator<Item = BasisStateAction>,
+        bosons: impl Iterator<Item = &'b BosonProduct>,
+        fermions: impl Iterator<Item = &'b FermionProduct>,
+    ) -> Result<ProductColumns, StruqtureError> {
+        let mut subsystem_columns: Vec<ProductColumns> = spins
+            .zip(self.subsystem_dimensions.iter())
+            .map(|(action, dimension)| {
+                (0..*dimension)
+                    .map(|column| action.entry_on_column(column))
+                    .collect()
+            })
+            .collect();
+        for (product, (_, space)) in bosons.zip(self.bosons.iter()) {
+            subsystem_columns.push(space.product_columns(product)?);
+        }
+        for (product, (_, space)) in fermions.zip(self.fermions.iter()) {
+            subsystem_columns.push(space.product_columns(product)?);
+        }
+        // Kronecker product with the first subsystem changing fastest
+        let mut columns: ProductColumns = vec![Some((0, Complex64::new(1.0, 0.0)))];
+        for local_columns in subsystem_columns {
+            let previous_dimension = columns.len();
+            columns = local_columns
+                .iter()
+                .flat_map(|local_entry| {
+                    columns.iter().map(move |entry| match (local_entry, entry) {
+                        (Some((local_row, local_value)), Some((row, value))) => {
+                            Some((row + previous_dimension * local_row, value * local_value))
+                        }
+                        _ => None,
+                    })
+                })
+                .collect();
+        }
+        Ok(columns)
+    }
+}
+
+impl ProductBasis<MixedProduct> for MixedHilbertSpace {
+    // From trait
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    // From trait
+    fn product_columns(&self, product: &MixedProduct) -> Result<ProductColumns, StruqtureError> {
+        self.combined_product_columns(
+            product.spins().map(BasisStateAction::from),
+            product.bosons(),
+            product.fermions(),
+        )
+    }
+}
+
+impl ProductBasis<MixedDecoherenceProduct> for MixedHilbertSpace {
+    // From trait
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    // From trait
+    fn product_columns(
+        &self,
+        product: &MixedDecoherenceProduct,
+    ) -> Result<ProductColumns, StruqtureError> {
+        self.combined_product_columns(
+            product.spins().map(BasisStateAction::from),
+            product.bosons(),
+            product.fermions(),
+        )
+    }
+}
@@ -10,11 +10,20 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{MixedDecoherenceProduct, MixedIndex, OperateOnMixedSystems};
+use super::{
+    MixedDecoherenceProduct, MixedHilbertSpace, MixedIndex, OperateOnMixedSystems,
+    ToMixedSparseMatrixSuperOperator,
+};
+use crate::mappings::boson_spin::{check_dicke_subsystems, dicke_mapped_subsystems};
+use crate::mappings::{combine_mapped_subsystems, BosonToSpin, JordanWignerFermionToSpin};
 use crate::prelude::*;
+use crate::sparse_matrix_assembly::add_lindblad_superoperator;
+use crate::spins::{DecoherenceOperator, DecoherenceProduct, PauliOperator};
 use crate::{OperateOnDensityMatrix, StruqtureError};
+use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt::{self, Write};
 use std::iter::{FromIterator, IntoIterator};
 use std::ops;
@@ -301,6 +310,19 @@ impl OperateOnMixedSystems<'_> for MixedLindbladNoiseOperator {
     }
 }
 
+impl<'a> ToMixedSparseMatrixSuperOperator<'a> for MixedLindbladNoiseOperator {
+    // From trait
+    fn sparse_matrix_superoperator(
+        &'a self,
+        space: &MixedHilbertSpace,
+    ) -> Result<HashMap<(usize, usize), Complex64>, StruqtureError> {
+        space.check_object(self)?;
+        let mut matrix: HashMap<(usize, usize), Complex64> = HashMap::new();
+        add_lindblad_superoperator(self.iter(), space, &mut matrix)?;
+        Ok(matrix)
+    }
+}
+
 /// Implements the default function (Default trait) of MixedLindbladNoiseOperator (an empty MixedLindbladNoiseOperator).
 ///
 impl Default for MixedLindbladNoiseOperator {
@@ -401,6 +423,43 @@ impl MixedLindbladNoiseOperator {
         }
         Ok(new_operator)
     }
+
+    /// Transforms the bosonic subsystems into spin subsystems using the Dicke mapping.
+    ///
+    /// Every bosonic subsystem is mapped to a spin subsystem that is appended to the spin subsystems,
+    /// with the number of spins per bosonic mode chosen for every bosonic subsystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins_per_bosonic_mode` - The number of spins representing each mode, for every bosonic subsystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(MixedLindbladNoiseOperator)` - The mixed noise operator without bosonic subsystems.
+    /// * `Err(StruqtureError::GenericError)` - The number of spins per bosonic mode is not given for every bosonic subsystem.
+    /// * `Err(StruqtureError)` - The boson -> spin transformation is only available for
+    ///   terms such as b†b or (b† + b).
+    pub fn dicke_boson_spin_mapping_per_subsystem(
+        &self,
+        number_spins_per_bosonic_mode: &[usize],
+    ) -> Result<MixedLindbladNoiseOperator, StruqtureError> {
+        check_dicke_subsystems(number_spins_per_bosonic_mode, self.n_bosons)?;
+        let mut out =
+            MixedLindbladNoiseOperator::new(self.n_spins + self.n_bosons, 0, self.n_fermions);
+        for ((left, right), rate) in self.iter() {
+            let left_terms = dicke_decoherence_terms(left, number_spins_per_bosonic_mode)?;
+            let right_terms = dicke_decoherence_terms(right, number_spins_per_bosonic_mode)?;
+            for (left_product, left_value) in left_terms.iter() {
+                for (right_product, right_value) in right_terms.iter() {
+                    out.add_operator_product(
+                        (left_product.clone(), right_product.clone()),
+                        right_value.conj() * left_value * rate,
+                    )?;
+                }
+            }
+        }
+        Ok(out)
+    }
 }
 
 /// Implements the negative sign function of MixedLindbladNoiseOperator.
@@ -673,6 +732,125 @@ impl fmt::Display for MixedLindbladNoiseOperator {
     }
 }
 
+impl JordanWignerFermionToSpin for MixedLindbladNoiseOperator {
+    type Output = MixedLindbladNoiseOperator;
+
+    /// Implements JordanWignerFermionToSpin for a MixedLindbladNoiseOperator.
+    ///
+    /// Every fermionic subsystem is mapped to a spin subsystem that is appended to the spin subsystems,
+    /// the resulting MixedLindbladNoiseOperator has no fermionic subsystems.
+    /// The convention used is that |0> represents an empty fermionic state (spin-orbital),
+    /// and |1> represents an occupied fermionic state.
+    ///
+    /// As for a FermionLindbladNoiseOperator, the mapped terms with an identity on one side are dropped,
+    /// so that the coherent part these terms can have is not contained in the result.
+    ///
+    /// # Returns
+    ///
+    /// `MixedLindbladNoiseOperator` - The mixed noise operator that results from the transformation.
+    ///
+    /// # Panics
+    ///
+    /// * Internal bug in `add_operator_product`.
+    /// * Internal bug in `MixedDecoherenceProduct::new`.
+    fn jordan_wigner(&self) -> Self::Output {
+        let mut out =
+            MixedLindbladNoiseOperator::new(self.n_spins + self.n_fermions, self.n_bosons, 0);
+        for ((left, right), rate) in self.iter() {
+            let left_terms = jordan_wigner_decoherence_terms(left);
+            let right_terms = jordan_wigner_decoherence_terms(right);
+            for (left_product, left_value) in left_terms.iter() {
+                for (right_product, right_value) in right_terms.iter() {
+                    if is_identity(left_product) || is_identity(right_product) {
+                        continue;
+                    }
+                    out.add_operator_product(
+                        (left_product.clone(), right_product.clone()),
+                        right_value.conj() * left_value * rate,
+                    )
+                    .expect("Internal bug in add_operator_product.");
+                }
+            }
+        }
+        out
+    }
+}
+
+/// Maps the fermionic subsystems of a MixedDecoherenceProduct to spin subsystems.
+fn jordan_wigner_decoherence_terms(
+    product: &MixedDecoherenceProduct,
+) -> Vec<(MixedDecoherenceProduct, CalculatorComplex)> {
+    let mapped_fermions: Vec<Vec<(DecoherenceProduct, CalculatorComplex)>> = product
+        .fermions()
+        .map(|fermion| {
+            DecoherenceOperator::from(fermion.jordan_wigner())
+                .into_iter()
+                .collect()
+        })
+        .collect();
+    combine_mapped_subsystems(product.spins().cloned().collect(), mapped_fermions)
+        .into_iter()
+        .map(|(spins, value)| {
+            (
+                MixedDecoherenceProduct::new(spins, product.bosons().cloned(), [])
+                    .expect("Internal bug in MixedDecoherenceProduct::new."),
+                value,
+            )
+        })
+        .collect()
+}
+
+/// Returns true if the MixedDecoherenceProduct acts as the identity on all subsystems.
+fn is_identity(product: &MixedDecoherenceProduct) -> bool {
+    product.spins().all(|spin| spin.is_empty())
+        && product
+            .bosons()
+            .all(|boson| boson.number_creators() == 0 && boson.number_annihilators() == 0)
+        && product
+            .fermions()
+            .all(|fermion| fermion.number_creators() == 0 && fermion.number_annihilators() == 0)
+}
+
+impl BosonToSpin for MixedLindbladNoiseOperator {
+    type Output = MixedLindbladNoiseOperator;
+
+    // From trait
+    fn dicke_boson_spin_mapping(
+        &self,
+        number_spins_per_bosonic_mode: usize,
+    ) -> Result<Self::Output, StruqtureError> {
+        self.dicke_boson_spin_mapping_per_subsystem(&vec![
+            number_spins_per_bosonic_mode;
+            self.n_bosons
+        ])
+    }
+}
+
+/// Maps the bosonic subsystems of a MixedDecoherenceProduct to spin subsystems with the Dicke mapping.
+fn dicke_decoherence_terms(
+    product: &MixedDecoherenceProduct,
+    number_spins_per_bosonic_mode: &[usize],
+) -> Result<Vec<(MixedDecoherenceProduct, CalculatorComplex)>, StruqtureError> {
+    let mapped_bosons: Vec<Vec<(DecoherenceProduct, CalculatorComplex)>> =
+        dicke_mapped_subsystems(product.bosons(), number_spins_per_bosonic_mode)?
+            .into_iter()
+            .map(|terms| {
+                DecoherenceOperator::from(PauliOperator::from_iter(terms))
+                    .into_iter()
+                    .collect()
+            })
+            .collect();
+    combine_mapped_subsystems(product.spins().cloned().collect(), mapped_bosons)
+        .into_iter()
+        .map(|(spins, value)| {
+            Ok((
+                MixedDecoherenceProduct::new(spins, [], product.fermions().cloned())?,
+                value,
+            ))
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -10,10 +10,17 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{MixedHamiltonian, MixedLindbladNoiseOperator, OperateOnMixedSystems};
+use super::{
+    MixedHamiltonian, MixedHilbertSpace, MixedLindbladNoiseOperator, OperateOnMixedSystems,
+    ToMixedSparseMatrixSuperOperator,
+};
+use crate::mappings::{BosonToSpin, JordanWignerFermionToSpin};
+use crate::sparse_matrix_assembly::{add_commutator_superoperator, add_lindblad_superoperator};
 use crate::{OpenSystem, OperateOnDensityMatrix, StruqtureError};
+use num_complex::Complex64;
 use qoqo_calculator::CalculatorFloat;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt;
 use std::ops;
 
@@ -164,6 +171,21 @@ impl OperateOnMixedSystems<'_> for MixedLindbladOpenSystem {
     }
 }
 
+impl<'a> ToMixedSparseMatrixSuperOperator<'a> for MixedLindbladOpenSystem {
+    // From trait
+    fn sparse_matrix_superoperator(
+        &'a self,
+        space: &MixedHilbertSpace,
+    ) -> Result<HashMap<(usize, usize), Complex64>, StruqtureError> {
+        space.check_object(self)?;
+        let mut matrix: HashMap<(usize, usize), Complex64> = HashMap::new();
+        let operator = self.system.expanded_operator()?;
+        add_commutator_superoperator(operator.iter(), space, &mut matrix)?;
+        add_lindblad_superoperator(self.noise.iter(), space, &mut matrix)?;
+        Ok(matrix)
+    }
+}
+
 /// Functions for the MixedLindbladOpenSystem
 ///
 impl MixedLindbladOpenSystem {
@@ -210,6 +232,33 @@ impl MixedLindbladOpenSystem {
         let new_noise = MixedLindbladNoiseOperator::from_struqture_1(&noise_one)?;
         Self::group(new_system, new_noise)
     }
+
+    /// Transforms the bosonic subsystems into spin subsystems using the Dicke mapping.
+    ///
+    /// Every bosonic subsystem is mapped to a spin subsystem that is appended to the spin subsystems,
+    /// with the number of spins per bosonic mode chosen for every bosonic subsystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins_per_bosonic_mode` - The number of spins representing each mode, for every bosonic subsystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(MixedLindbladOpenSystem)` - The mixed open system without bosonic subsystems.
+    /// * `Err(StruqtureError::GenericError)` - The number of spins per bosonic mode is not given for every bosonic subsystem.
+    /// * `Err(StruqtureError)` - The boson -> spin transformation is only available for
+    ///   terms such as b†b or (b† + b).
+    pub fn dicke_boson_spin_mapping_per_subsystem(
+        &self,
+        number_spins_per_bosonic_mode: &[usize],
+    ) -> Result<MixedLindbladOpenSystem, StruqtureError> {
+        Self::group(
+            self.system()
+                .dicke_boson_spin_mapping_per_subsystem(number_spins_per_bosonic_mode)?,
+            self.noise()
+                .dicke_boson_spin_mapping_per_subsystem(number_spins_per_bosonic_mode)?,
+        )
+    }
 }
 
 /// Implements the negative sign function of MixedLindbladOpenSystem.
@@ -322,3 +371,40 @@ impl fmt::Display for MixedLindbladOpenSystem {
         write!(f, "{output}")
     }
 }
+
+impl JordanWignerFermionToSpin for MixedLindbladOpenSystem {
+    type Output = MixedLindbladOpenSystem;
+
+    /// Implements JordanWignerFermionToSpin for a MixedLindbladOpenSystem.
+    ///
+    /// Every fermionic subsystem is mapped to a spin subsystem that is appended to the spin subsystems,
+    /// the resulting MixedLindbladOpenSystem has no fermionic subsystems.
+    /// The convention used is that |0> represents an empty fermionic state (spin-orbital),
+    /// and |1> represents an occupied fermionic state.
+    ///
+    /// # Returns
+    ///
+    /// `MixedLindbladOpenSystem` - The mixed open system that results from the transformation.
+    fn jordan_wigner(&self) -> Self::Output {
+        let jw_system = self.system().jordan_wigner();
+        let jw_noise = self.noise().jordan_wigner();
+        MixedLindbladOpenSystem::group(jw_system, jw_noise).expect(
+            "Internal bug in jordan_wigner() for MixedHamiltonian or MixedLindbladNoiseOperator.",
+        )
+    }
+}
+
+impl BosonToSpin for MixedLindbladOpenSystem {
+    type Output = MixedLindbladOpenSystem;
+
+    // From trait
+    fn dicke_boson_spin_mapping(
+        &self,
+        number_spins_per_bosonic_mode: usize,
+    ) -> Result<Self::Output, StruqtureError> {
+        self.dicke_boson_spin_mapping_per_subsystem(&vec![
+            number_spins_per_bosonic_mode;
+            self.system().n_bosons
+        ])
+    }
+}
@@ -10,10 +10,23 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{MixedIndex, MixedProduct, OperateOnMixedSystems};
-use crate::{ModeIndex, OperateOnDensityMatrix, OperateOnState, SpinIndex, StruqtureError};
+use super::{
+    HermitianMixedProduct, MixedHamiltonian, MixedHilbertSpace, MixedIndex, MixedProduct,
+    OperateOnMixedSystems, ToMixedSparseMatrixOperator, ToMixedSparseMatrixSuperOperator,
+};
+use crate::mappings::boson_spin::check_dicke_subsystems;
+use crate::mappings::{BosonToSpin, JordanWignerFermionToSpin};
+use crate::parallel_iteration::multiply_terms;
+use crate::sparse_matrix_assembly::{add_commutator_superoperator, operator_matrix};
+use crate::symbolic_sum::SymbolicSum;
+use crate::{
+    mode_products_reorder_freely, ModeIndex, OperateOnDensityMatrix, OperateOnState, SpinIndex,
+    StruqtureError, SymmetricIndex,
+};
+use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt::{self, Write};
 use std::iter::{FromIterator, IntoIterator};
 use std::ops;
@@ -252,6 +265,30 @@ impl OperateOnMixedSystems<'_> for MixedOperator {
     }
 }
 
+impl<'a> ToMixedSparseMatrixOperator<'a> for MixedOperator {
+    // From trait
+    fn sparse_matrix(
+        &'a self,
+        space: &MixedHilbertSpace,
+    ) -> Result<HashMap<(usize, usize), Complex64>, StruqtureError> {
+        space.check_object(self)?;
+        operator_matrix(self.iter(), space)
+    }
+}
+
+impl<'a> ToMixedSparseMatrixSuperOperator<'a> for MixedOperator {
+    // From trait
+    fn sparse_matrix_superoperator(
+        &'a self,
+        space: &MixedHilbertSpace,
+    ) -> Result<HashMap<(usize, usize), Complex64>, StruqtureError> {
+        space.check_object(self)?;
+        let mut matrix: HashMap<(usize, usize), Complex64> = HashMap::new();
+        add_commutator_superoperator(self.iter(), space, &mut matrix)?;
+        Ok(matrix)
+    }
+}
+
 /// Implements the default function (Default trait) of MixedOperator (an empty MixedOperator).
 ///
 impl Default for MixedOperator {
@@ -350,6 +387,196 @@ impl MixedOperator {
         }
         Ok(new_operator)
     }
+
+    /// Returns the commutator [self, other] = self * other - other * self.
+    ///
+    /// Pairs of MixedProducts whose boson and fermion parts can be reordered without contractions
+    /// only contribute when reordering them changes the sign, in which case they contribute twice their product.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The MixedOperator to take the commutator with.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(MixedOperator)` - The commutator of the two MixedOperators.
+    /// * `Err(StruqtureError::MismatchedNumberSubsystems)` - Number of subsystems in self and other do not match.
+    pub fn commutator(&self, other: &MixedOperator) -> Result<MixedOperator, StruqtureError> {
+        commutator_of_mixed_products(self, other, false)
+    }
+
+    /// Returns the anticommutator {self, other} = self * other + other * self.
+    ///
+    /// Pairs of MixedProducts whose boson and fermion parts can be reordered without contractions
+    /// only contribute when reordering them does not change the sign, in which case they contribute twice their product.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The MixedOperator to take the anticommutator with.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(MixedOperator)` - The anticommutator of the two MixedOperators.
+    /// * `Err(StruqtureError::MismatchedNumberSubsystems)` - Number of subsystems in self and other do not match.
+    pub fn anticommutator(&self, other: &MixedOperator) -> Result<MixedOperator, StruqtureError> {
+        commutator_of_mixed_products(self, other, true)
+    }
+
+    /// Transforms the bosonic subsystems into spin subsystems using the Dicke mapping.
+    ///
+    /// The MixedOperator has to be hermitian. It is mapped like the corresponding MixedHamiltonian, where
+    /// a term with a single bosonic annihilator b and its hermitian conjugate term with the creator b†
+    /// are mapped together as the Dicke representation of (b† + b).
+    ///
+    /// Every bosonic subsystem is mapped to a spin subsystem that is appended to the spin subsystems,
+    /// with the number of spins per bosonic mode chosen for every bosonic subsystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins_per_bosonic_mode` - The number of spins representing each mode, for every bosonic subsystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(MixedOperator)` - The mixed operator without bosonic subsystems.
+    /// * `Err(StruqtureError::GenericError)` - The number of spins per bosonic mode is not given for every bosonic subsystem.
+    /// * `Err(StruqtureError::NonHermitianOperator)` - The MixedOperator is not hermitian.
+    /// * `Err(StruqtureError)` - The boson -> spin transformation is only available for
+    ///   terms such as b†b or (b† + b).
+    pub fn dicke_boson_spin_mapping_per_subsystem(
+        &self,
+        number_spins_per_bosonic_mode: &[usize],
+    ) -> Result<MixedOperator, StruqtureError> {
+        check_dicke_subsystems(number_spins_per_bosonic_mode, self.n_bosons)?;
+        // self - self^† has to vanish term by term
+        let mut differences: HashMap<MixedProduct, SymbolicSum> = HashMap::new();
+        for (key, value) in self.iter() {
+            let (conjugate, sign) = key.hermitian_conjugate();
+            differences
+                .entry(key.clone())
+                .or_default()
+                .add_complex(value, Complex64::new(1.0, 0.0));
+            differences
+                .entry(conjugate)
+                .or_default()
+                .add_complex_conjugate(value, Complex64::new(-sign, 0.0));
+        }
+        if !differences.values().all(|difference| difference.cancels()) {
+            return Err(StruqtureError::NonHermitianOperator);
+        }
+        // Only the canonical term of every pair of hermitian conjugate terms is kept in the MixedHamiltonian
+        let mut hamiltonian = MixedHamiltonian::new(self.n_spins, self.n_bosons, self.n_fermions);
+        for (key, value) in self.iter() {
+            if let Ok(product) = HermitianMixedProduct::new(
+                key.spins().cloned(),
+                key.bosons().cloned(),
+                key.fermions().cloned(),
+            ) {
+                hamiltonian.add_operator_product(product, value.clone())?;
+            }
+        }
+        hamiltonian
+            .dicke_boson_spin_mapping_per_subsystem(number_spins_per_bosonic_mode)?
+            .expanded_operator()
+    }
+}
+
+/// Returns the sign picked up when reordering two MixedProducts, if they can be reordered without contractions.
+///
+/// Different subsystems commute with each other, so the sign is the product of the signs of all subsystems.
+///
+/// # Arguments
+///
+/// * `left` - The left-hand MixedProduct.
+/// * `right` - The right-hand MixedProduct.
+///
+/// # Returns
+///
+/// * `Some(f64)` - The sign picked up when reordering the two products.
+/// * `None` - Reordering the two products creates contraction terms.
+fn mixed_products_swap_sign(left: &MixedProduct, right: &MixedProduct) -> Option<f64> {
+    if left
+        .bosons()
+        .zip(right.bosons())
+        .any(|(bpl, bpr)| !mode_products_reorder_freely(bpl, bpr))
+    {
+        return None;
+    }
+    let mut sign = 1.0;
+    for (fpl, fpr) in left.fermions().zip(right.fermions()) {
+        if !mode_products_reorder_freely(fpl, fpr) {
+            return None;
+        }
+        let number_left = fpl.number_creators() + fpl.number_annihilators();
+        let number_right = fpr.number_creators() + fpr.number_annihilators();
+        if (number_left * number_right) % 2 == 1 {
+            sign *= -1.0;
+        }
+    }
+    for (ppl, ppr) in left.spins().zip(right.spins()) {
+        if !ppl.commutes_with(ppr) {
+            sign *= -1.0;
+        }
+    }
+    Some(sign)
+}
+
+/// Builds the (anti-)commutator of two MixedOperators.
+///
+/// # Arguments
+///
+/// * `left` - The left-hand operator.
+/// * `right` - The right-hand operator.
+/// * `anticommutator` - Whether to build the anticommutator instead of the commutator.
+///
+/// # Returns
+///
+/// * `Ok(MixedOperator)` - The resulting (anti-)commutator.
+/// * `Err(StruqtureError::MismatchedNumberSubsystems)` - Number of subsystems in left and right do not match.
+fn commutator_of_mixed_products(
+    left: &MixedOperator,
+    right: &MixedOperator,
+    anticommutator: bool,
+) -> Result<MixedOperator, StruqtureError> {
+    if left.n_spins != right.n_spins
+        || left.n_bosons != right.n_bosons
+        || left.n_fermions != right.n_fermions
+    {
+        return Err(StruqtureError::MismatchedNumberSubsystems {
+            target_number_spin_subsystems: left.n_spins,
+            target_number_boson_subsystems: left.n_bosons,
+            target_number_fermion_subsystems: left.n_fermions,
+            actual_number_spin_subsystems: right.n_spins,
+            actual_number_boson_subsystems: right.n_bosons,
+            actual_number_fermion_subsystems: right.n_fermions,
+        });
+    }
+    let reversed_sign = if anticommutator { 1.0 } else { -1.0 };
+    let mut op = MixedOperator::new(left.n_spins, left.n_bosons, left.n_fermions);
+    for (mpl, vall) in left.iter() {
+        for (mpr, valr) in right.iter() {
+            let coefficient = vall.clone() * valr;
+            match mixed_products_swap_sign(mpl, mpr) {
+                Some(swap_sign) => {
+                    let prefactor = 1.0 + reversed_sign * swap_sign;
+                    if prefactor == 0.0 {
+                        continue;
+                    }
+                    for (prod, coeff) in (mpl.clone() * mpr.clone())? {
+                        op.add_operator_product(prod, coefficient.clone() * coeff * prefactor)?;
+                    }
+                }
+                None => {
+                    for (prod, coeff) in (mpl.clone() * mpr.clone())? {
+                        op.add_operator_product(prod, coefficient.clone() * coeff)?;
+                    }
+                    for (prod, coeff) in (mpr.clone() * mpl.clone())? {
+                        op.add_operator_product(prod, coefficient.clone() * coeff * reversed_sign)?;
+                    }
+                }
+            }
+        }
+    }
+    Ok(op)
 }
 
 /// Implements the negative sign function of MixedOperator.
@@ -485,15 +712,34 @@ impl ops::Mul<MixedOperator> for MixedOperator {
             self.n_fermions,
             self.len() * other.len(),
         );
-        for (bps, vals) in self {
-            for (bpo, valo) in other.iter() {
-                let mixed_products = (bps.clone() * bpo.clone())?;
-                let coefficient = Into::<CalculatorComplex>::into(valo) * vals.clone();
-                for (b, coeff) in mixed_products {
-                    op.add_operator_product(b, coefficient.clone() * coeff)?;
+        let left: Vec<(&MixedProduct, &CalculatorComplex)> = self.iter().collect();
+        let right: Vec<(&MixedProduct, &CalculatorComplex)> = other.iter().collect();
+        multiply_terms(
+            &left,
+            &right,
+            |(bps, vals), right| {
+                let mut partial = MixedOperator::with_capacity(
+                    self.n_spins,
+                    self.n_bosons,
+                    self.n_fermions,
+                    right.len(),
+                );
+                for (bpo, valo) in right {
+                    let mixed_products = ((*bps).clone() * (*bpo).clone())?;
+                    let coefficient = Into::<CalculatorComplex>::into(*valo) * (*vals).clone();
+                    for (b, coeff) in mixed_products {
+                        partial.add_operator_product(b, coefficient.clone() * coeff)?;
+                    }
                 }
-            }
-        }
+                Ok(partial)
+            },
+            |partial| {
+                for (b, coefficient) in partial {
+                    op.add_operator_product(b, coefficient)?;
+                }
+                Ok(())
+            },
+        )?;
         Ok(op)
     }
 }
@@ -610,6 +856,50 @@ impl fmt::Display for MixedOperator {
     }
 }
 
+impl JordanWignerFermionToSpin for MixedOperator {
+    type Output = MixedOperator;
+
+    /// Implements JordanWignerFermionToSpin for a MixedOperator.
+    ///
+    /// Every fermionic subsystem is mapped to a spin subsystem that is appended to the spin subsystems,
+    /// the resulting MixedOperator has no fermionic subsystems.
+    /// The convention used is that |0> represents an empty fermionic state (spin-orbital),
+    /// and |1> represents an occupied fermionic state.
+    ///
+    /// # Returns
+    ///
+    /// `MixedOperator` - The mixed operator that results from the transformation.
+    ///
+    /// # Panics
+    ///
+    /// * Internal bug in `add_operator_product`.
+    fn jordan_wigner(&self) -> Self::Output {
+        let mut out = MixedOperator::new(self.n_spins + self.n_fermions, self.n_bosons, 0);
+        for (key, value) in self.iter() {
+            for (product, mapped_value) in key.jordan_wigner() {
+                out.add_operator_product(product, mapped_value * value)
+                    .expect("Internal bug in add_operator_product.");
+            }
+        }
+        out
+    }
+}
+
+impl BosonToSpin for MixedOperator {
+    type Output = MixedOperator;
+
+    // From trait
+    fn dicke_boson_spin_mapping(
+        &self,
+        number_spins_per_bosonic_mode: usize,
+    ) -> Result<Self::Output, StruqtureError> {
+        self.dicke_boson_spin_mapping_per_subsystem(&vec![
+            number_spins_per_bosonic_mode;
+            self.n_bosons
+        ])
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -10,12 +10,16 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::MixedOperator;
 use super::{GetValueMixed, HermitianMixedProduct, MixedIndex};
 use crate::bosons::BosonProduct;
 use crate::fermions::FermionProduct;
+use crate::mappings::{combine_mapped_subsystems, JordanWignerFermionToSpin};
 use crate::spins::PauliProduct;
+use crate::OperateOnDensityMatrix;
 use crate::{CorrespondsTo, StruqtureError, SymmetricIndex};
 use num_complex::Complex64;
+use qoqo_calculator::CalculatorComplex;
 use serde::{
     de::{Error, SeqAccess, Visitor},
     ser::SerializeTuple,
@@ -745,6 +749,50 @@ impl std::fmt::Display for MixedProduct {
     }
 }
 
+impl JordanWignerFermionToSpin for MixedProduct {
+    type Output = MixedOperator;
+
+    /// Implements JordanWignerFermionToSpin for a MixedProduct.
+    ///
+    /// Every fermionic subsystem is mapped to a spin subsystem that is appended to the spin subsystems,
+    /// the resulting MixedOperator has no fermionic subsystems.
+    /// The convention used is that |0> represents an empty fermionic state (spin-orbital),
+    /// and |1> represents an occupied fermionic state.
+    ///
+    /// # Returns
+    ///
+    /// `MixedOperator` - The mixed operator that results from the transformation.
+    ///
+    /// # Panics
+    ///
+    /// * Internal bug in `add_operator_product`.
+    fn jordan_wigner(&self) -> Self::Output {
+        let mapped_fermions: Vec<Vec<(PauliProduct, CalculatorComplex)>> = self
+            .fermions()
+            .map(|fermion| fermion.jordan_wigner().into_iter().collect())
+            .collect();
+        let terms = combine_mapped_subsystems(self.spins().cloned().collect(), mapped_fermions);
+        let mut out = MixedOperator::with_capacity(
+            self.spins().len() + self.fermions().len(),
+            self.bosons().len(),
+            0,
+            terms.len(),
+        );
+        for (spins, value) in terms {
+            out.add_operator_product(
+                MixedProduct {
+                    spins: spins.into_iter().collect(),
+                    bosons: self.bosons.clone(),
+                    fermions: TinyVec::new(),
+                },
+                value,
+            )
+            .expect("Internal bug in add_operator_product.");
+        }
+        out
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -27,6 +27,7 @@
 mod mixed_decoherence_product;
 mod mixed_hamiltonian;
 mod mixed_hermitian_product;
+mod mixed_hilbert_space;
 mod mixed_noise_operator;
 mod mixed_open_system;
 mod mixed_operator;
@@ -34,18 +35,22 @@ mod mixed_plus_minus_operator;
 mod mixed_plus_minus_product;
 mod mixed_product;
 
+use crate::sparse_matrix_assembly::hash_map_to_coo;
 use crate::{
-    bosons::BosonIndex, fermions::FermionIndex, ModeIndex, OperateOnDensityMatrix, SpinIndex,
-    StruqtureError,
+    bosons::BosonIndex, fermions::FermionIndex, CooSparseMatrix, ModeIndex, OperateOnDensityMatrix,
+    SpinIndex, StruqtureError,
 };
 // #[cfg(feature = "json_schema")]
 // use mixed_noise_system::TinyVecDef;
+use num_complex::Complex64;
 use qoqo_calculator::CalculatorComplex;
+use std::collections::HashMap;
 use std::str::FromStr;
 
 pub use mixed_decoherence_product::MixedDecoherenceProduct;
 pub use mixed_hamiltonian::MixedHamiltonian;
 pub use mixed_hermitian_product::HermitianMixedProduct;
+pub use mixed_hilbert_space::MixedHilbertSpace;
 pub use mixed_noise_operator::MixedLindbladNoiseOperator;
 pub use mixed_open_system::MixedLindbladOpenSystem;
 pub use mixed_operator::MixedOperator;
@@ -211,3 +216,107 @@ where
     &'a Self: IntoIterator<Item = (&'a Self::Index, &'a Self::Value)>,
 {
 }
+
+/// Trait for constructing the matrix of mixed operators on the tensor-product Hilbert space of all subsystems.
+///
+/// The basis and the ordering of the subsystems are described in [crate::mixed_systems::MixedHilbertSpace].
+///
+/// # Example
+/// ```
+/// use struqture::prelude::*;
+/// use qoqo_calculator::CalculatorComplex;
+/// use struqture::bosons::{BosonCutoff, BosonProduct};
+/// use struqture::spins::PauliProduct;
+/// use struqture::mixed_systems::{MixedHilbertSpace, MixedOperator, MixedProduct, ToMixedSparseMatrixOperator};
+///
+/// let mut mo = MixedOperator::new(1, 1, 0);
+/// let product = MixedProduct::new([PauliProduct::new().z(0)], [BosonProduct::new([0], [0]).unwrap()], []).unwrap();
+/// mo.set(product, CalculatorComplex::from(1.0)).unwrap();
+///
+/// // The spin changes fastest, so the state with the spin down and one boson has the index 3
+/// let space = MixedHilbertSpace::new(vec![1], vec![(1, BosonCutoff::Uniform(1))], vec![]).unwrap();
+/// let matrix = mo.sparse_matrix(&space).unwrap();
+/// assert_eq!(matrix.get(&(3, 3)), Some(&(-1.0).into()));
+/// ```
+pub trait ToMixedSparseMatrixOperator<'a>: OperateOnMixedSystems<'a> {
+    /// Constructs the sparse matrix representation of Self on a mixed Hilbert space as a HashMap.
+    ///
+    /// # Arguments
+    ///
+    /// * `space` - The Hilbert space of the matrix.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HashMap<(usize, usize), Complex64>)` - The matrix of Self.
+    /// * `Err(StruqtureError::MismatchedNumberSubsystems)` - The number of subsystems of Self and the Hilbert space do not match.
+    /// * `Err(StruqtureError::GenericError)` - A subsystem of Self acts on more spins or modes than the Hilbert space contains.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn sparse_matrix(
+        &'a self,
+        space: &MixedHilbertSpace,
+    ) -> Result<HashMap<(usize, usize), Complex64>, StruqtureError>;
+
+    /// Constructs the sparse matrix representation of Self on a mixed Hilbert space in COO representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `space` - The Hilbert space of the matrix.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Vec<Complex64>, (Vec<usize>, Vec<usize>)))` - The matrix sorted by rows and columns.
+    /// * `Err(StruqtureError::MismatchedNumberSubsystems)` - The number of subsystems of Self and the Hilbert space do not match.
+    /// * `Err(StruqtureError::GenericError)` - A subsystem of Self acts on more spins or modes than the Hilbert space contains.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn sparse_matrix_coo(
+        &'a self,
+        space: &MixedHilbertSpace,
+    ) -> Result<CooSparseMatrix, StruqtureError> {
+        Ok(hash_map_to_coo(self.sparse_matrix(space)?))
+    }
+}
+
+/// Trait for constructing the Lindblad superoperator of mixed objects on the tensor-product Hilbert space of all subsystems.
+///
+/// The superoperator for the operator O is defined as the Matrix S so that
+/// `flatten(-i [O, p]) = S flatten(p)` where `[,]` is the commutator, `p` is a matrix
+/// and `flatten` flattens a matrix into a vector in row-major form.
+/// Noise terms with the rate γ and the Lindblad operators (L, R) contribute `γ (L p R^† - 1/2 {R^† L, p})`.
+/// The basis of the density matrix is described in [crate::mixed_systems::MixedHilbertSpace].
+pub trait ToMixedSparseMatrixSuperOperator<'a>: OperateOnMixedSystems<'a> {
+    /// Constructs the sparse matrix representation of the superoperator on a mixed Hilbert space as a HashMap.
+    ///
+    /// # Arguments
+    ///
+    /// * `space` - The Hilbert space of the density matrix.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HashMap<(usize, usize), Complex64>)` - The superoperator acting on the row-major flattened density matrix.
+    /// * `Err(StruqtureError::MismatchedNumberSubsystems)` - The number of subsystems of Self and the Hilbert space do not match.
+    /// * `Err(StruqtureError::GenericError)` - A subsystem of Self acts on more spins or modes than the Hilbert space contains.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn sparse_matrix_superoperator(
+        &'a self,
+        space: &MixedHilbertSpace,
+    ) -> Result<HashMap<(usize, usize), Complex64>, StruqtureError>;
+
+    /// Constructs the sparse matrix representation of the superoperator on a mixed Hilbert space in COO representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `space` - The Hilbert space of the density matrix.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Vec<Complex64>, (Vec<usize>, Vec<usize>)))` - The superoperator sorted by rows and columns.
+    /// * `Err(StruqtureError::MismatchedNumberSubsystems)` - The number of subsystems of Self and the Hilbert space do not match.
+    /// * `Err(StruqtureError::GenericError)` - A subsystem of Self acts on more spins or modes than the Hilbert space contains.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn sparse_matrix_superoperator_coo(
+        &'a self,
+        space: &MixedHilbertSpace,
+    ) -> Result<CooSparseMatrix, StruqtureError> {
+        Ok(hash_map_to_coo(self.sparse_matrix_superoperator(space)?))
+    }
+}
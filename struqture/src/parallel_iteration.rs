@@ -0,0 +1,192 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Evaluation of independent items, on several threads when the `parallel` feature is enabled.
+//!
+//! The items are always consumed one after the other in their serial order, so that sums of
+//! floating point numbers and the order of entries in ordered maps do not depend on the feature.
+
+use crate::StruqtureError;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The number of matrix rows evaluated together before they are consumed.
+#[cfg(feature = "parallel")]
+const ROWS_PER_CHUNK: usize = 1 << 12;
+
+/// The number of operator products evaluated together before they are added to the result.
+const PRODUCTS_PER_CHUNK: usize = 1 << 16;
+
+/// Marker trait for types that have to be shared between threads when the `parallel` feature is enabled.
+///
+/// Without the `parallel` feature every type implements this trait.
+#[cfg(feature = "parallel")]
+pub(crate) trait SyncIfParallel: Sync {}
+#[cfg(feature = "parallel")]
+impl<T: Sync + ?Sized> SyncIfParallel for T {}
+
+/// Marker trait for types that have to be shared between threads when the `parallel` feature is enabled.
+///
+/// Without the `parallel` feature every type implements this trait.
+#[cfg(not(feature = "parallel"))]
+pub(crate) trait SyncIfParallel {}
+#[cfg(not(feature = "parallel"))]
+impl<T: ?Sized> SyncIfParallel for T {}
+
+/// Marker trait for types that have to be sent between threads when the `parallel` feature is enabled.
+#[cfg(feature = "parallel")]
+pub(crate) trait SendIfParallel: Send {}
+#[cfg(feature = "parallel")]
+impl<T: Send + ?Sized> SendIfParallel for T {}
+
+/// Marker trait for types that have to be sent between threads when the `parallel` feature is enabled.
+#[cfg(not(feature = "parallel"))]
+pub(crate) trait SendIfParallel {}
+#[cfg(not(feature = "parallel"))]
+impl<T: ?Sized> SendIfParallel for T {}
+
+/// Evaluates a function for a range of items and consumes the results in order.
+///
+/// With the `parallel` feature the items of each chunk are evaluated on several threads
+/// before the results of the chunk are consumed.
+///
+/// # Arguments
+///
+/// * `number_items` - The number of items, which are indexed from 0.
+/// * `items_per_chunk` - The number of items evaluated before their results are consumed.
+/// * `evaluate` - The function evaluated for every item.
+/// * `consume` - The function consuming the item index and result of every item in increasing order.
+///
+/// # Returns
+///
+/// * `Ok(())` - All items were consumed.
+/// * `Err(E)` - The first error returned by `consume`, no later items are consumed.
+#[cfg(feature = "parallel")]
+pub(crate) fn evaluate_in_order<T, E>(
+    number_items: usize,
+    items_per_chunk: usize,
+    evaluate: impl Fn(usize) -> T + Sync + Send,
+    mut consume: impl FnMut(usize, T) -> Result<(), E>,
+) -> Result<(), E>
+where
+    T: Send,
+{
+    let items_per_chunk = items_per_chunk.max(1);
+    let mut start = 0;
+    while start < number_items {
+        let end = number_items.min(start.saturating_add(items_per_chunk));
+        let results: Vec<T> = (start..end).into_par_iter().map(&evaluate).collect();
+        for (item, result) in (start..end).zip(results) {
+            consume(item, result)?;
+        }
+        start = end;
+    }
+    Ok(())
+}
+
+/// Evaluates a function for a range of items and consumes the results in order.
+///
+/// With the `parallel` feature the items of each chunk are evaluated on several threads
+/// before the results of the chunk are consumed.
+///
+/// # Arguments
+///
+/// * `number_items` - The number of items, which are indexed from 0.
+/// * `items_per_chunk` - The number of items evaluated before their results are consumed.
+/// * `evaluate` - The function evaluated for every item.
+/// * `consume` - The function consuming the item index and result of every item in increasing order.
+///
+/// # Returns
+///
+/// * `Ok(())` - All items were consumed.
+/// * `Err(E)` - The first error returned by `consume`, no later items are consumed.
+#[cfg(not(feature = "parallel"))]
+pub(crate) fn evaluate_in_order<T, E>(
+    number_items: usize,
+    _items_per_chunk: usize,
+    evaluate: impl Fn(usize) -> T,
+    mut consume: impl FnMut(usize, T) -> Result<(), E>,
+) -> Result<(), E> {
+    for item in 0..number_items {
+        consume(item, evaluate(item))?;
+    }
+    Ok(())
+}
+
+/// Constructs the entries of every row of a matrix on several threads and consumes them in the order of the rows.
+///
+/// # Arguments
+///
+/// * `number_rows` - The number of rows of the matrix.
+/// * `entries_on_row` - The function constructing the entries of a row.
+/// * `consume` - The function consuming the row index and entries of every row in increasing order.
+///
+/// # Returns
+///
+/// * `Ok(())` - All rows were consumed.
+/// * `Err(StruqtureError)` - The first error returned by `entries_on_row`.
+#[cfg(feature = "parallel")]
+pub(crate) fn for_each_row<T>(
+    number_rows: usize,
+    entries_on_row: impl Fn(usize) -> Result<T, StruqtureError> + Sync,
+    mut consume: impl FnMut(usize, T),
+) -> Result<(), StruqtureError>
+where
+    T: Send,
+{
+    evaluate_in_order(
+        number_rows,
+        ROWS_PER_CHUNK,
+        &entries_on_row,
+        |row, entries| {
+            consume(row, entries?);
+            Ok(())
+        },
+    )
+}
+
+/// Multiplies every term of a left operator with every term of a right operator with a merge reduction.
+///
+/// The products of one left term with all right terms are reduced into a partial operator, and the
+/// partial operators of all left terms are merged into the result in the order of the left terms.
+/// The partial operators are the same with and without the `parallel` feature, so that the result,
+/// including the order of its terms, does not depend on the feature.
+///
+/// # Arguments
+///
+/// * `left` - The terms of the left operator.
+/// * `right` - The terms of the right operator.
+/// * `multiply_with_right` - The function returning the partial operator of a left term multiplied with all right terms.
+/// * `merge` - The function merging a partial operator into the result.
+///
+/// # Returns
+///
+/// * `Ok(())` - All partial operators were merged.
+/// * `Err(StruqtureError)` - The first error returned by `multiply_with_right` or `merge`.
+pub(crate) fn multiply_terms<L, R, O>(
+    left: &[L],
+    right: &[R],
+    multiply_with_right: impl Fn(&L, &[R]) -> Result<O, StruqtureError> + SyncIfParallel,
+    mut merge: impl FnMut(O) -> Result<(), StruqtureError>,
+) -> Result<(), StruqtureError>
+where
+    L: SyncIfParallel,
+    R: SyncIfParallel,
+    O: SendIfParallel,
+{
+    evaluate_in_order(
+        left.len(),
+        PRODUCTS_PER_CHUNK / right.len().max(1),
+        |index| multiply_with_right(&left[index], right),
+        |_, partial| merge(partial?),
+    )
+}
@@ -10,8 +10,14 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub use crate::bosons::ToBosonSparseMatrixOperator;
+pub use crate::bosons::ToBosonSparseMatrixSuperOperator;
+pub use crate::fermions::ToFermionSparseMatrixOperator;
+pub use crate::fermions::ToFermionSparseMatrixSuperOperator;
 pub use crate::mixed_systems::MixedIndex;
 pub use crate::mixed_systems::OperateOnMixedSystems;
+pub use crate::mixed_systems::ToMixedSparseMatrixOperator;
+pub use crate::mixed_systems::ToMixedSparseMatrixSuperOperator;
 pub use crate::spins::OperateOnSpins;
 pub use crate::spins::ToSparseMatrixOperator;
 pub use crate::spins::ToSparseMatrixSuperOperator;
@@ -23,5 +29,6 @@ pub use crate::OperateOnDensityMatrix;
 pub use crate::OperateOnModes;
 pub use crate::OperateOnState;
 pub use crate::SpinIndex;
+pub use crate::SymbolicParametersTrait;
 pub use crate::SymmetricIndex;
 pub use crate::TruncateTrait;
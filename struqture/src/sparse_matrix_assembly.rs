@@ -0,0 +1,273 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Assembly of sparse operator and superoperator matrices from the actions of operator products.
+
+use crate::{CooSparseMatrix, StruqtureError};
+use num_complex::Complex64;
+use qoqo_calculator::CalculatorComplex;
+use std::collections::HashMap;
+
+/// Converts a coefficient into a Complex64.
+///
+/// # Arguments
+///
+/// * `value` - The coefficient to convert.
+///
+/// # Returns
+///
+/// * `Ok(Complex64)` - The converted coefficient.
+/// * `Err(StruqtureError::CalculatorError)` - CalculatorFloat could not be converted to f64.
+pub(crate) fn complex_coefficient<V>(value: V) -> Result<Complex64, StruqtureError>
+where
+    V: Into<CalculatorComplex>,
+{
+    let value: CalculatorComplex = value.into();
+    Ok(Complex64::new(value.re.try_into()?, value.im.try_into()?))
+}
+
+/// Returns the integers with a given number of bits set, in ascending order.
+///
+/// The integers encode the basis states of systems of two-level modes or spins, where bit `i` is the state of mode `i`.
+///
+/// # Arguments
+///
+/// * `number_bits` - The number of bits of the integers, which has to be smaller than `usize::BITS`.
+/// * `number_set_bits` - The number of set bits, or None for all integers with `number_bits` bits.
+///
+/// # Returns
+///
+/// * `Vec<usize>` - The sorted integers.
+pub(crate) fn basis_states_with_set_bits(
+    number_bits: usize,
+    number_set_bits: Option<usize>,
+) -> Vec<usize> {
+    let end = 1usize << number_bits;
+    match number_set_bits {
+        None => (0..end).collect(),
+        Some(number_set_bits) if number_set_bits > number_bits => Vec::new(),
+        Some(0) => vec![0],
+        Some(number_set_bits) => {
+            // Gosper's hack: steps to the next larger integer with the same number of set bits
+            let mut states = Vec::new();
+            let mut state = (1usize << number_set_bits) - 1;
+            while state < end {
+                states.push(state);
+                let lowest = state & state.wrapping_neg();
+                let ripple = state + lowest;
+                state = (((ripple ^ state) >> 2) / lowest) | ripple;
+            }
+            states
+        }
+    }
+}
+
+/// The non-zero entry in each column of the matrix of an operator product.
+///
+/// The entry of a column is the row and the value of the only non-zero entry in that column,
+/// or None if the product annihilates the corresponding basis state.
+pub(crate) type ProductColumns = Vec<Option<(usize, Complex64)>>;
+
+/// A basis in which every operator product maps each basis state onto at most one basis state.
+///
+/// The products are also required to map different basis states onto different basis states,
+/// so that every row of their matrices contains at most one non-zero entry as well.
+pub(crate) trait ProductBasis<P> {
+    /// Returns the dimension of the Hilbert space spanned by the basis.
+    fn dimension(&self) -> usize;
+
+    /// Returns the non-zero entry in each column of the matrix of a product.
+    ///
+    /// # Arguments
+    ///
+    /// * `product` - The operator product.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ProductColumns)` - The row and value of the non-zero entry of each column.
+    /// * `Err(StruqtureError)` - The product cannot be represented in the basis.
+    fn product_columns(&self, product: &P) -> Result<ProductColumns, StruqtureError>;
+}
+
+/// Adds an entry to a sparse matrix, summing duplicate entries.
+fn add_entry(
+    matrix: &mut HashMap<(usize, usize), Complex64>,
+    key: (usize, usize),
+    value: Complex64,
+) {
+    *matrix.entry(key).or_insert(Complex64::new(0.0, 0.0)) += value;
+}
+
+/// Constructs the matrix of a sum of operator products.
+///
+/// # Arguments
+///
+/// * `terms` - The operator products and their coefficients.
+/// * `basis` - The basis of the matrix.
+///
+/// # Returns
+///
+/// * `Ok(HashMap<(usize, usize), Complex64>)` - The matrix of the operator.
+/// * `Err(StruqtureError)` - A coefficient could not be converted or a product cannot be represented in the basis.
+pub(crate) fn operator_matrix<'b, P: 'b>(
+    terms: impl Iterator<Item = (&'b P, &'b CalculatorComplex)>,
+    basis: &impl ProductBasis<P>,
+) -> Result<HashMap<(usize, usize), Complex64>, StruqtureError> {
+    let mut matrix: HashMap<(usize, usize), Complex64> = HashMap::new();
+    for (product, value) in terms {
+        let coefficient = complex_coefficient(value.clone())?;
+        for (column, entry) in basis.product_columns(product)?.into_iter().enumerate() {
+            if let Some((row, entry_value)) = entry {
+                add_entry(&mut matrix, (row, column), coefficient * entry_value);
+            }
+        }
+    }
+    Ok(matrix)
+}
+
+/// Adds the superoperator of the commutator part `-i [H, ρ]` of the Lindblad equation to a matrix.
+///
+/// The superoperator acts on the density matrix ρ flattened in row-major form.
+///
+/// # Arguments
+///
+/// * `terms` - The operator products of H and their coefficients.
+/// * `basis` - The basis of the density matrix.
+/// * `matrix` - The superoperator matrix the terms are added to.
+///
+/// # Returns
+///
+/// * `Ok(())` - The terms were added.
+/// * `Err(StruqtureError)` - A coefficient could not be converted or a product cannot be represented in the basis.
+pub(crate) fn add_commutator_superoperator<'b, P: 'b>(
+    terms: impl Iterator<Item = (&'b P, &'b CalculatorComplex)>,
+    basis: &impl ProductBasis<P>,
+    matrix: &mut HashMap<(usize, usize), Complex64>,
+) -> Result<(), StruqtureError> {
+    let dimension = basis.dimension();
+    let operator = operator_matrix(terms, basis)?;
+    for ((row, column), value) in operator {
+        let value = Complex64::new(0.0, -1.0) * value;
+        for index in 0..dimension {
+            // -i H ρ
+            add_entry(
+                matrix,
+                (row * dimension + index, column * dimension + index),
+                value,
+            );
+            // i ρ H
+            add_entry(
+                matrix,
+                (index * dimension + column, index * dimension + row),
+                -value,
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Adds the superoperator of the noise part of the Lindblad equation to a matrix.
+///
+/// For every noise term with the rate γ and the Lindblad operators (L, R) the superoperator of
+/// `γ (L ρ R^† - 1/2 {R^† L, ρ})` acting on the row-major flattened density matrix ρ is added.
+///
+/// # Arguments
+///
+/// * `terms` - The pairs of operator products (L, R) and their rates.
+/// * `basis` - The basis of the density matrix.
+/// * `matrix` - The superoperator matrix the terms are added to.
+///
+/// # Returns
+///
+/// * `Ok(())` - The terms were added.
+/// * `Err(StruqtureError)` - A coefficient could not be converted or a product cannot be represented in the basis.
+pub(crate) fn add_lindblad_superoperator<'b, P: 'b>(
+    terms: impl Iterator<Item = (&'b (P, P), &'b CalculatorComplex)>,
+    basis: &impl ProductBasis<P>,
+    matrix: &mut HashMap<(usize, usize), Complex64>,
+) -> Result<(), StruqtureError> {
+    let dimension = basis.dimension();
+    for ((left, right), value) in terms {
+        let rate = complex_coefficient(value.clone())?;
+        let left_columns = basis.product_columns(left)?;
+        let right_columns = basis.product_columns(right)?;
+        // L ρ R^†: entries L[r, k] conj(R[c, m]) at ((r, c), (k, m))
+        for (left_column, left_entry) in left_columns.iter().enumerate() {
+            let Some((left_row, left_value)) = left_entry else {
+                continue;
+            };
+            for (right_column, right_entry) in right_columns.iter().enumerate() {
+                if let Some((right_row, right_value)) = right_entry {
+                    add_entry(
+                        matrix,
+                        (
+                            left_row * dimension + right_row,
+                            left_column * dimension + right_column,
+                        ),
+                        rate * left_value * right_value.conj(),
+                    );
+                }
+            }
+        }
+        // -1/2 {R^† L, ρ}, where (R^† L)[r, k] = conj(R[j, r]) L[j, k]
+        let mut right_rows: HashMap<usize, (usize, Complex64)> = HashMap::new();
+        for (right_column, right_entry) in right_columns.iter().enumerate() {
+            if let Some((right_row, right_value)) = right_entry {
+                right_rows.insert(*right_row, (right_column, *right_value));
+            }
+        }
+        for (left_column, left_entry) in left_columns.iter().enumerate() {
+            let Some((intermediate, left_value)) = left_entry else {
+                continue;
+            };
+            if let Some((row, right_value)) = right_rows.get(intermediate) {
+                let value = rate * right_value.conj() * left_value * -0.5;
+                for index in 0..dimension {
+                    add_entry(
+                        matrix,
+                        (row * dimension + index, left_column * dimension + index),
+                        value,
+                    );
+                    add_entry(
+                        matrix,
+                        (index * dimension + left_column, index * dimension + row),
+                        value,
+                    );
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Converts a sparse matrix stored as a HashMap into the COO format sorted by rows and columns.
+///
+/// # Arguments
+///
+/// * `matrix` - The sparse matrix.
+///
+/// # Returns
+///
+/// * `CooSparseMatrix` - The sparse matrix in COO format.
+pub(crate) fn hash_map_to_coo(matrix: HashMap<(usize, usize), Complex64>) -> CooSparseMatrix {
+    let mut entries: Vec<((usize, usize), Complex64)> = matrix.into_iter().collect();
+    entries.sort_unstable_by_key(|(key, _)| *key);
+    let mut values = Vec::with_capacity(entries.len());
+    let mut rows = Vec::with_capacity(entries.len());
+    let mut columns = Vec::with_capacity(entries.len());
+    for ((row, column), value) in entries {
+        rows.push(row);
+        columns.push(column);
+        values.push(value);
+    }
+    (values, (rows, columns))
+}
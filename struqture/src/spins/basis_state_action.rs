@@ -0,0 +1,338 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{
+    DecoherenceProduct, PauliProduct, PlusMinusProduct, SingleDecoherenceOperator,
+    SinglePauliOperator, SinglePlusMinusOperator,
+};
+use crate::sparse_matrix_assembly::complex_coefficient;
+use crate::{SpinIndex, StruqtureError};
+use ndarray::Array2;
+use num_complex::Complex64;
+use qoqo_calculator::CalculatorComplex;
+
+/// Action of a product of single-spin operators on the little endian computational basis states.
+///
+/// The product maps the basis state `row ^ flip_mask` onto the basis state `row` with the amplitude
+/// `prefactor * (-1)^popcount(row & sign_mask)` for all rows with `row & condition_mask == condition_value`.
+/// All other rows are not reached by the product.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct BasisStateAction {
+    flip_mask: usize,
+    sign_mask: usize,
+    condition_mask: usize,
+    condition_value: usize,
+    prefactor: Complex64,
+}
+
+impl BasisStateAction {
+    /// Creates the action of the identity.
+    fn identity() -> Self {
+        BasisStateAction {
+            flip_mask: 0,
+            sign_mask: 0,
+            condition_mask: 0,
+            condition_value: 0,
+            prefactor: Complex64::new(1.0, 0.0),
+        }
+    }
+
+    /// Returns the only non-zero entry of the matrix of the product on a row.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The row of the matrix.
+    ///
+    /// # Returns
+    ///
+    /// * `Some((usize, Complex64))` - The column and the value of the non-zero entry.
+    /// * `None` - All entries on the row are zero.
+    fn entry_on_row(&self, row: usize) -> Option<(usize, Complex64)> {
+        if row & self.condition_mask != self.condition_value {
+            return None;
+        }
+        if (row & self.sign_mask).count_ones() % 2 == 0 {
+            Some((row ^ self.flip_mask, self.prefactor))
+        } else {
+            Some((row ^ self.flip_mask, -self.prefactor))
+        }
+    }
+
+    /// Returns the only non-zero entry of the matrix of the product on a column.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - The column of the matrix.
+    ///
+    /// # Returns
+    ///
+    /// * `Some((usize, Complex64))` - The row and the value of the non-zero entry.
+    /// * `None` - All entries on the column are zero.
+    pub(crate) fn entry_on_column(&self, column: usize) -> Option<(usize, Complex64)> {
+        let row = column ^ self.flip_mask;
+        self.entry_on_row(row).map(|(_, value)| (row, value))
+    }
+}
+
+impl From<&PauliProduct> for BasisStateAction {
+    fn from(product: &PauliProduct) -> Self {
+        let mut action = BasisStateAction::identity();
+        for (index, pauli) in product.iter() {
+            let bit = 1usize << index;
+            match pauli {
+                SinglePauliOperator::Identity => (),
+                SinglePauliOperator::X => action.flip_mask |= bit,
+                // <0|Y|1> = -i and <1|Y|0> = i
+                SinglePauliOperator::Y => {
+                    action.flip_mask |= bit;
+                    action.sign_mask |= bit;
+                    action.prefactor *= Complex64::new(0.0, -1.0);
+                }
+                SinglePauliOperator::Z => action.sign_mask |= bit,
+            }
+        }
+        action
+    }
+}
+
+impl From<&DecoherenceProduct> for BasisStateAction {
+    fn from(product: &DecoherenceProduct) -> Self {
+        let mut action = BasisStateAction::identity();
+        for (index, pauli) in product.iter() {
+            let bit = 1usize << index;
+            match pauli {
+                SingleDecoherenceOperator::Identity => (),
+                SingleDecoherenceOperator::X => action.flip_mask |= bit,
+                // <0|iY|1> = 1 and <1|iY|0> = -1
+                SingleDecoherenceOperator::IY => {
+                    action.flip_mask |= bit;
+                    action.sign_mask |= bit;
+                }
+                SingleDecoherenceOperator::Z => action.sign_mask |= bit,
+            }
+        }
+        action
+    }
+}
+
+impl From<&PlusMinusProduct> for BasisStateAction {
+    fn from(product: &PlusMinusProduct) -> Self {
+        let mut action = BasisStateAction::identity();
+        for (index, pauli) in product.iter() {
+            let bit = 1usize << index;
+            match pauli {
+                SinglePlusMinusOperator::Identity => (),
+                // The raising operator maps |1> onto |0>
+                SinglePlusMinusOperator::Plus => {
+                    action.flip_mask |= bit;
+                    action.condition_mask |= bit;
+                }
+                // The lowering operator maps |0> onto |1>
+                SinglePlusMinusOperator::Minus => {
+                    action.flip_mask |= bit;
+                    action.condition_mask |= bit;
+                    action.condition_value |= bit;
+                }
+                SinglePlusMinusOperator::Z => action.sign_mask |= bit,
+            }
+        }
+        action
+    }
+}
+
+/// Applies a sum of products of single-spin operators to a dense state vector.
+///
+/// # Arguments
+///
+/// * `terms` - The actions of the products and their coefficients.
+/// * `number_spins` - The number of spins the operator acts on.
+/// * `state` - The little endian state vector.
+///
+/// # Returns
+///
+/// * `Ok(Vec<Complex64>)` - The state vector after applying the operator.
+/// * `Err(StruqtureError::GenericError)` - The length of the state vector is not a power of two or too small for the operator.
+/// * `Err(StruqtureError::CalculatorError)` - A coefficient could not be converted to f64.
+pub(crate) fn apply_terms_to_state<V>(
+    terms: impl Iterator<Item = (BasisStateAction, V)>,
+    number_spins: usize,
+    state: &[Complex64],
+) -> Result<Vec<Complex64>, StruqtureError>
+where
+    V: Into<CalculatorComplex>,
+{
+    let mut result = vec![Complex64::new(0.0, 0.0); state.len()];
+    apply_terms_to_state_into(terms, number_spins, state, &mut result)?;
+    Ok(result)
+}
+
+/// Applies a sum of products of single-spin operators to a dense state vector, writing the result into a given buffer.
+///
+/// The previous content of the buffer is overwritten.
+///
+/// # Arguments
+///
+/// * `terms` - The actions of the products and their coefficients.
+/// * `number_spins` - The number of spins the operator acts on.
+/// * `state` - The little endian state vector.
+/// * `result` - The buffer the state vector after applying the operator is written into.
+///
+/// # Returns
+///
+/// * `Ok(())` - The result was written into the buffer.
+/// * `Err(StruqtureError::GenericError)` - The length of the state vector is not a power of two or too small for the operator, or the buffer does not have the same length as the state vector.
+/// * `Err(StruqtureError::CalculatorError)` - A coefficient could not be converted to f64.
+pub(crate) fn apply_terms_to_state_into<V>(
+    terms: impl Iterator<Item = (BasisStateAction, V)>,
+    number_spins: usize,
+    state: &[Complex64],
+    result: &mut [Complex64],
+) -> Result<(), StruqtureError>
+where
+    V: Into<CalculatorComplex>,
+{
+    if !state.len().is_power_of_two() || state.len().trailing_zeros() < number_spins as u32 {
+        return Err(StruqtureError::GenericError {
+            msg: format!(
+                "State vector of length {} does not describe a system of at least {} spins.",
+                state.len(),
+                number_spins
+            ),
+        });
+    }
+    if result.len() != state.len() {
+        return Err(StruqtureError::GenericError {
+            msg: format!(
+                "Result buffer of length {} does not match the state vector of length {}.",
+                result.len(),
+                state.len()
+            ),
+        });
+    }
+    result.fill(Complex64::new(0.0, 0.0));
+    for (action, value) in terms {
+        let coefficient = complex_coefficient(value)? * action.prefactor;
+        for (row, entry) in result.iter_mut().enumerate() {
+            if row & action.condition_mask != action.condition_value {
+                continue;
+            }
+            let contribution = coefficient * state[row ^ action.flip_mask];
+            if (row & action.sign_mask).count_ones() % 2 == 0 {
+                *entry += contribution;
+            } else {
+                *entry -= contribution;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Checks that a density matrix is square and large enough for a given number of spins.
+///
+/// # Arguments
+///
+/// * `density_matrix` - The little endian density matrix.
+/// * `number_spins` - The number of spins the superoperator acts on.
+///
+/// # Returns
+///
+/// * `Ok(())` - The density matrix has a valid shape.
+/// * `Err(StruqtureError::GenericError)` - The density matrix is not square, its dimension is not a power of two or it is too small.
+pub(crate) fn check_density_matrix(
+    density_matrix: &Array2<Complex64>,
+    number_spins: usize,
+) -> Result<(), StruqtureError> {
+    let (rows, columns) = density_matrix.dim();
+    if rows != columns || !rows.is_power_of_two() || rows.trailing_zeros() < number_spins as u32 {
+        return Err(StruqtureError::GenericError {
+            msg: format!(
+                "Density matrix of shape ({rows}, {columns}) does not describe a system of at least {number_spins} spins."
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// Adds the commutator term `-i * coefficient * [P, ρ]` of a product P to a result matrix.
+///
+/// # Arguments
+///
+/// * `action` - The action of the product P.
+/// * `coefficient` - The coefficient of the product.
+/// * `density_matrix` - The density matrix ρ.
+/// * `result` - The matrix the term is added to.
+pub(crate) fn add_commutator_to_density_matrix(
+    action: &BasisStateAction,
+    coefficient: Complex64,
+    density_matrix: &Array2<Complex64>,
+    result: &mut Array2<Complex64>,
+) {
+    let dimension = density_matrix.nrows();
+    let prefactor = Complex64::new(0.0, -1.0) * coefficient;
+    for row in 0..dimension {
+        if let Some((column, value)) = action.entry_on_row(row) {
+            let factor = prefactor * value;
+            for index in 0..dimension {
+                // -i c P ρ
+                result[(row, index)] += factor * density_matrix[(column, index)];
+                // i c ρ P
+                result[(index, column)] -= factor * density_matrix[(index, row)];
+            }
+        }
+    }
+}
+
+/// Adds the Lindblad term `coefficient * (L ρ R^† - 1/2 {R^† L, ρ})` to a result matrix.
+///
+/// # Arguments
+///
+/// * `left` - The action of the left Lindblad operator L.
+/// * `right` - The action of the right Lindblad operator R.
+/// * `coefficient` - The coefficient of the Lindblad term.
+/// * `density_matrix` - The density matrix ρ.
+/// * `result` - The matrix the term is added to.
+pub(crate) fn add_lindblad_term_to_density_matrix(
+    left: &BasisStateAction,
+    right: &BasisStateAction,
+    coefficient: Complex64,
+    density_matrix: &Array2<Complex64>,
+    result: &mut Array2<Complex64>,
+) {
+    let dimension = density_matrix.nrows();
+    // L ρ R^†: (r, c) -> L[r, k] ρ[k, m] conj(R[c, m])
+    for row in 0..dimension {
+        if let Some((left_column, left_value)) = left.entry_on_row(row) {
+            let factor = coefficient * left_value;
+            for column in 0..dimension {
+                if let Some((right_column, right_value)) = right.entry_on_row(column) {
+                    result[(row, column)] +=
+                        factor * density_matrix[(left_column, right_column)] * right_value.conj();
+                }
+            }
+        }
+    }
+    // -1/2 (R^† L ρ + ρ R^† L), where (R^† L)[r, k] = conj(R[j, r]) L[j, k] with j = r ^ flip_mask(R)
+    let prefactor = coefficient * -0.5;
+    for row in 0..dimension {
+        let intermediate = row ^ right.flip_mask;
+        if let (Some((_, right_value)), Some((column, left_value))) = (
+            right.entry_on_row(intermediate),
+            left.entry_on_row(intermediate),
+        ) {
+            let factor = prefactor * right_value.conj() * left_value;
+            for index in 0..dimension {
+                result[(row, index)] += factor * density_matrix[(column, index)];
+                result[(index, column)] += factor * density_matrix[(index, row)];
+            }
+        }
+    }
+}
@@ -0,0 +1,186 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{DecoherenceProduct, PauliProduct, SinglePauliOperator};
+use crate::{SpinIndex, StruqtureError};
+use num_complex::Complex64;
+
+/// A Clifford gate that Pauli operators can be conjugated with.
+///
+/// Conjugating a Pauli operator P with a gate C gives C P C^†, which is again a product of Pauli matrices up to a phase.
+///
+/// # Example
+/// ```
+/// use num_complex::Complex64;
+/// use struqture::prelude::*;
+/// use struqture::spins::{CliffordGate, PauliProduct};
+///
+/// let product = PauliProduct::new().x(0);
+/// let (conjugated, phase) = product
+///     .conjugate_with_clifford(&CliffordGate::ControlledNot { control: 0, target: 1 })
+///     .unwrap();
+/// assert_eq!(conjugated, PauliProduct::new().x(0).x(1));
+/// assert_eq!(phase, Complex64::new(1.0, 0.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CliffordGate {
+    /// The Hadamard gate on a qubit.
+    Hadamard(usize),
+    /// The phase gate S = diag(1, i) on a qubit.
+    S(usize),
+    /// The inverse phase gate S^† = diag(1, -i) on a qubit.
+    SDagger(usize),
+    /// The controlled NOT gate.
+    ControlledNot {
+        /// The control qubit.
+        control: usize,
+        /// The target qubit.
+        target: usize,
+    },
+    /// The controlled Z gate on two qubits.
+    ControlledZ(usize, usize),
+    /// The SWAP gate of two qubits.
+    Swap(usize, usize),
+}
+
+impl CliffordGate {
+    /// Returns the qubits the gate acts on.
+    fn qubits(&self) -> Vec<usize> {
+        match *self {
+            CliffordGate::Hadamard(qubit)
+            | CliffordGate::S(qubit)
+            | CliffordGate::SDagger(qubit) => {
+                vec![qubit]
+            }
+            CliffordGate::ControlledNot { control, target } => vec![control, target],
+            CliffordGate::ControlledZ(first, second) | CliffordGate::Swap(first, second) => {
+                vec![first, second]
+            }
+        }
+    }
+
+    /// Conjugates a single-qubit Pauli matrix on one of the qubits of the gate.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubit` - The qubit of the Pauli matrix, one of the qubits of the gate.
+    /// * `pauli` - The non-identity Pauli matrix.
+    ///
+    /// # Returns
+    ///
+    /// * `(PauliProduct, f64)` - The conjugated Pauli matrix and its sign.
+    fn conjugate_single(&self, qubit: usize, pauli: SinglePauliOperator) -> (PauliProduct, f64) {
+        use SinglePauliOperator::{Identity, X, Y, Z};
+        let single =
+            |qubit: usize, pauli: SinglePauliOperator| PauliProduct::new().set_pauli(qubit, pauli);
+        match (*self, pauli) {
+            (_, Identity) => (PauliProduct::new(), 1.0),
+            (CliffordGate::Hadamard(_), X) => (single(qubit, Z), 1.0),
+            (CliffordGate::Hadamard(_), Y) => (single(qubit, Y), -1.0),
+            (CliffordGate::Hadamard(_), Z) => (single(qubit, X), 1.0),
+            (CliffordGate::S(_), X) => (single(qubit, Y), 1.0),
+            (CliffordGate::S(_), Y) => (single(qubit, X), -1.0),
+            (CliffordGate::SDagger(_), X) => (single(qubit, Y), -1.0),
+            (CliffordGate::SDagger(_), Y) => (single(qubit, X), 1.0),
+            (CliffordGate::S(_), Z) | (CliffordGate::SDagger(_), Z) => (single(qubit, Z), 1.0),
+            (CliffordGate::ControlledNot { control, target }, pauli) => {
+                match (qubit == control, pauli) {
+                    // X and Y on the control qubit spread an X to the target qubit
+                    (true, X) | (true, Y) => (single(control, pauli).x(target), 1.0),
+                    (true, Z) => (single(control, Z), 1.0),
+                    (false, X) => (single(target, X), 1.0),
+                    // Y and Z on the target qubit spread a Z to the control qubit
+                    (false, Y) | (false, Z) => (single(target, pauli).z(control), 1.0),
+                    (_, Identity) => (PauliProduct::new(), 1.0),
+                }
+            }
+            (CliffordGate::ControlledZ(first, second), pauli) => {
+                let other = if qubit == first { second } else { first };
+                match pauli {
+                    X | Y => (single(qubit, pauli).z(other), 1.0),
+                    _ => (single(qubit, pauli), 1.0),
+                }
+            }
+            (CliffordGate::Swap(first, second), pauli) => {
+                let other = if qubit == first { second } else { first };
+                (single(other, pauli), 1.0)
+            }
+        }
+    }
+}
+
+/// Conjugates a PauliProduct with a sequence of Clifford gates.
+///
+/// # Arguments
+///
+/// * `product` - The PauliProduct P to conjugate.
+/// * `gates` - The gates C_0, C_1, ... in the order they are applied.
+///
+/// # Returns
+///
+/// * `Ok((PauliProduct, Complex64))` - The PauliProduct and the phase of ... C_1 C_0 P C_0^† C_1^† ...
+/// * `Err(StruqtureError::GenericError)` - A two-qubit gate acts twice on the same qubit.
+pub(crate) fn conjugate_pauli_product(
+    product: &PauliProduct,
+    gates: &[CliffordGate],
+) -> Result<(PauliProduct, Complex64), StruqtureError> {
+    let mut product = product.clone();
+    let mut sign = 1.0;
+    for gate in gates {
+        let qubits = gate.qubits();
+        if qubits.len() == 2 && qubits[0] == qubits[1] {
+            return Err(StruqtureError::GenericError {
+                msg: format!("Clifford gate {gate:?} acts twice on the same qubit."),
+            });
+        }
+        let mut conjugated = product.clone();
+        for qubit in qubits.iter() {
+            conjugated = conjugated.set_pauli(*qubit, SinglePauliOperator::Identity);
+        }
+        // The conjugated single-qubit Pauli matrices commute, so the order of the multiplication does not matter
+        for qubit in qubits {
+            if let Some(pauli) = product.get(&qubit) {
+                let (image, image_sign) = gate.conjugate_single(qubit, *pauli);
+                let (new_product, phase) = conjugated * image;
+                conjugated = new_product;
+                sign *= image_sign * phase.re;
+            }
+        }
+        product = conjugated;
+    }
+    Ok((product, Complex64::new(sign, 0.0)))
+}
+
+/// Conjugates a DecoherenceProduct with a sequence of Clifford gates.
+///
+/// # Arguments
+///
+/// * `product` - The DecoherenceProduct D to conjugate.
+/// * `gates` - The gates C_0, C_1, ... in the order they are applied.
+///
+/// # Returns
+///
+/// * `Ok((DecoherenceProduct, Complex64))` - The DecoherenceProduct and the phase of ... C_1 C_0 D C_0^† C_1^† ...
+/// * `Err(StruqtureError::GenericError)` - A two-qubit gate acts twice on the same qubit.
+pub(crate) fn conjugate_decoherence_product(
+    product: &DecoherenceProduct,
+    gates: &[CliffordGate],
+) -> Result<(DecoherenceProduct, Complex64), StruqtureError> {
+    let (pauli_product, to_pauli_phase) = DecoherenceProduct::decoherence_to_spin(product.clone());
+    let (conjugated, phase) = conjugate_pauli_product(&pauli_product, gates)?;
+    let (decoherence_product, to_decoherence_phase) =
+        DecoherenceProduct::spin_to_decoherence(conjugated);
+    Ok((
+        decoherence_product,
+        to_pauli_phase * phase * to_decoherence_phase,
+    ))
+}
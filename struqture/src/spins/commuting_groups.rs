@@ -0,0 +1,106 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::PauliProduct;
+use crate::SpinIndex;
+
+/// The commutation relation required between all PauliProducts of a group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PauliCommutation {
+    /// The PauliProducts commute on every qubit, so the group can be measured in a single-qubit basis.
+    QubitWise,
+    /// The PauliProducts commute as a whole.
+    Full,
+}
+
+/// The graph colouring used to partition PauliProducts into groups.
+///
+/// The PauliProducts are the vertices of a graph in which two PauliProducts are connected when they do not commute.
+/// Every colour of a colouring of the graph is one group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphColouring {
+    /// Assigns the smallest available colour to the PauliProducts in the order of the operator.
+    Greedy,
+    /// Assigns the smallest available colour to the PauliProducts in the order of decreasing number of non-commuting PauliProducts.
+    LargestDegreeFirst,
+}
+
+/// Partitions PauliProducts into groups of mutually commuting PauliProducts.
+///
+/// # Arguments
+///
+/// * `products` - The PauliProducts to partition.
+/// * `commutation` - The commutation relation within a group.
+/// * `colouring` - The graph colouring used for the partition.
+///
+/// # Returns
+///
+/// * `Vec<(Vec<usize>, Option<PauliProduct>)>` - The indices of the PauliProducts of each group in increasing order,
+///   and for qubit-wise commuting groups the single-qubit measurement basis.
+pub(crate) fn commuting_groups(
+    products: &[&PauliProduct],
+    commutation: PauliCommutation,
+    colouring: GraphColouring,
+) -> Vec<(Vec<usize>, Option<PauliProduct>)> {
+    let conflicts: Vec<Vec<usize>> = products
+        .iter()
+        .map(|product| {
+            products
+                .iter()
+                .enumerate()
+                .filter(|(_, other)| match commutation {
+                    PauliCommutation::QubitWise => !product.qubit_wise_commutes_with(other),
+                    PauliCommutation::Full => !product.commutes_with(other),
+                })
+                .map(|(index, _)| index)
+                .collect()
+        })
+        .collect();
+    let mut order: Vec<usize> = (0..products.len()).collect();
+    if colouring == GraphColouring::LargestDegreeFirst {
+        // The sort is stable, so PauliProducts with the same degree keep their order
+        order.sort_by_key(|index| std::cmp::Reverse(conflicts[*index].len()));
+    }
+    let mut colours: Vec<Option<usize>> = vec![None; products.len()];
+    let mut number_colours = 0;
+    for index in order {
+        let mut used = vec![false; number_colours];
+        for colour in conflicts[index].iter().filter_map(|other| colours[*other]) {
+            used[colour] = true;
+        }
+        let colour = used.iter().position(|used| !used).unwrap_or(number_colours);
+        number_colours = number_colours.max(colour + 1);
+        colours[index] = Some(colour);
+    }
+    let mut groups: Vec<Vec<usize>> = vec![Vec::new(); number_colours];
+    for (index, colour) in colours.into_iter().enumerate() {
+        groups[colour.expect("Internal error: every PauliProduct is coloured.")].push(index);
+    }
+    groups
+        .into_iter()
+        .map(|group| {
+            let basis = match commutation {
+                PauliCommutation::QubitWise => {
+                    Some(group.iter().fold(PauliProduct::new(), |basis, index| {
+                        products[*index]
+                            .iter()
+                            .fold(basis, |basis, (qubit, pauli)| {
+                                basis.set_pauli(*qubit, *pauli)
+                            })
+                    }))
+                }
+                PauliCommutation::Full => None,
+            };
+            (group, basis)
+        })
+        .collect()
+}
@@ -10,11 +10,15 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{OperateOnSpins, PauliOperator};
+use super::{
+    apply_terms_to_state, apply_terms_to_state_into, BasisStateAction, OperateOnSpins,
+    PauliOperator,
+};
 use crate::fermions::FermionOperator;
 use crate::mappings::JordanWignerSpinToFermion;
 use crate::spins::DecoherenceProduct;
 use crate::{OperateOnDensityMatrix, OperateOnState, SpinIndex, StruqtureError, SymmetricIndex};
+use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde::{Deserialize, Serialize};
 use std::fmt::{self, Write};
@@ -254,6 +258,57 @@ impl DecoherenceOperator {
         }
     }
 
+    /// Applies the DecoherenceOperator to a state vector without constructing its matrix.
+    ///
+    /// The state vector is given in the little endian computational basis and the number of spins
+    /// is inferred from its length.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - The state vector the DecoherenceOperator is applied to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Complex64>)` - The state vector after applying the DecoherenceOperator.
+    /// * `Err(StruqtureError::GenericError)` - The length of the state vector is not a power of two or too small for the DecoherenceOperator.
+    /// * `Err(StruqtureError::CalculatorError)` - CalculatorFloat could not be converted to f64.
+    pub fn apply_to_state(&self, state: &[Complex64]) -> Result<Vec<Complex64>, StruqtureError> {
+        apply_terms_to_state(
+            self.iter()
+                .map(|(product, value)| (BasisStateAction::from(product), value.clone())),
+            self.current_number_spins(),
+            state,
+        )
+    }
+
+    /// Applies the DecoherenceOperator to a state vector without constructing its matrix, writing the result into a given buffer.
+    ///
+    /// Unlike `apply_to_state`, no new vector is allocated. The previous content of the buffer is overwritten.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - The state vector the DecoherenceOperator is applied to.
+    /// * `result` - The buffer the state vector after applying the DecoherenceOperator is written into, of the same length as `state`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The result was written into the buffer.
+    /// * `Err(StruqtureError::GenericError)` - The length of the state vector is not a power of two or too small for the DecoherenceOperator, or the buffer does not have the same length as the state vector.
+    /// * `Err(StruqtureError::CalculatorError)` - CalculatorFloat could not be converted to f64.
+    pub fn apply_to_state_into(
+        &self,
+        state: &[Complex64],
+        result: &mut [Complex64],
+    ) -> Result<(), StruqtureError> {
+        apply_terms_to_state_into(
+            self.iter()
+                .map(|(product, value)| (BasisStateAction::from(product), value.clone())),
+            self.current_number_spins(),
+            state,
+            result,
+        )
+    }
+
     /// Export to struqture_1 format.
     #[cfg(feature = "struqture_1_export")]
     pub fn to_struqture_1(
@@ -32,6 +32,12 @@ pub use decoherence_operator::*;
 mod pauli_operator;
 pub use pauli_operator::*;
 
+mod packed_pauli_product;
+pub use packed_pauli_product::*;
+
+mod packed_pauli_operator;
+pub use packed_pauli_operator::*;
+
 mod pauli_hamiltonian;
 pub use pauli_hamiltonian::*;
 
@@ -50,7 +56,29 @@ pub use plus_minus_operator::*;
 mod plus_minus_noise_operator;
 pub use plus_minus_noise_operator::*;
 
-use crate::CooSparseMatrix;
+mod z2_symmetries;
+pub use z2_symmetries::*;
+
+mod clifford;
+pub use clifford::CliffordGate;
+pub(crate) use clifford::{conjugate_decoherence_product, conjugate_pauli_product};
+
+mod commuting_groups;
+pub(crate) use commuting_groups::commuting_groups;
+pub use commuting_groups::{GraphColouring, PauliCommutation};
+
+mod basis_state_action;
+pub(crate) use basis_state_action::{
+    add_commutator_to_density_matrix, add_lindblad_term_to_density_matrix, apply_terms_to_state,
+    apply_terms_to_state_into, check_density_matrix, BasisStateAction,
+};
+
+use crate::sparse_matrix_assembly::{basis_states_with_set_bits, hash_map_to_coo};
+use crate::{
+    csr_to_csc, push_csr_row, CooSparseMatrix, CscSparseMatrix, CsrSparseMatrix,
+    SectorSparseMatrix, MAXIMUM_DENSE_MATRIX_DIMENSION,
+};
+use ndarray::Array2;
 
 /// Trait for non-Hermitian operations on spins.
 ///
@@ -119,11 +147,11 @@ where
     ) -> Result<HashMap<(usize, usize), Complex64>, StruqtureError> {
         let dimension = 2usize.pow(number_spins as u32);
         let mut matrix: HashMap<(usize, usize), Complex64> = HashMap::new();
-        for row in 0..dimension {
-            for (column, val) in self.sparse_matrix_entries_on_row(row)?.into_iter() {
+        self.for_each_sparse_matrix_row(dimension, |row, entries| {
+            for (column, val) in entries.into_iter() {
                 matrix.insert((row, column), val);
             }
-        }
+        })?;
         Ok(matrix)
     }
 
@@ -145,16 +173,209 @@ where
         let mut rows: Vec<usize> = Vec::with_capacity(capacity);
         let mut columns: Vec<usize> = Vec::with_capacity(capacity);
 
-        for row in 0..dimension {
-            for (col, val) in self.sparse_matrix_entries_on_row(row)?.into_iter() {
+        self.for_each_sparse_matrix_row(dimension, |row, entries| {
+            for (col, val) in entries.into_iter() {
                 rows.push(row);
                 columns.push(col);
                 values.push(val);
             }
-        }
+        })?;
         Ok((values, (rows, columns)))
     }
 
+    /// Constructs the sparse matrix representation of the operator-like object in CSR format with a given number of spins.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins for which to construct the sparse matrix in CSR form.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Vec<Complex64>, Vec<usize>, Vec<usize>))` - The little endian matrix representation of the operator-like object as (values, column indices, row pointers) with sorted column indices.
+    /// * `Err(StruqtureError::GenericError)` - The number of spins is smaller than the number of spins Self acts on.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn sparse_matrix_csr(&'a self, number_spins: usize) -> Result<CsrSparseMatrix, StruqtureError> {
+        check_sparse_matrix_spins(number_spins, self.current_number_spins())?;
+        let dimension = 2usize.pow(number_spins as u32);
+        let mut csr: CsrSparseMatrix = (Vec::new(), Vec::new(), vec![0]);
+        self.for_each_sparse_matrix_row(dimension, |_, entries| push_csr_row(&mut csr, entries))?;
+        Ok(csr)
+    }
+
+    /// Constructs the sparse matrix representation of the operator-like object in CSC format with a given number of spins.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins for which to construct the sparse matrix in CSC form.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Vec<Complex64>, Vec<usize>, Vec<usize>))` - The little endian matrix representation of the operator-like object as (values, row indices, column pointers) with sorted row indices.
+    /// * `Err(StruqtureError::GenericError)` - The number of spins is smaller than the number of spins Self acts on.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn sparse_matrix_csc(&'a self, number_spins: usize) -> Result<CscSparseMatrix, StruqtureError> {
+        let dimension = 2usize.pow(number_spins as u32);
+        Ok(csr_to_csc(self.sparse_matrix_csr(number_spins)?, dimension))
+    }
+
+    /// Constructs the sparse matrix representation of Self restricted to a fixed number of up spins.
+    ///
+    /// Only the basis states of the sector returned by [crate::spins::magnetization_sector_basis] are enumerated
+    /// and their rows are obtained with `sparse_matrix_entries_on_row`.
+    /// The rows and columns of the matrix are the positions of the basis states in the returned basis.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins for which to construct the sparse matrix.
+    /// * `number_up_spins` - The number of spins in the state |0> (eigenvalue +1 of Z) in the sector.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SectorSparseMatrix)` - The matrix in the sector and the little endian basis states of the sector.
+    /// * `Err(StruqtureError::GenericError)` - The operator does not conserve the number of up spins, the number of spins is smaller than the number of spins Self acts on or the number of up spins is larger than the number of spins.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn sparse_matrix_magnetization_sector(
+        &'a self,
+        number_spins: usize,
+        number_up_spins: usize,
+    ) -> Result<SectorSparseMatrix, StruqtureError> {
+        if number_spins < self.current_number_spins() {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "Cannot construct a matrix for {number_spins} spins of an object acting on {} spins.",
+                    self.current_number_spins()
+                ),
+            });
+        }
+        let basis = magnetization_sector_basis(number_spins, number_up_spins)?;
+        let leaves_sector = || {
+            StruqtureError::GenericError {
+            msg: format!(
+                "Operator does not conserve the number of up spins and cannot be restricted to the sector with {number_up_spins} up spins."
+            ),
+        }
+        };
+        let mut matrix: HashMap<(usize, usize), Complex64> = HashMap::new();
+        for (row, state) in basis.iter().enumerate() {
+            for (column_state, val) in self.sparse_matrix_entries_on_row(*state)?.into_iter() {
+                if val == Complex64::new(0.0, 0.0) {
+                    continue;
+                }
+                let column = basis
+                    .binary_search(&column_state)
+                    .map_err(|_| leaves_sector())?;
+                matrix.insert((row, column), val);
+            }
+        }
+        // The rows of the sector only show transitions into the sector, transitions out of the
+        // sector are found on the rows reached by flipping the spins of the terms.
+        let mut flip_masks: Vec<usize> = self
+            .iter()
+            .map(|(index, _)| {
+                index
+                    .iter()
+                    .filter(|(_, pauliop)| {
+                        matches!(
+                            SinglePauliOperator::from(*pauliop),
+                            SinglePauliOperator::X | SinglePauliOperator::Y
+                        )
+                    })
+                    .fold(0usize, |mask, (spin_op_index, _)| {
+                        mask | (1 << spin_op_index)
+                    })
+            })
+            .collect();
+        flip_masks.sort_unstable();
+        flip_masks.dedup();
+        let number_down_spins = number_spins - number_up_spins;
+        for state in basis.iter() {
+            for mask in flip_masks.iter() {
+                let row_state = state ^ mask;
+                if row_state.count_ones() as usize != number_down_spins
+                    && self
+                        .sparse_matrix_entries_on_row(row_state)?
+                        .get(state)
+                        .is_some_and(|val| *val != Complex64::new(0.0, 0.0))
+                {
+                    return Err(leaves_sector());
+                }
+            }
+        }
+        Ok((matrix, basis))
+    }
+
+    /// Constructs the sparse matrix representation of Self restricted to a fixed number of up spins in COO representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins for which to construct the sparse matrix.
+    /// * `number_up_spins` - The number of spins in the state |0> (eigenvalue +1 of Z) in the sector.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(((Vec<Complex64>, (Vec<usize>, Vec<usize>)), Vec<usize>))` - The matrix in the sector sorted by rows and columns and the little endian basis states of the sector.
+    /// * `Err(StruqtureError::GenericError)` - The operator does not conserve the number of up spins, the number of spins is smaller than the number of spins Self acts on or the number of up spins is larger than the number of spins.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn sparse_matrix_coo_magnetization_sector(
+        &'a self,
+        number_spins: usize,
+        number_up_spins: usize,
+    ) -> Result<(CooSparseMatrix, Vec<usize>), StruqtureError> {
+        let (matrix, basis) =
+            self.sparse_matrix_magnetization_sector(number_spins, number_up_spins)?;
+        Ok((hash_map_to_coo(matrix), basis))
+    }
+
+    /// Constructs the dense matrix representation of the operator-like object with a given number of spins.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins for which to construct the dense matrix.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Array2<Complex64>)` - The little endian matrix representation of the operator-like object.
+    /// * `Err(StruqtureError::DenseMatrixTooLarge)` - The dimension of the matrix exceeds MAXIMUM_DENSE_MATRIX_DIMENSION.
+    /// * `Err(StruqtureError::GenericError)` - The number of spins is smaller than the number of spins the object acts on.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn dense_matrix(&'a self, number_spins: usize) -> Result<Array2<Complex64>, StruqtureError> {
+        let dimension = dense_matrix_dimension(number_spins, self.current_number_spins(), 1)?;
+        let mut matrix: Array2<Complex64> = Array2::zeros((dimension, dimension));
+        self.for_each_sparse_matrix_row(dimension, |row, entries| {
+            for (column, val) in entries.into_iter() {
+                matrix[(row, column)] += val;
+            }
+        })?;
+        Ok(matrix)
+    }
+
+    /// Constructs the entries of every row of the sparse matrix and consumes them in the order of the rows.
+    ///
+    /// The provided implementation constructs the rows one after the other on a single thread. With the
+    /// `parallel` feature, all implementations of this trait in struqture override it to construct the
+    /// rows on several threads, implementations outside of struqture have to override it themselves
+    /// to do the same.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_rows` - The number of rows of the sparse matrix.
+    /// * `consume` - The function consuming the row index and entries of every row in increasing order.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - All rows were consumed.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn for_each_sparse_matrix_row(
+        &'a self,
+        number_rows: usize,
+        mut consume: impl FnMut(usize, HashMap<usize, Complex64>),
+    ) -> Result<(), StruqtureError> {
+        for row in 0..number_rows {
+            consume(row, self.sparse_matrix_entries_on_row(row)?);
+        }
+        Ok(())
+    }
+
     /// Constructs the sparse matrix entries for one row of the sparse matrix.
     ///
     /// # Arguments
@@ -338,14 +559,15 @@ pub trait ToSparseMatrixSuperOperator<'a>: OperateOnSpins<'a> + PartialEq + Clon
     ) -> Result<HashMap<(usize, usize), Complex64>, StruqtureError> {
         let dimension = 2usize.pow(number_spins as u32);
         let mut matrix: HashMap<(usize, usize), Complex64> = HashMap::new();
-        for row in 0..dimension.pow(2) {
-            for (column, val) in self
-                .sparse_matrix_superoperator_entries_on_row(row, number_spins)?
-                .into_iter()
-            {
-                matrix.insert((row, column), val);
-            }
-        }
+        self.for_each_sparse_matrix_superoperator_row(
+            dimension.pow(2),
+            number_spins,
+            |row, entries| {
+                for (column, val) in entries.into_iter() {
+                    matrix.insert((row, column), val);
+                }
+            },
+        )?;
         Ok(matrix)
     }
 
@@ -373,17 +595,136 @@ pub trait ToSparseMatrixSuperOperator<'a>: OperateOnSpins<'a> + PartialEq + Clon
         let mut rows: Vec<usize> = Vec::with_capacity(capacity);
         let mut columns: Vec<usize> = Vec::with_capacity(capacity);
 
-        for row in 0..dimension.pow(2) {
-            for (col, val) in self
-                .sparse_matrix_superoperator_entries_on_row(row, number_spins)?
-                .into_iter()
-            {
-                rows.push(row);
-                columns.push(col);
-                values.push(val);
+        self.for_each_sparse_matrix_superoperator_row(
+            dimension.pow(2),
+            number_spins,
+            |row, entries| {
+                for (col, val) in entries.into_iter() {
+                    rows.push(row);
+                    columns.push(col);
+                    values.push(val);
+                }
+            },
+        )?;
+        Ok((values, (rows, columns)))
+    }
+
+    /// Constructs the sparse matrix representation of the superoperator in CSR representation.
+    ///
+    /// The superoperator for the operator O is defined as the Matrix S so that
+    /// `flatten(-i [O, p]) = S flatten(p)` wher `[,]` is the commutator, `p` is a matrix
+    /// and `flatten` flattens a matrix into a vector in row-major form.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins for which to construct the sparse matrix in CSR form.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Vec<Complex64>, Vec<usize>, Vec<usize>))` - The little endian matrix representation of Self as (values, column indices, row pointers) with sorted column indices.
+    /// * `Err(StruqtureError::GenericError)` - The number of spins is smaller than the number of spins Self acts on.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn sparse_matrix_superoperator_csr(
+        &'a self,
+        number_spins: usize,
+    ) -> Result<CsrSparseMatrix, StruqtureError> {
+        check_sparse_matrix_spins(number_spins, self.current_number_spins())?;
+        let dimension = 2usize.pow(number_spins as u32);
+        let mut csr: CsrSparseMatrix = (Vec::new(), Vec::new(), vec![0]);
+        self.for_each_sparse_matrix_superoperator_row(
+            dimension.pow(2),
+            number_spins,
+            |_, entries| push_csr_row(&mut csr, entries),
+        )?;
+        Ok(csr)
+    }
+
+    /// Constructs the sparse matrix representation of the superoperator in CSC representation.
+    ///
+    /// The superoperator for the operator O is defined as the Matrix S so that
+    /// `flatten(-i [O, p]) = S flatten(p)` wher `[,]` is the commutator, `p` is a matrix
+    /// and `flatten` flattens a matrix into a vector in row-major form.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins for which to construct the sparse matrix in CSC form.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Vec<Complex64>, Vec<usize>, Vec<usize>))` - The little endian matrix representation of Self as (values, row indices, column pointers) with sorted row indices.
+    /// * `Err(StruqtureError::GenericError)` - The number of spins is smaller than the number of spins Self acts on.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn sparse_matrix_superoperator_csc(
+        &'a self,
+        number_spins: usize,
+    ) -> Result<CscSparseMatrix, StruqtureError> {
+        let dimension = 2usize.pow(number_spins as u32);
+        Ok(csr_to_csc(
+            self.sparse_matrix_superoperator_csr(number_spins)?,
+            dimension.pow(2),
+        ))
+    }
+
+    /// Constructs the dense matrix representation of the superoperator.
+    ///
+    /// The superoperator for the operator O is defined as the Matrix S so that
+    /// `flatten(-i [O, p]) = S flatten(p)` wher `[,]` is the commutator, `p` is a matrix
+    /// and `flatten` flattens a matrix into a vector in row-major form.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins for which to construct the dense matrix.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Array2<Complex64>)` - The little endian matrix representation of Self.
+    /// * `Err(StruqtureError::DenseMatrixTooLarge)` - The dimension of the superoperator exceeds MAXIMUM_DENSE_MATRIX_DIMENSION.
+    /// * `Err(StruqtureError::GenericError)` - The number of spins is smaller than the number of spins Self acts on.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn dense_superoperator(
+        &'a self,
+        number_spins: usize,
+    ) -> Result<Array2<Complex64>, StruqtureError> {
+        let dimension = dense_matrix_dimension(number_spins, self.current_number_spins(), 2)?;
+        let mut matrix: Array2<Complex64> = Array2::zeros((dimension, dimension));
+        self.for_each_sparse_matrix_superoperator_row(dimension, number_spins, |row, entries| {
+            for (column, val) in entries.into_iter() {
+                matrix[(row, column)] += val;
             }
+        })?;
+        Ok(matrix)
+    }
+
+    /// Constructs the entries of every row of the sparse superoperator matrix and consumes them in the order of the rows.
+    ///
+    /// The provided implementation constructs the rows one after the other on a single thread. With the
+    /// `parallel` feature, all implementations of this trait in struqture override it to construct the
+    /// rows on several threads, implementations outside of struqture have to override it themselves
+    /// to do the same.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_rows` - The number of rows of the superoperator matrix.
+    /// * `number_spins` - The number of spins for which to construct the superoperator matrix.
+    /// * `consume` - The function consuming the row index and entries of every row in increasing order.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - All rows were consumed.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn for_each_sparse_matrix_superoperator_row(
+        &'a self,
+        number_rows: usize,
+        number_spins: usize,
+        mut consume: impl FnMut(usize, HashMap<usize, Complex64>),
+    ) -> Result<(), StruqtureError> {
+        for row in 0..number_rows {
+            consume(
+                row,
+                self.sparse_matrix_superoperator_entries_on_row(row, number_spins)?,
+            );
         }
-        Ok((values, (rows, columns)))
+        Ok(())
     }
 
     /// Constructs the sparse matrix entries for one row of the sparse matrix superoperator.
@@ -404,6 +745,116 @@ pub trait ToSparseMatrixSuperOperator<'a>: OperateOnSpins<'a> + PartialEq + Clon
     ) -> Result<HashMap<usize, Complex<f64>>, StruqtureError>;
 }
 
+/// Checks that a sparse matrix with a given number of spins contains all spins the object acts on.
+///
+/// # Arguments
+///
+/// * `number_spins` - The number of spins for which to construct the sparse matrix.
+/// * `current_number_spins` - The number of spins the object acts on.
+///
+/// # Returns
+///
+/// * `Ok(())` - The number of spins is large enough.
+/// * `Err(StruqtureError::GenericError)` - The number of spins is smaller than the number of spins the object acts on.
+fn check_sparse_matrix_spins(
+    number_spins: usize,
+    current_number_spins: usize,
+) -> Result<(), StruqtureError> {
+    if number_spins < current_number_spins {
+        return Err(StruqtureError::GenericError {
+            msg: format!(
+                "Cannot construct a sparse matrix for {number_spins} spins of an object acting on {current_number_spins} spins."
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// Returns the dimension of a dense matrix for a given number of spins.
+///
+/// # Arguments
+///
+/// * `number_spins` - The number of spins for which to construct the dense matrix.
+/// * `current_number_spins` - The number of spins the object acts on.
+/// * `spins_per_site` - The number of spin indices per spin, 1 for operators and 2 for superoperators.
+///
+/// # Returns
+///
+/// * `Ok(usize)` - The dimension of the dense matrix.
+/// * `Err(StruqtureError::DenseMatrixTooLarge)` - The dimension exceeds MAXIMUM_DENSE_MATRIX_DIMENSION.
+/// * `Err(StruqtureError::GenericError)` - The number of spins is smaller than the number of spins the object acts on.
+fn dense_matrix_dimension(
+    number_spins: usize,
+    current_number_spins: usize,
+    spins_per_site: usize,
+) -> Result<usize, StruqtureError> {
+    if number_spins < current_number_spins {
+        return Err(StruqtureError::GenericError {
+            msg: format!(
+                "Cannot construct a dense matrix for {number_spins} spins of an object acting on {current_number_spins} spins."
+            ),
+        });
+    }
+    let dimension = number_spins
+        .checked_mul(spins_per_site)
+        .and_then(|exponent| u32::try_from(exponent).ok())
+        .and_then(|exponent| 2usize.checked_pow(exponent))
+        .unwrap_or(usize::MAX);
+    if dimension > MAXIMUM_DENSE_MATRIX_DIMENSION {
+        return Err(StruqtureError::DenseMatrixTooLarge {
+            dimension,
+            maximum_dimension: MAXIMUM_DENSE_MATRIX_DIMENSION,
+        });
+    }
+    Ok(dimension)
+}
+
+/// Returns the little endian basis states of the spin sector with a fixed number of up spins.
+///
+/// A spin is up in the state |0>, the eigenstate of Z with the eigenvalue +1.
+/// The basis states are sorted in ascending order, the position of a basis state in the returned vector
+/// is the row (column) index used by the sector-restricted matrices of spin operators.
+///
+/// # Arguments
+///
+/// * `number_spins` - The number of spins.
+/// * `number_up_spins` - The number of up spins of the sector.
+///
+/// # Returns
+///
+/// * `Ok(Vec<usize>)` - The sorted basis states of the sector.
+/// * `Err(StruqtureError::GenericError)` - The number of up spins is larger than the number of spins or the number of spins is too large.
+///
+/// # Example
+/// ```
+/// use struqture::spins::magnetization_sector_basis;
+///
+/// // With spin 0 written first, the states |110>, |101> and |011> with a single spin up (|0>) are 0b011, 0b101 and 0b110
+/// assert_eq!(magnetization_sector_basis(3, 1).unwrap(), vec![3, 5, 6]);
+/// ```
+pub fn magnetization_sector_basis(
+    number_spins: usize,
+    number_up_spins: usize,
+) -> Result<Vec<usize>, StruqtureError> {
+    if number_up_spins > number_spins {
+        return Err(StruqtureError::GenericError {
+            msg: format!(
+                "Cannot have {number_up_spins} up spins in a system of {number_spins} spins."
+            ),
+        });
+    }
+    if number_spins >= usize::BITS as usize {
+        return Err(StruqtureError::GenericError {
+            msg: format!("Cannot construct the magnetization sector of {number_spins} spins."),
+        });
+    }
+    // Bit i is set when spin i is down
+    Ok(basis_states_with_set_bits(
+        number_spins,
+        Some(number_spins - number_up_spins),
+    ))
+}
+
 /// Trait for Hermitian operations on spins.
 ///
 /// # Example
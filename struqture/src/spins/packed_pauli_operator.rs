@@ -0,0 +1,482 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{OperateOnSpins, PackedPauliProduct, PauliOperator, PauliProduct};
+use crate::{OperateOnDensityMatrix, OperateOnState, StruqtureError, SymmetricIndex};
+use indexmap::map::{Entry, Iter};
+use indexmap::IndexMap;
+use qoqo_calculator::CalculatorComplex;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Write};
+use std::iter::{FromIterator, IntoIterator};
+use std::ops;
+
+/// PackedPauliOperators are PauliOperators storing their terms as PackedPauliProducts.
+///
+/// The multiplication of two PackedPauliOperators multiplies the bit-packed pauli strings with popcounts
+/// instead of merging the sorted qubit indices of PauliProducts, which makes it considerably faster for
+/// operators with many terms.
+/// A PackedPauliOperator converts losslessly to and from a PauliOperator and is serialised as one.
+///
+/// # Example
+///
+/// ```
+/// use struqture::prelude::*;
+/// use qoqo_calculator::CalculatorComplex;
+/// use struqture::spins::{PackedPauliOperator, PauliOperator, PauliProduct};
+///
+/// let mut operator = PauliOperator::new();
+/// operator.add_operator_product(PauliProduct::new().x(0).x(1), CalculatorComplex::from(0.5)).unwrap();
+/// operator.add_operator_product(PauliProduct::new().z(0), CalculatorComplex::from(0.2)).unwrap();
+///
+/// let packed = PackedPauliOperator::from(operator.clone());
+/// let square = packed.clone() * packed;
+/// assert_eq!(PauliOperator::from(square), operator.clone() * operator);
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(from = "PauliOperator")]
+#[serde(into = "PauliOperator")]
+pub struct PackedPauliOperator {
+    // The internal HashMap of PackedPauliProducts and coefficients (CalculatorComplex)
+    internal_map: IndexMap<PackedPauliProduct, CalculatorComplex>,
+}
+
+#[cfg(feature = "json_schema")]
+impl schemars::JsonSchema for PackedPauliOperator {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "PauliOperator".into()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        <PauliOperator>::json_schema(generator)
+    }
+}
+
+impl<'a> OperateOnDensityMatrix<'a> for PackedPauliOperator {
+    type Value = CalculatorComplex;
+    type Index = PackedPauliProduct;
+
+    // From trait
+    fn get(&self, key: &Self::Index) -> &Self::Value {
+        match self.internal_map.get(key) {
+            Some(value) => value,
+            None => &CalculatorComplex::ZERO,
+        }
+    }
+
+    // From trait
+    fn iter(&'a self) -> impl ExactSizeIterator<Item = (&'a Self::Index, &'a Self::Value)> {
+        self.internal_map.iter()
+    }
+
+    // From trait
+    fn keys(&'a self) -> impl ExactSizeIterator<Item = &'a Self::Index> {
+        self.internal_map.keys()
+    }
+
+    // From trait
+    fn values(&'a self) -> impl ExactSizeIterator<Item = &'a Self::Value> {
+        self.internal_map.values()
+    }
+
+    // From trait
+    fn remove(&mut self, key: &Self::Index) -> Option<Self::Value> {
+        self.internal_map.shift_remove(key)
+    }
+
+    // From trait
+    fn empty_clone(&self, capacity: Option<usize>) -> Self {
+        match capacity {
+            Some(cap) => Self::with_capacity(cap),
+            None => Self::new(),
+        }
+    }
+
+    /// Overwrites an existing entry or sets a new entry in the PackedPauliOperator with the given (PackedPauliProduct key, CalculatorComplex value) pair.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The PackedPauliProduct key to set in the PackedPauliOperator.
+    /// * `value` - The corresponding CalculatorComplex value to set for the key in the PackedPauliOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(CalculatorComplex))` - The key existed, this is the value it had before it was set with the value input.
+    /// * `Ok(None)` - The key did not exist, it has been set with its corresponding value.
+    fn set(
+        &mut self,
+        key: Self::Index,
+        value: Self::Value,
+    ) -> Result<Option<Self::Value>, StruqtureError> {
+        if value != CalculatorComplex::ZERO {
+            Ok(self.internal_map.insert(key, value))
+        } else {
+            match self.internal_map.entry(key) {
+                Entry::Occupied(val) => Ok(Some(val.shift_remove())),
+                Entry::Vacant(_) => Ok(None),
+            }
+        }
+    }
+}
+
+impl OperateOnState<'_> for PackedPauliOperator {
+    // From trait
+    fn hermitian_conjugate(&self) -> Self {
+        let mut new_operator = Self::with_capacity(self.len());
+        for (packed_product, value) in self.iter() {
+            let (new_packed_product, prefactor) = packed_product.hermitian_conjugate();
+            new_operator
+                .add_operator_product(new_packed_product, value.conj() * prefactor)
+                .expect("Internal bug in add_operator_product");
+        }
+        new_operator
+    }
+}
+
+impl OperateOnSpins<'_> for PackedPauliOperator {
+    /// Gets the maximum index of the PackedPauliOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of spins in the PackedPauliOperator.
+    fn current_number_spins(&self) -> usize {
+        self.internal_map
+            .keys()
+            .map(|key| key.current_number_spins())
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// Implements the default function (Default trait) of PackedPauliOperator (an empty PackedPauliOperator).
+///
+impl Default for PackedPauliOperator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Functions for the PackedPauliOperator
+///
+impl PackedPauliOperator {
+    /// Creates a new PackedPauliOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The new (empty) PackedPauliOperator.
+    pub fn new() -> Self {
+        PackedPauliOperator {
+            internal_map: IndexMap::new(),
+        }
+    }
+
+    /// Creates a new PackedPauliOperator with pre-allocated capacity.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The pre-allocated capacity of the system.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The new (empty) PackedPauliOperator.
+    pub fn with_capacity(capacity: usize) -> Self {
+        PackedPauliOperator {
+            internal_map: IndexMap::with_capacity(capacity),
+        }
+    }
+}
+
+impl From<PauliOperator> for PackedPauliOperator {
+    /// Converts a PauliOperator into a PackedPauliOperator.
+    ///
+    /// # Arguments
+    ///
+    /// * `operator` - The PauliOperator to convert.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The PackedPauliOperator with the same terms in the same order.
+    fn from(operator: PauliOperator) -> Self {
+        PackedPauliOperator {
+            internal_map: operator
+                .into_iter()
+                .map(|(product, value)| (PackedPauliProduct::from(product), value))
+                .collect(),
+        }
+    }
+}
+
+impl From<PackedPauliOperator> for PauliOperator {
+    /// Converts a PackedPauliOperator into a PauliOperator.
+    ///
+    /// # Arguments
+    ///
+    /// * `operator` - The PackedPauliOperator to convert.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The PauliOperator with the same terms in the same order.
+    fn from(operator: PackedPauliOperator) -> Self {
+        operator
+            .into_iter()
+            .map(|(packed_product, value)| (PauliProduct::from(packed_product), value))
+            .collect()
+    }
+}
+
+/// Implements the negative sign function of PackedPauliOperator.
+///
+impl ops::Neg for PackedPauliOperator {
+    type Output = PackedPauliOperator;
+    /// Implement minus sign for PackedPauliOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The PackedPauliOperator * -1.
+    fn neg(self) -> Self {
+        PackedPauliOperator {
+            internal_map: self
+                .internal_map
+                .into_iter()
+                .map(|(key, val)| (key, -val))
+                .collect(),
+        }
+    }
+}
+
+/// Implements the plus function of PackedPauliOperator by PackedPauliOperator.
+///
+impl<T, V> ops::Add<T> for PackedPauliOperator
+where
+    T: IntoIterator<Item = (PackedPauliProduct, V)>,
+    V: Into<CalculatorComplex>,
+{
+    type Output = Self;
+    /// Implements `+` (add) for two PackedPauliOperators.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The PackedPauliOperator to be added.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The two PackedPauliOperators added together.
+    ///
+    /// # Panics
+    ///
+    /// * Internal error in add_operator_product.
+    fn add(mut self, other: T) -> Self {
+        for (key, value) in other.into_iter() {
+            self.add_operator_product(key, Into::<CalculatorComplex>::into(value))
+                .expect("Internal bug in add_operator_product");
+        }
+        self
+    }
+}
+
+/// Implements the minus function of PackedPauliOperator by PackedPauliOperator.
+///
+impl<T, V> ops::Sub<T> for PackedPauliOperator
+where
+    T: IntoIterator<Item = (PackedPauliProduct, V)>,
+    V: Into<CalculatorComplex>,
+{
+    type Output = Self;
+    /// Implements `-` (subtract) for two PackedPauliOperators.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The PackedPauliOperator to be subtracted.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The two PackedPauliOperators subtracted.
+    ///
+    /// # Panics
+    ///
+    /// * Internal error in add_operator_product.
+    fn sub(mut self, other: T) -> Self {
+        for (key, value) in other.into_iter() {
+            self.add_operator_product(key, Into::<CalculatorComplex>::into(value) * -1.0)
+                .expect("Internal bug in add_operator_product");
+        }
+        self
+    }
+}
+
+/// Implements the multiplication function of PackedPauliOperator by CalculatorComplex/CalculatorFloat.
+///
+impl<T> ops::Mul<T> for PackedPauliOperator
+where
+    T: Into<CalculatorComplex>,
+{
+    type Output = Self;
+    /// Implement `*` for PackedPauliOperator and CalculatorComplex/CalculatorFloat.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The CalculatorComplex or CalculatorFloat by which to multiply.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The PackedPauliOperator multiplied by the CalculatorComplex/CalculatorFloat.
+    fn mul(self, other: T) -> Self {
+        let other_cc = Into::<CalculatorComplex>::into(other);
+        PackedPauliOperator {
+            internal_map: self
+                .internal_map
+                .into_iter()
+                .map(|(key, val)| (key, val * other_cc.clone()))
+                .collect(),
+        }
+    }
+}
+
+/// Implements the multiplication function of PackedPauliOperator by PackedPauliOperator.
+///
+impl ops::Mul<PackedPauliOperator> for PackedPauliOperator {
+    type Output = Self;
+    /// Implement `*` for PackedPauliOperator and PackedPauliOperator.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The PackedPauliOperator to multiply by.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The two PackedPauliOperators multiplied.
+    fn mul(self, other: PackedPauliOperator) -> Self {
+        // Coefficients are accumulated before zeros are removed, since removing cancelled
+        // terms one at a time from the ordered map is linear in its size.
+        // The capacity is not set to the number of products, which is too large for large operators.
+        let mut internal_map: IndexMap<PackedPauliProduct, CalculatorComplex> =
+            IndexMap::with_capacity(self.len().max(other.len()));
+        for (pps, vals) in self.iter() {
+            for (ppo, valo) in other.iter() {
+                let (ppp, coefficient) = pps.multiply(ppo);
+                let coefficient = valo.clone() * coefficient * vals.clone();
+                match internal_map.entry(ppp) {
+                    Entry::Occupied(mut entry) => {
+                        let value = entry.get_mut();
+                        *value = coefficient + value.clone();
+                    }
+                    Entry::Vacant(entry) => {
+                        entry.insert(coefficient);
+                    }
+                }
+            }
+        }
+        internal_map.retain(|_, value| *value != CalculatorComplex::ZERO);
+        PackedPauliOperator { internal_map }
+    }
+}
+
+/// Implements the into_iter function (IntoIterator trait) of PackedPauliOperator.
+///
+impl IntoIterator for PackedPauliOperator {
+    type Item = (PackedPauliProduct, CalculatorComplex);
+    type IntoIter = indexmap::map::IntoIter<PackedPauliProduct, CalculatorComplex>;
+    /// Returns the PackedPauliOperator in Iterator form.
+    ///
+    /// # Returns
+    ///
+    /// * `Self::IntoIter` - The PackedPauliOperator in Iterator form.
+    fn into_iter(self) -> Self::IntoIter {
+        self.internal_map.into_iter()
+    }
+}
+
+/// Implements the into_iter function (IntoIterator trait) of reference PackedPauliOperator.
+///
+impl<'a> IntoIterator for &'a PackedPauliOperator {
+    type Item = (&'a PackedPauliProduct, &'a CalculatorComplex);
+    type IntoIter = Iter<'a, PackedPauliProduct, CalculatorComplex>;
+
+    /// Returns the reference PackedPauliOperator in Iterator form.
+    ///
+    /// # Returns
+    ///
+    /// * `Self::IntoIter` - The reference PackedPauliOperator in Iterator form.
+    fn into_iter(self) -> Self::IntoIter {
+        self.internal_map.iter()
+    }
+}
+
+/// Implements the from_iter function (FromIterator trait) of PackedPauliOperator.
+///
+impl FromIterator<(PackedPauliProduct, CalculatorComplex)> for PackedPauliOperator {
+    /// Returns the object in PackedPauliOperator form, from an Iterator form of the object.
+    ///
+    /// # Arguments
+    ///
+    /// * `iter` - The iterator containing the information from which to create the PackedPauliOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `Self::IntoIter` - The iterator in PackedPauliOperator form.
+    ///
+    /// # Panics
+    ///
+    /// * Internal error in add_operator_product.
+    fn from_iter<I: IntoIterator<Item = (PackedPauliProduct, CalculatorComplex)>>(iter: I) -> Self {
+        let mut so = PackedPauliOperator::new();
+        for (pp, cc) in iter {
+            so.add_operator_product(pp, cc)
+                .expect("Internal bug in add_operator_product");
+        }
+        so
+    }
+}
+
+/// Implements the extend function (Extend trait) of PackedPauliOperator.
+///
+impl Extend<(PackedPauliProduct, CalculatorComplex)> for PackedPauliOperator {
+    /// Extends the PackedPauliOperator by the specified operations (in Iterator form).
+    ///
+    /// # Arguments
+    ///
+    /// * `iter` - The iterator containing the operations by which to extend the PackedPauliOperator.
+    ///
+    /// # Panics
+    ///
+    /// * Internal error in add_operator_product.
+    fn extend<I: IntoIterator<Item = (PackedPauliProduct, CalculatorComplex)>>(&mut self, iter: I) {
+        for (pp, cc) in iter {
+            self.add_operator_product(pp, cc)
+                .expect("Internal bug in add_operator_product");
+        }
+    }
+}
+
+/// Implements the format function (Display trait) of PackedPauliOperator.
+///
+impl fmt::Display for PackedPauliOperator {
+    /// Formats the PackedPauliOperator using the given formatter.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The formatter to use.
+    ///
+    /// # Returns
+    ///
+    /// * `std::fmt::Result` - The formatted PackedPauliOperator.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut output = "PackedPauliOperator{\n".to_string();
+        for (key, val) in self.iter() {
+            writeln!(output, "{key}: {val},")?;
+        }
+        output.push('}');
+
+        write!(f, "{output}")
+    }
+}
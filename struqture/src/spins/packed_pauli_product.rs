@@ -0,0 +1,366 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{PauliProduct, SinglePauliOperator};
+use crate::{SpinIndex, StruqtureError, SymmetricIndex};
+use num_complex::Complex64;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::ops::Mul;
+use std::str::FromStr;
+use tinyvec::TinyVec;
+
+/// The number of qubits stored in one word of a PackedPauliProduct.
+const QUBITS_PER_WORD: usize = u64::BITS as usize;
+
+/// PackedPauliProducts are products of pauli matrices stored as bit-packed binary symplectic vectors.
+///
+/// The pauli matrix on qubit i is determined by the bits (x_i, z_i) as in [PauliProduct::to_symplectic].
+/// Multiplying two PackedPauliProducts only uses bitwise operations and popcounts on whole words,
+/// which makes them faster to multiply than PauliProducts when they act on many qubits.
+/// A PackedPauliProduct converts losslessly to and from a PauliProduct and is serialised as one.
+///
+/// # Example
+///
+/// ```
+/// use num_complex::Complex64;
+/// use struqture::prelude::*;
+/// use struqture::spins::{PackedPauliProduct, PauliProduct};
+///
+/// let left = PackedPauliProduct::from(PauliProduct::new().x(0).z(70));
+/// let right = PackedPauliProduct::from(PauliProduct::new().y(0));
+/// let (product, phase) = left * right;
+/// assert_eq!(PauliProduct::from(product), PauliProduct::new().z(0).z(70));
+/// assert_eq!(phase, Complex64::new(0.0, 1.0));
+/// ```
+///
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(from = "PauliProduct")]
+#[serde(into = "PauliProduct")]
+pub struct PackedPauliProduct {
+    /// The x bits of the qubits, without trailing words that are zero in x and z
+    x: TinyVec<[u64; 2]>,
+    /// The z bits of the qubits, with the same number of words as x
+    z: TinyVec<[u64; 2]>,
+}
+
+#[cfg(feature = "json_schema")]
+impl schemars::JsonSchema for PackedPauliProduct {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "struqture::spins::PauliProduct".into()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        <PauliProduct>::json_schema(generator)
+    }
+}
+
+impl PackedPauliProduct {
+    /// Creates a new PackedPauliProduct acting on no qubits.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The new (identity) PackedPauliProduct.
+    pub fn new() -> Self {
+        PackedPauliProduct {
+            x: TinyVec::new(),
+            z: TinyVec::new(),
+        }
+    }
+
+    /// Gets the pauli matrix acting on a qubit.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The qubit to get the pauli matrix of.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(SinglePauliOperator)` - The pauli matrix on the qubit.
+    /// * `None` - The identity acts on the qubit.
+    pub fn get(&self, index: usize) -> Option<SinglePauliOperator> {
+        let word = index / QUBITS_PER_WORD;
+        let mask = 1u64 << (index % QUBITS_PER_WORD);
+        let x_bit = self.x.get(word).is_some_and(|bits| bits & mask != 0);
+        let z_bit = self.z.get(word).is_some_and(|bits| bits & mask != 0);
+        match (x_bit, z_bit) {
+            (false, false) => None,
+            (true, false) => Some(SinglePauliOperator::X),
+            (true, true) => Some(SinglePauliOperator::Y),
+            (false, true) => Some(SinglePauliOperator::Z),
+        }
+    }
+
+    /// Sets the pauli matrix acting on a qubit. This function consumes Self.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The qubit to set the pauli matrix of.
+    /// * `pauli` - The pauli matrix to set, the identity removes the pauli matrix on the qubit.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The PackedPauliProduct with the pauli matrix set.
+    pub fn set_pauli(mut self, index: usize, pauli: SinglePauliOperator) -> Self {
+        let word = index / QUBITS_PER_WORD;
+        let mask = 1u64 << (index % QUBITS_PER_WORD);
+        let (x_bit, z_bit) = match pauli {
+            SinglePauliOperator::Identity => (false, false),
+            SinglePauliOperator::X => (true, false),
+            SinglePauliOperator::Y => (true, true),
+            SinglePauliOperator::Z => (false, true),
+        };
+        if word >= self.x.len() {
+            if !x_bit && !z_bit {
+                return self;
+            }
+            self.x.resize(word + 1, 0);
+            self.z.resize(word + 1, 0);
+        }
+        self.x[word] = if x_bit {
+            self.x[word] | mask
+        } else {
+            self.x[word] & !mask
+        };
+        self.z[word] = if z_bit {
+            self.z[word] | mask
+        } else {
+            self.z[word] & !mask
+        };
+        self.trim();
+        self
+    }
+
+    /// Returns the number of qubits the PackedPauliProduct acts on.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The largest qubit index with a non-identity pauli matrix plus one.
+    pub fn current_number_spins(&self) -> usize {
+        match self.x.last().zip(self.z.last()) {
+            Some((x, z)) => {
+                let highest_bit = QUBITS_PER_WORD - (x | z).leading_zeros() as usize;
+                (self.x.len() - 1) * QUBITS_PER_WORD + highest_bit
+            }
+            None => 0,
+        }
+    }
+
+    /// Returns the qubits and non-identity pauli matrices of the PackedPauliProduct.
+    ///
+    /// # Returns
+    ///
+    /// * `impl Iterator<Item = (usize, SinglePauliOperator)>` - The qubits in increasing order and their pauli matrices.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, SinglePauliOperator)> + '_ {
+        self.x
+            .iter()
+            .zip(self.z.iter())
+            .enumerate()
+            .flat_map(|(word, (x, z))| {
+                let mut bits = x | z;
+                std::iter::from_fn(move || {
+                    if bits == 0 {
+                        return None;
+                    }
+                    let bit = bits.trailing_zeros() as usize;
+                    bits &= bits - 1;
+                    Some(word * QUBITS_PER_WORD + bit)
+                })
+            })
+            .map(|index| {
+                (
+                    index,
+                    self.get(index)
+                        .expect("Internal error: the qubit has a set bit."),
+                )
+            })
+    }
+
+    /// Returns whether the PackedPauliProduct commutes with another PackedPauliProduct.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The PackedPauliProduct to check against.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the two PackedPauliProducts commute.
+    pub fn commutes_with(&self, other: &PackedPauliProduct) -> bool {
+        let anticommuting: u32 = self
+            .x
+            .iter()
+            .zip(self.z.iter())
+            .zip(other.x.iter().zip(other.z.iter()))
+            .map(|((x_left, z_left), (x_right, z_right))| {
+                (x_left & z_right).count_ones() + (z_left & x_right).count_ones()
+            })
+            .sum();
+        anticommuting % 2 == 0
+    }
+
+    /// Multiplies the PackedPauliProduct with another PackedPauliProduct.
+    ///
+    /// Writing a pauli string with symplectic vectors (x, z) as i^(x·z) X^x Z^z, the phase of the product
+    /// follows from the popcounts of the bitwise ands of the symplectic vectors.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The PackedPauliProduct to multiply by from the right.
+    ///
+    /// # Returns
+    ///
+    /// * `(PackedPauliProduct, Complex64)` - The product and its phase.
+    pub fn multiply(&self, other: &PackedPauliProduct) -> (PackedPauliProduct, Complex64) {
+        let words = self.x.len().max(other.x.len());
+        let mut product = PackedPauliProduct {
+            x: TinyVec::with_capacity(words),
+            z: TinyVec::with_capacity(words),
+        };
+        // The exponent of i only matters modulo 4, which is compatible with wrapping arithmetic
+        let mut exponent: u32 = 0;
+        for word in 0..words {
+            let x_left = self.x.get(word).copied().unwrap_or(0);
+            let z_left = self.z.get(word).copied().unwrap_or(0);
+            let x_right = other.x.get(word).copied().unwrap_or(0);
+            let z_right = other.z.get(word).copied().unwrap_or(0);
+            let x_product = x_left ^ x_right;
+            let z_product = z_left ^ z_right;
+            exponent = exponent
+                .wrapping_add((x_left & z_left).count_ones())
+                .wrapping_add((x_right & z_right).count_ones())
+                .wrapping_add(2 * (z_left & x_right).count_ones())
+                .wrapping_add(3 * (x_product & z_product).count_ones());
+            product.x.push(x_product);
+            product.z.push(z_product);
+        }
+        product.trim();
+        let phase = match exponent % 4 {
+            0 => Complex64::new(1.0, 0.0),
+            1 => Complex64::new(0.0, 1.0),
+            2 => Complex64::new(-1.0, 0.0),
+            _ => Complex64::new(0.0, -1.0),
+        };
+        (product, phase)
+    }
+
+    /// Removes trailing words that are zero in x and z, so that equal pauli strings are stored identically.
+    fn trim(&mut self) {
+        while self.x.last() == Some(&0) && self.z.last() == Some(&0) {
+            self.x.pop();
+            self.z.pop();
+        }
+    }
+}
+
+impl SymmetricIndex for PackedPauliProduct {
+    // From trait
+    fn hermitian_conjugate(&self) -> (Self, f64) {
+        (self.clone(), 1.0)
+    }
+
+    // From trait
+    fn is_natural_hermitian(&self) -> bool {
+        true
+    }
+}
+
+/// Implements the multiplication function of PackedPauliProduct by PackedPauliProduct.
+///
+impl Mul<PackedPauliProduct> for PackedPauliProduct {
+    type Output = (Self, Complex64);
+    /// Implement `*` for PackedPauliProduct and PackedPauliProduct.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The PackedPauliProduct to multiply by.
+    ///
+    /// # Returns
+    ///
+    /// * `(Self, Complex64)` - The two PackedPauliProducts multiplied and the resulting prefactor.
+    fn mul(self, rhs: PackedPauliProduct) -> Self::Output {
+        self.multiply(&rhs)
+    }
+}
+
+impl From<PauliProduct> for PackedPauliProduct {
+    /// Converts a PauliProduct into a PackedPauliProduct.
+    ///
+    /// # Arguments
+    ///
+    /// * `product` - The PauliProduct to convert.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The PackedPauliProduct with the same pauli matrices.
+    fn from(product: PauliProduct) -> Self {
+        product
+            .iter()
+            .fold(PackedPauliProduct::new(), |packed, (index, pauli)| {
+                packed.set_pauli(*index, *pauli)
+            })
+    }
+}
+
+impl From<PackedPauliProduct> for PauliProduct {
+    /// Converts a PackedPauliProduct into a PauliProduct.
+    ///
+    /// # Arguments
+    ///
+    /// * `packed` - The PackedPauliProduct to convert.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The PauliProduct with the same pauli matrices.
+    fn from(packed: PackedPauliProduct) -> Self {
+        packed
+            .iter()
+            .fold(PauliProduct::new(), |product, (index, pauli)| {
+                product.set_pauli(index, pauli)
+            })
+    }
+}
+
+/// Implements the from_str function (FromStr trait) of PackedPauliProduct.
+///
+impl FromStr for PackedPauliProduct {
+    type Err = StruqtureError;
+    /// Constructs a PackedPauliProduct from a string in the format of a PauliProduct.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The string to convert.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The successfully converted PackedPauliProduct.
+    /// * `Err(StruqtureError)` - The string is not a valid PauliProduct.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(PauliProduct::from_str(s)?.into())
+    }
+}
+
+/// Implements the format function (Display trait) of PackedPauliProduct.
+///
+impl fmt::Display for PackedPauliProduct {
+    /// Formats the PackedPauliProduct in the format of a PauliProduct.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The formatter to use.
+    ///
+    /// # Returns
+    ///
+    /// * `std::fmt::Result` - The formatted PackedPauliProduct.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", PauliProduct::from(self.clone()))
+    }
+}
@@ -10,17 +10,32 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{OperateOnSpins, PauliOperator, ToSparseMatrixOperator, ToSparseMatrixSuperOperator};
+use super::pauli_operator::commutator_of_pauli_products;
+use super::{
+    add_commutator_to_density_matrix, apply_terms_to_state, apply_terms_to_state_into,
+    check_density_matrix, commuting_groups, BasisStateAction, GraphColouring, OperateOnSpins,
+    PauliCommutation, PauliOperator, PlusMinusProduct, ToSparseMatrixOperator,
+    ToSparseMatrixSuperOperator,
+};
+use crate::bosons::BosonHamiltonian;
 use crate::fermions::{FermionHamiltonian, FermionOperator};
-use crate::mappings::JordanWignerSpinToFermion;
+use crate::mappings::bravyi_kitaev::{conjugate_with_controlled_nots, inverse_bravyi_kitaev_gates};
+use crate::mappings::spin_boson::{hermitian_boson_part, SpinBosonRepresentation};
+use crate::mappings::{BravyiKitaevSpinToFermion, JordanWignerSpinToFermion, SpinToBoson};
+#[cfg(feature = "parallel")]
+use crate::parallel_iteration::for_each_row;
 use crate::prelude::*;
+use crate::sparse_matrix_assembly::complex_coefficient;
 use crate::spins::{HermitianOperateOnSpins, PauliProduct, SpinIndex};
+use crate::symbolic_sum::SymbolicSum;
 use crate::{GetValue, OperateOnDensityMatrix, OperateOnState, StruqtureError};
 use indexmap::map::{Entry, Iter};
 use indexmap::IndexMap;
+use ndarray::Array2;
 use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt::{self, Write};
 use std::iter::{FromIterator, IntoIterator};
 use std::ops;
@@ -206,7 +221,22 @@ impl OperateOnSpins<'_> for PauliHamiltonian {
 
 impl HermitianOperateOnSpins<'_> for PauliHamiltonian {}
 
-impl ToSparseMatrixOperator<'_> for PauliHamiltonian {}
+impl ToSparseMatrixOperator<'_> for PauliHamiltonian {
+    // From trait
+    #[cfg(feature = "parallel")]
+    fn for_each_sparse_matrix_row(
+        &self,
+        number_rows: usize,
+        consume: impl FnMut(usize, std::collections::HashMap<usize, Complex64>),
+    ) -> Result<(), StruqtureError> {
+        for_each_row(
+            number_rows,
+            |row| self.sparse_matrix_entries_on_row(row),
+            consume,
+        )
+    }
+}
+
 impl<'a> ToSparseMatrixSuperOperator<'a> for PauliHamiltonian {
     // From trait
     fn sparse_matrix_superoperator_entries_on_row(
@@ -220,6 +250,27 @@ impl<'a> ToSparseMatrixSuperOperator<'a> for PauliHamiltonian {
             number_spins,
         )
     }
+
+    // From trait
+    #[cfg(feature = "parallel")]
+    fn for_each_sparse_matrix_superoperator_row(
+        &'a self,
+        number_rows: usize,
+        number_spins: usize,
+        consume: impl FnMut(usize, std::collections::HashMap<usize, Complex64>),
+    ) -> Result<(), StruqtureError> {
+        for_each_row(
+            number_rows,
+            |row| {
+                <Self as ToSparseMatrixSuperOperator>::sparse_matrix_superoperator_entries_on_row(
+                    self,
+                    row,
+                    number_spins,
+                )
+            },
+            consume,
+        )
+    }
 }
 
 /// Implements the default function (Default trait) of PauliHamiltonian (an empty PauliHamiltonian).
@@ -259,6 +310,88 @@ impl PauliHamiltonian {
         }
     }
 
+    /// Applies the PauliHamiltonian to a state vector without constructing its matrix.
+    ///
+    /// The state vector is given in the little endian computational basis and the number of spins
+    /// is inferred from its length.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - The state vector the PauliHamiltonian is applied to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Complex64>)` - The state vector after applying the PauliHamiltonian.
+    /// * `Err(StruqtureError::GenericError)` - The length of the state vector is not a power of two or too small for the PauliHamiltonian.
+    /// * `Err(StruqtureError::CalculatorError)` - CalculatorFloat could not be converted to f64.
+    pub fn apply_to_state(&self, state: &[Complex64]) -> Result<Vec<Complex64>, StruqtureError> {
+        apply_terms_to_state(
+            self.iter()
+                .map(|(product, value)| (BasisStateAction::from(product), value.clone())),
+            self.current_number_spins(),
+            state,
+        )
+    }
+
+    /// Applies the PauliHamiltonian to a state vector without constructing its matrix, writing the result into a given buffer.
+    ///
+    /// Unlike `apply_to_state`, no new vector is allocated. The previous content of the buffer is overwritten.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - The state vector the PauliHamiltonian is applied to.
+    /// * `result` - The buffer the state vector after applying the PauliHamiltonian is written into, of the same length as `state`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The result was written into the buffer.
+    /// * `Err(StruqtureError::GenericError)` - The length of the state vector is not a power of two or too small for the PauliHamiltonian, or the buffer does not have the same length as the state vector.
+    /// * `Err(StruqtureError::CalculatorError)` - CalculatorFloat could not be converted to f64.
+    pub fn apply_to_state_into(
+        &self,
+        state: &[Complex64],
+        result: &mut [Complex64],
+    ) -> Result<(), StruqtureError> {
+        apply_terms_to_state_into(
+            self.iter()
+                .map(|(product, value)| (BasisStateAction::from(product), value.clone())),
+            self.current_number_spins(),
+            state,
+            result,
+        )
+    }
+
+    /// Applies the coherent part of the Lindblad equation `-i [H, ρ]` to a density matrix without constructing the superoperator.
+    ///
+    /// The density matrix is given in the little endian computational basis and the number of spins
+    /// is inferred from its dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `density_matrix` - The density matrix ρ the superoperator is applied to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Array2<Complex64>)` - The time derivative `-i [H, ρ]` of the density matrix.
+    /// * `Err(StruqtureError::GenericError)` - The density matrix is not square, its dimension is not a power of two or it is too small for the PauliHamiltonian.
+    /// * `Err(StruqtureError::CalculatorError)` - CalculatorFloat could not be converted to f64.
+    pub fn apply_to_density_matrix(
+        &self,
+        density_matrix: &Array2<Complex64>,
+    ) -> Result<Array2<Complex64>, StruqtureError> {
+        check_density_matrix(density_matrix, self.current_number_spins())?;
+        let mut result = Array2::zeros(density_matrix.raw_dim());
+        for (product, value) in self.iter() {
+            add_commutator_to_density_matrix(
+                &BasisStateAction::from(product),
+                complex_coefficient(value.clone())?,
+                density_matrix,
+                &mut result,
+            );
+        }
+        Ok(result)
+    }
+
     /// Export to struqture_1 format.
     #[cfg(feature = "struqture_1_export")]
     pub fn to_struqture_1(
@@ -284,6 +417,103 @@ impl PauliHamiltonian {
         }
         Ok(new_operator)
     }
+
+    /// Returns the commutator [self, other] = self * other - other * self.
+    ///
+    /// The commutator of two hermitian operators is anti-hermitian, so the result is a PauliOperator.
+    /// Pairs of PauliProducts that commute do not contribute and are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The PauliHamiltonian to take the commutator with.
+    ///
+    /// # Returns
+    ///
+    /// * `PauliOperator` - The commutator of the two PauliHamiltonians.
+    pub fn commutator(&self, other: &PauliHamiltonian) -> PauliOperator {
+        commutator_of_pauli_products(self.internal_map.iter(), other.internal_map.iter(), false)
+    }
+
+    /// Returns the anticommutator {self, other} = self * other + other * self.
+    ///
+    /// Pairs of PauliProducts that anti-commute do not contribute and are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The PauliHamiltonian to take the anticommutator with.
+    ///
+    /// # Returns
+    ///
+    /// * `PauliOperator` - The anticommutator of the two PauliHamiltonians.
+    pub fn anticommutator(&self, other: &PauliHamiltonian) -> PauliOperator {
+        commutator_of_pauli_products(self.internal_map.iter(), other.internal_map.iter(), true)
+    }
+
+    /// Returns whether the PauliHamiltonian conserves the total Sz of the spins.
+    ///
+    /// The PauliHamiltonian is rewritten in terms of raising, lowering and Z operators and every term
+    /// with a non-vanishing coefficient has to contain as many raising as lowering operators.
+    /// The coefficients are summed per expression, so that terms cancel when their symbolic coefficients
+    /// are written identically, like the X and Y parts of the exchange interaction J (XX + YY).
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the PauliHamiltonian commutes with the total Sz.
+    pub fn conserves_total_sz(&self) -> bool {
+        let mut sums: HashMap<PlusMinusProduct, SymbolicSum> = HashMap::new();
+        for (product, value) in self.iter() {
+            let expansion: Vec<(PlusMinusProduct, Complex64)> = product.clone().into();
+            for (plus_minus_product, prefactor) in expansion {
+                sums.entry(plus_minus_product)
+                    .or_default()
+                    .add_float(value, prefactor);
+            }
+        }
+        sums.iter()
+            .all(|(product, sum)| sum.cancels() || product.conserves_total_sz())
+    }
+
+    /// Returns whether the PauliHamiltonian conserves the Z-parity Z_0 Z_1 ... Z_n of the spins.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether every PauliProduct contains an even number of X and Y operators.
+    pub fn conserves_z_parity(&self) -> bool {
+        self.keys().all(|product| product.conserves_z_parity())
+    }
+
+    /// Partitions the terms of the PauliHamiltonian into groups of mutually commuting terms.
+    ///
+    /// # Arguments
+    ///
+    /// * `commutation` - Whether the terms of a group have to commute qubit-wise or as a whole.
+    /// * `colouring` - The graph colouring used for the partition.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<(PauliHamiltonian, Option<PauliProduct>)>` - The groups and, for qubit-wise commuting groups, the measurement basis
+    ///   with the Pauli operator to measure on every qubit that a term of the group acts on.
+    pub fn commuting_groups(
+        &self,
+        commutation: PauliCommutation,
+        colouring: GraphColouring,
+    ) -> Vec<(PauliHamiltonian, Option<PauliProduct>)> {
+        let terms: Vec<(&PauliProduct, _)> = self.iter().collect();
+        let products: Vec<&PauliProduct> = terms.iter().map(|(product, _)| *product).collect();
+        commuting_groups(&products, commutation, colouring)
+            .into_iter()
+            .map(|(group, basis)| {
+                let mut operator = PauliHamiltonian::with_capacity(group.len());
+                for index in group {
+                    let (product, value) = terms[index];
+                    operator.set(product.clone(), value.clone()).expect(
+                        "Internal error: the terms of a PauliHamiltonian can always be set.",
+                    );
+                }
+                (operator, basis)
+            })
+            .collect()
+    }
 }
 
 impl TryFrom<PauliOperator> for PauliHamiltonian {
@@ -608,6 +838,70 @@ impl JordanWignerSpinToFermion for PauliHamiltonian {
     }
 }
 
+impl BravyiKitaevSpinToFermion for PauliHamiltonian {
+    type Output = FermionHamiltonian;
+
+    /// Implements BravyiKitaevSpinToFermion for a PauliHamiltonian.
+    ///
+    /// The convention used is that |0> represents an even and |1> an odd parity of the modes
+    /// stored in a qubit.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins (and fermionic modes) of the mapping.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(FermionHamiltonian)` - The fermionic Hamiltonian that results from the transformation.
+    /// * `Err(StruqtureError::GenericError)` - The PauliHamiltonian acts on more than `number_spins` spins.
+    fn bravyi_kitaev(&self, number_spins: usize) -> Result<Self::Output, StruqtureError> {
+        let gates = inverse_bravyi_kitaev_gates(number_spins, self.current_number_spins())?;
+        Ok(conjugate_with_controlled_nots(self.clone(), &gates)?.jordan_wigner())
+    }
+}
+
+impl SpinToBoson for PauliHamiltonian {
+    type Output = BosonHamiltonian;
+
+    /// Implements the Holstein-Primakoff mapping for a PauliHamiltonian.
+    ///
+    /// The convention used is that |0> of spin i is the vacuum of the bosonic mode i.
+    ///
+    /// # Arguments
+    ///
+    /// * `spin` - The spin S, a positive multiple of 1/2.
+    /// * `order` - The highest power of the occupation in the expansion of the square root.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BosonHamiltonian)` - The bosonic Hamiltonian that results from the transformation.
+    /// * `Err(StruqtureError::GenericError)` - The spin is not a positive multiple of 1/2.
+    fn holstein_primakoff_mapping(
+        &self,
+        spin: f64,
+        order: usize,
+    ) -> Result<Self::Output, StruqtureError> {
+        // The truncated expansions of S^+ and S^- are still hermitian conjugates of each other
+        let operator = SpinBosonRepresentation::holstein_primakoff(spin, order)?
+            .map_pauli_operator(&PauliOperator::from(self.clone()))?;
+        hermitian_boson_part(&operator)
+    }
+
+    /// Implements the Schwinger-boson mapping for a PauliHamiltonian.
+    ///
+    /// The convention used is that spin i is represented by the bosonic modes 2i and 2i + 1.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BosonHamiltonian)` - The bosonic Hamiltonian that results from the transformation.
+    /// * `Err(StruqtureError)` - Error adding an operator product.
+    fn schwinger_boson_mapping(&self) -> Result<Self::Output, StruqtureError> {
+        let operator = SpinBosonRepresentation::SchwingerBoson
+            .map_pauli_operator(&PauliOperator::from(self.clone()))?;
+        hermitian_boson_part(&operator)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -10,12 +10,21 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{OperateOnSpins, SingleDecoherenceOperator, ToSparseMatrixSuperOperator};
+use super::{
+    add_lindblad_term_to_density_matrix, check_density_matrix, conjugate_decoherence_product,
+    BasisStateAction, CliffordGate, OperateOnSpins, SingleDecoherenceOperator,
+    ToSparseMatrixSuperOperator,
+};
 use crate::fermions::FermionLindbladNoiseOperator;
-use crate::mappings::JordanWignerSpinToFermion;
+use crate::mappings::bravyi_kitaev::inverse_bravyi_kitaev_gates;
+use crate::mappings::{BravyiKitaevSpinToFermion, JordanWignerSpinToFermion};
+#[cfg(feature = "parallel")]
+use crate::parallel_iteration::for_each_row;
+use crate::sparse_matrix_assembly::complex_coefficient;
 use crate::spins::{DecoherenceOperator, DecoherenceProduct};
 use crate::{OperateOnDensityMatrix, SpinIndex, StruqtureError, SymmetricIndex};
 use itertools::Itertools;
+use ndarray::Array2;
 use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde::{Deserialize, Serialize};
@@ -252,6 +261,21 @@ impl ToSparseMatrixSuperOperator<'_> for PauliLindbladNoiseOperator {
         }
         Ok(entries)
     }
+
+    // From trait
+    #[cfg(feature = "parallel")]
+    fn for_each_sparse_matrix_superoperator_row(
+        &self,
+        number_rows: usize,
+        number_spins: usize,
+        consume: impl FnMut(usize, HashMap<usize, Complex64>),
+    ) -> Result<(), StruqtureError> {
+        for_each_row(
+            number_rows,
+            |row| self.sparse_matrix_superoperator_entries_on_row(row, number_spins),
+            consume,
+        )
+    }
 }
 
 /// Implements the default function (Default trait) of PauliLindbladNoiseOperator (an empty PauliLindbladNoiseOperator).
@@ -334,6 +358,40 @@ impl PauliLindbladNoiseOperator {
         Ok(())
     }
 
+    /// Applies the PauliLindbladNoiseOperator to a density matrix without constructing the superoperator.
+    ///
+    /// For every noise term with the rate γ and the Lindblad operators (L, R) the contribution
+    /// `γ (L ρ R^† - 1/2 {R^† L, ρ})` is added to the result.
+    /// The density matrix is given in the little endian computational basis and the number of spins
+    /// is inferred from its dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `density_matrix` - The density matrix ρ the superoperator is applied to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Array2<Complex64>)` - The time derivative of the density matrix caused by the noise.
+    /// * `Err(StruqtureError::GenericError)` - The density matrix is not square, its dimension is not a power of two or it is too small for the PauliLindbladNoiseOperator.
+    /// * `Err(StruqtureError::CalculatorError)` - CalculatorFloat could not be converted to f64.
+    pub fn apply_to_density_matrix(
+        &self,
+        density_matrix: &Array2<Complex64>,
+    ) -> Result<Array2<Complex64>, StruqtureError> {
+        check_density_matrix(density_matrix, self.current_number_spins())?;
+        let mut result = Array2::zeros(density_matrix.raw_dim());
+        for ((left, right), value) in self.iter() {
+            add_lindblad_term_to_density_matrix(
+                &BasisStateAction::from(left),
+                &BasisStateAction::from(right),
+                complex_coefficient(value.clone())?,
+                density_matrix,
+                &mut result,
+            );
+        }
+        Ok(result)
+    }
+
     /// Remaps the qubits in the PauliLindbladNoiseOperator.
     ///
     /// # Arguments
@@ -355,6 +413,49 @@ impl PauliLindbladNoiseOperator {
         new_noise
     }
 
+    /// Conjugates the PauliLindbladNoiseOperator with a Clifford gate.
+    ///
+    /// The Lindblad term L ρ R^† is transformed into (C L C^†) ρ (C R C^†)^†, so the noise channel is
+    /// conjugated with the gate C.
+    ///
+    /// # Arguments
+    ///
+    /// * `gate` - The Clifford gate C.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The conjugated PauliLindbladNoiseOperator.
+    /// * `Err(StruqtureError::GenericError)` - A two-qubit gate acts twice on the same qubit.
+    pub fn conjugate_with_clifford(&self, gate: &CliffordGate) -> Result<Self, StruqtureError> {
+        self.conjugate_with_clifford_sequence(std::slice::from_ref(gate))
+    }
+
+    /// Conjugates the PauliLindbladNoiseOperator with a sequence of Clifford gates.
+    ///
+    /// # Arguments
+    ///
+    /// * `gates` - The Clifford gates in the order they are applied.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The conjugated PauliLindbladNoiseOperator.
+    /// * `Err(StruqtureError::GenericError)` - A two-qubit gate acts twice on the same qubit.
+    pub fn conjugate_with_clifford_sequence(
+        &self,
+        gates: &[CliffordGate],
+    ) -> Result<Self, StruqtureError> {
+        let mut new_noise = PauliLindbladNoiseOperator::with_capacity(self.len());
+        for ((left, right), rate) in self.iter() {
+            let (new_left, left_phase) = conjugate_decoherence_product(left, gates)?;
+            let (new_right, right_phase) = conjugate_decoherence_product(right, gates)?;
+            new_noise.add_operator_product(
+                (new_left, new_right),
+                rate.clone() * (left_phase * right_phase.conj()),
+            )?;
+        }
+        Ok(new_noise)
+    }
+
     /// Separate self into an operator with the terms of given number of spins and an operator with the remaining operations
     ///
     /// # Arguments
@@ -825,6 +926,30 @@ impl JordanWignerSpinToFermion for PauliLindbladNoiseOperator {
     }
 }
 
+impl BravyiKitaevSpinToFermion for PauliLindbladNoiseOperator {
+    type Output = FermionLindbladNoiseOperator;
+
+    /// Implements BravyiKitaevSpinToFermion for a PauliLindbladNoiseOperator.
+    ///
+    /// The convention used is that |0> represents an even and |1> an odd parity of the modes
+    /// stored in a qubit.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins (and fermionic modes) of the mapping.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(FermionLindbladNoiseOperator)` - The fermionic noise operator that results from the transformation.
+    /// * `Err(StruqtureError::GenericError)` - The PauliLindbladNoiseOperator acts on more than `number_spins` spins.
+    fn bravyi_kitaev(&self, number_spins: usize) -> Result<Self::Output, StruqtureError> {
+        let gates = inverse_bravyi_kitaev_gates(number_spins, self.current_number_spins())?;
+        Ok(self
+            .conjugate_with_clifford_sequence(&gates)?
+            .jordan_wigner())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
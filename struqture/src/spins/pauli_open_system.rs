@@ -12,9 +12,12 @@
 
 use super::PauliLindbladNoiseOperator;
 use crate::fermions::FermionLindbladOpenSystem;
-use crate::mappings::JordanWignerSpinToFermion;
+use crate::mappings::{BravyiKitaevSpinToFermion, JordanWignerSpinToFermion};
+#[cfg(feature = "parallel")]
+use crate::parallel_iteration::for_each_row;
 use crate::spins::{OperateOnSpins, PauliHamiltonian, ToSparseMatrixSuperOperator};
 use crate::{OpenSystem, OperateOnDensityMatrix, StruqtureError};
+use ndarray::Array2;
 use num_complex::Complex64;
 use qoqo_calculator::CalculatorFloat;
 use serde::{Deserialize, Serialize};
@@ -147,6 +150,21 @@ impl<'a> ToSparseMatrixSuperOperator<'a> for PauliLindbladOpenSystem {
         }
         Ok(system_row)
     }
+
+    // From trait
+    #[cfg(feature = "parallel")]
+    fn for_each_sparse_matrix_superoperator_row(
+        &'a self,
+        number_rows: usize,
+        number_spins: usize,
+        consume: impl FnMut(usize, HashMap<usize, Complex64>),
+    ) -> Result<(), StruqtureError> {
+        for_each_row(
+            number_rows,
+            |row| self.sparse_matrix_superoperator_entries_on_row(row, number_spins),
+            consume,
+        )
+    }
 }
 
 /// Functions for the PauliLindbladOpenSystem
@@ -164,6 +182,30 @@ impl PauliLindbladOpenSystem {
         }
     }
 
+    /// Applies the Lindblad superoperator of the PauliLindbladOpenSystem to a density matrix without constructing the superoperator.
+    ///
+    /// The result is the sum of the coherent part `-i [H, ρ]` and the noise part of the Lindblad equation.
+    /// The density matrix is given in the little endian computational basis and the number of spins
+    /// is inferred from its dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `density_matrix` - The density matrix ρ the superoperator is applied to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Array2<Complex64>)` - The time derivative of the density matrix.
+    /// * `Err(StruqtureError::GenericError)` - The density matrix is not square, its dimension is not a power of two or it is too small for the PauliLindbladOpenSystem.
+    /// * `Err(StruqtureError::CalculatorError)` - CalculatorFloat could not be converted to f64.
+    pub fn apply_to_density_matrix(
+        &self,
+        density_matrix: &Array2<Complex64>,
+    ) -> Result<Array2<Complex64>, StruqtureError> {
+        let coherent = self.system.apply_to_density_matrix(density_matrix)?;
+        let incoherent = self.noise.apply_to_density_matrix(density_matrix)?;
+        Ok(coherent + incoherent)
+    }
+
     /// Export to struqture_1 format.
     #[cfg(feature = "struqture_1_export")]
     pub fn to_struqture_1(
@@ -324,3 +366,27 @@ impl JordanWignerSpinToFermion for PauliLindbladOpenSystem {
         )
     }
 }
+
+impl BravyiKitaevSpinToFermion for PauliLindbladOpenSystem {
+    type Output = FermionLindbladOpenSystem;
+
+    /// Implements BravyiKitaevSpinToFermion for a PauliLindbladOpenSystem.
+    ///
+    /// The convention used is that |0> represents an even and |1> an odd parity of the modes
+    /// stored in a qubit.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins (and fermionic modes) of the mapping.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(FermionLindbladOpenSystem)` - The fermionic open system that results from the transformation.
+    /// * `Err(StruqtureError::GenericError)` - The PauliLindbladOpenSystem acts on more than `number_spins` spins.
+    fn bravyi_kitaev(&self, number_spins: usize) -> Result<Self::Output, StruqtureError> {
+        FermionLindbladOpenSystem::group(
+            self.system().bravyi_kitaev(number_spins)?,
+            self.noise().bravyi_kitaev(number_spins)?,
+        )
+    }
+}
@@ -10,13 +10,29 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{ToSparseMatrixOperator, ToSparseMatrixSuperOperator};
+use super::{
+    apply_terms_to_state, apply_terms_to_state_into, commuting_groups, conjugate_pauli_product,
+    BasisStateAction, CliffordGate, GraphColouring, PauliCommutation, ToSparseMatrixOperator,
+    ToSparseMatrixSuperOperator,
+};
+use crate::bosons::BosonOperator;
 use crate::fermions::FermionOperator;
-use crate::mappings::JordanWignerSpinToFermion;
+use crate::mappings::bravyi_kitaev::inverse_bravyi_kitaev_gates;
+use crate::mappings::spin_boson::SpinBosonRepresentation;
+use crate::mappings::SpinToBoson;
+use crate::mappings::{BravyiKitaevSpinToFermion, JordanWignerSpinToFermion};
+#[cfg(feature = "parallel")]
+use crate::parallel_iteration::for_each_row;
+use crate::parallel_iteration::multiply_terms;
+use crate::sparse_matrix_assembly::complex_coefficient;
 use crate::spins::{OperateOnSpins, PauliHamiltonian, PauliProduct, SpinIndex};
-use crate::{GetValue, OperateOnDensityMatrix, OperateOnState, StruqtureError, SymmetricIndex};
+use crate::{
+    GetValue, OperateOnDensityMatrix, OperateOnState, StruqtureError, SymmetricIndex,
+    SymplecticMatrices,
+};
 use indexmap::map::{Entry, Iter};
 use indexmap::IndexMap;
+use ndarray::{Array1, Array2, ArrayView1};
 use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde::{Deserialize, Serialize};
@@ -214,7 +230,22 @@ impl OperateOnSpins<'_> for PauliOperator {
     }
 }
 
-impl ToSparseMatrixOperator<'_> for PauliOperator {}
+impl ToSparseMatrixOperator<'_> for PauliOperator {
+    // From trait
+    #[cfg(feature = "parallel")]
+    fn for_each_sparse_matrix_row(
+        &self,
+        number_rows: usize,
+        consume: impl FnMut(usize, std::collections::HashMap<usize, Complex64>),
+    ) -> Result<(), StruqtureError> {
+        for_each_row(
+            number_rows,
+            |row| self.sparse_matrix_entries_on_row(row),
+            consume,
+        )
+    }
+}
+
 impl<'a> ToSparseMatrixSuperOperator<'a> for PauliOperator {
     // From trait
     fn sparse_matrix_superoperator_entries_on_row(
@@ -228,6 +259,27 @@ impl<'a> ToSparseMatrixSuperOperator<'a> for PauliOperator {
             number_spins,
         )
     }
+
+    // From trait
+    #[cfg(feature = "parallel")]
+    fn for_each_sparse_matrix_superoperator_row(
+        &'a self,
+        number_rows: usize,
+        number_spins: usize,
+        consume: impl FnMut(usize, std::collections::HashMap<usize, Complex64>),
+    ) -> Result<(), StruqtureError> {
+        for_each_row(
+            number_rows,
+            |row| {
+                <Self as ToSparseMatrixSuperOperator>::sparse_matrix_superoperator_entries_on_row(
+                    self,
+                    row,
+                    number_spins,
+                )
+            },
+            consume,
+        )
+    }
 }
 
 /// Implements the default function (Default trait) of PauliOperator (an empty PauliOperator).
@@ -290,6 +342,278 @@ impl PauliOperator {
             internal_map: IndexMap::with_capacity(capacity),
         }
     }
+
+    /// Applies the PauliOperator to a state vector without constructing its matrix.
+    ///
+    /// The state vector is given in the little endian computational basis and the number of spins
+    /// is inferred from its length.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - The state vector the PauliOperator is applied to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Complex64>)` - The state vector after applying the PauliOperator.
+    /// * `Err(StruqtureError::GenericError)` - The length of the state vector is not a power of two or too small for the PauliOperator.
+    /// * `Err(StruqtureError::CalculatorError)` - CalculatorFloat could not be converted to f64.
+    pub fn apply_to_state(&self, state: &[Complex64]) -> Result<Vec<Complex64>, StruqtureError> {
+        apply_terms_to_state(
+            self.iter()
+                .map(|(product, value)| (BasisStateAction::from(product), value.clone())),
+            self.current_number_spins(),
+            state,
+        )
+    }
+
+    /// Applies the PauliOperator to a state vector without constructing its matrix, writing the result into a given buffer.
+    ///
+    /// Unlike `apply_to_state`, no new vector is allocated. The previous content of the buffer is overwritten.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - The state vector the PauliOperator is applied to.
+    /// * `result` - The buffer the state vector after applying the PauliOperator is written into, of the same length as `state`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The result was written into the buffer.
+    /// * `Err(StruqtureError::GenericError)` - The length of the state vector is not a power of two or too small for the PauliOperator, or the buffer does not have the same length as the state vector.
+    /// * `Err(StruqtureError::CalculatorError)` - CalculatorFloat could not be converted to f64.
+    pub fn apply_to_state_into(
+        &self,
+        state: &[Complex64],
+        result: &mut [Complex64],
+    ) -> Result<(), StruqtureError> {
+        apply_terms_to_state_into(
+            self.iter()
+                .map(|(product, value)| (BasisStateAction::from(product), value.clone())),
+            self.current_number_spins(),
+            state,
+            result,
+        )
+    }
+
+    /// Returns the commutator [self, other] = self * other - other * self.
+    ///
+    /// Pairs of PauliProducts that commute do not contribute and are skipped,
+    /// all other pairs contribute twice their product.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The PauliOperator to take the commutator with.
+    ///
+    /// # Returns
+    ///
+    /// * `PauliOperator` - The commutator of the two PauliOperators.
+    pub fn commutator(&self, other: &PauliOperator) -> PauliOperator {
+        commutator_of_pauli_products(self.internal_map.iter(), other.internal_map.iter(), false)
+    }
+
+    /// Returns the anticommutator {self, other} = self * other + other * self.
+    ///
+    /// Pairs of PauliProducts that anti-commute do not contribute and are skipped,
+    /// all other pairs contribute twice their product.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The PauliOperator to take the anticommutator with.
+    ///
+    /// # Returns
+    ///
+    /// * `PauliOperator` - The anticommutator of the two PauliOperators.
+    pub fn anticommutator(&self, other: &PauliOperator) -> PauliOperator {
+        commutator_of_pauli_products(self.internal_map.iter(), other.internal_map.iter(), true)
+    }
+
+    /// Partitions the terms of the PauliOperator into groups of mutually commuting terms.
+    ///
+    /// # Arguments
+    ///
+    /// * `commutation` - Whether the terms of a group have to commute qubit-wise or as a whole.
+    /// * `colouring` - The graph colouring used for the partition.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<(PauliOperator, Option<PauliProduct>)>` - The groups and, for qubit-wise commuting groups, the measurement basis
+    ///   with the Pauli operator to measure on every qubit that a term of the group acts on.
+    pub fn commuting_groups(
+        &self,
+        commutation: PauliCommutation,
+        colouring: GraphColouring,
+    ) -> Vec<(PauliOperator, Option<PauliProduct>)> {
+        let terms: Vec<(&PauliProduct, _)> = self.iter().collect();
+        let products: Vec<&PauliProduct> = terms.iter().map(|(product, _)| *product).collect();
+        commuting_groups(&products, commutation, colouring)
+            .into_iter()
+            .map(|(group, basis)| {
+                let mut operator = PauliOperator::with_capacity(group.len());
+                for index in group {
+                    let (product, value) = terms[index];
+                    operator
+                        .set(product.clone(), value.clone())
+                        .expect("Internal error: the terms of a PauliOperator can always be set.");
+                }
+                (operator, basis)
+            })
+            .collect()
+    }
+
+    /// Exports the PauliOperator in the binary symplectic representation.
+    ///
+    /// Row k of the X and Z matrices is the binary symplectic representation of the k-th term
+    /// (see [PauliProduct::to_symplectic]) and entry k of the coefficient vector is its coefficient.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of columns of the X and Z matrices.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SymplecticMatrices)` - The X matrix, the Z matrix and the coefficient vector.
+    /// * `Err(StruqtureError::GenericError)` - The number of spins is smaller than the number of spins the PauliOperator acts on.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    pub fn to_symplectic_matrices(
+        &self,
+        number_spins: usize,
+    ) -> Result<SymplecticMatrices, StruqtureError> {
+        let mut x_matrix = Array2::from_elem((self.len(), number_spins), false);
+        let mut z_matrix = Array2::from_elem((self.len(), number_spins), false);
+        let mut coefficients = Array1::zeros(self.len());
+        for (row, (product, value)) in self.iter().enumerate() {
+            let (x, z) = product.to_symplectic(number_spins)?;
+            x_matrix.row_mut(row).assign(&ArrayView1::from(&x));
+            z_matrix.row_mut(row).assign(&ArrayView1::from(&z));
+            coefficients[row] = complex_coefficient(value.clone())?;
+        }
+        Ok((x_matrix, z_matrix, coefficients))
+    }
+
+    /// Imports a PauliOperator from the binary symplectic representation.
+    ///
+    /// Terms with the same PauliProduct are added up.
+    ///
+    /// # Arguments
+    ///
+    /// * `x_matrix` - The x bit vectors of the terms as rows.
+    /// * `z_matrix` - The z bit vectors of the terms as rows.
+    /// * `coefficients` - The coefficients of the terms.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The PauliOperator with the terms of the rows.
+    /// * `Err(StruqtureError::GenericError)` - The shapes of the matrices and the coefficient vector do not match.
+    pub fn from_symplectic_matrices(
+        x_matrix: &Array2<bool>,
+        z_matrix: &Array2<bool>,
+        coefficients: &Array1<Complex64>,
+    ) -> Result<Self, StruqtureError> {
+        if x_matrix.dim() != z_matrix.dim() || x_matrix.nrows() != coefficients.len() {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "Shapes of the X matrix {:?}, the Z matrix {:?} and the coefficient vector {} do not match.",
+                    x_matrix.dim(),
+                    z_matrix.dim(),
+                    coefficients.len()
+                ),
+            });
+        }
+        let mut operator = PauliOperator::with_capacity(coefficients.len());
+        for ((x, z), coefficient) in x_matrix
+            .rows()
+            .into_iter()
+            .zip(z_matrix.rows())
+            .zip(coefficients.iter())
+        {
+            let product = PauliProduct::from_symplectic(&x.to_vec(), &z.to_vec())?;
+            operator.add_operator_product(product, (*coefficient).into())?;
+        }
+        Ok(operator)
+    }
+
+    /// Conjugates the PauliOperator with a Clifford gate.
+    ///
+    /// # Arguments
+    ///
+    /// * `gate` - The Clifford gate C.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The conjugated PauliOperator C O C^†.
+    /// * `Err(StruqtureError::GenericError)` - A two-qubit gate acts twice on the same qubit.
+    pub fn conjugate_with_clifford(&self, gate: &CliffordGate) -> Result<Self, StruqtureError> {
+        self.conjugate_with_clifford_sequence(std::slice::from_ref(gate))
+    }
+
+    /// Conjugates the PauliOperator with a sequence of Clifford gates.
+    ///
+    /// # Arguments
+    ///
+    /// * `gates` - The Clifford gates C_0, C_1, ... in the order they are applied.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The conjugated PauliOperator ... C_1 C_0 O C_0^† C_1^† ...
+    /// * `Err(StruqtureError::GenericError)` - A two-qubit gate acts twice on the same qubit.
+    pub fn conjugate_with_clifford_sequence(
+        &self,
+        gates: &[CliffordGate],
+    ) -> Result<Self, StruqtureError> {
+        let mut new_operator = PauliOperator::with_capacity(self.len());
+        for (product, value) in self.iter() {
+            let (new_product, phase) = conjugate_pauli_product(product, gates)?;
+            new_operator.add_operator_product(new_product, value.clone() * phase)?;
+        }
+        Ok(new_operator)
+    }
+}
+
+/// Builds the (anti-)commutator of two sums of PauliProducts.
+///
+/// Two PauliProducts either commute or anti-commute, so every pair of terms contributes
+/// either zero or twice the product of the two terms.
+///
+/// # Arguments
+///
+/// * `left` - The terms of the left-hand operator.
+/// * `right` - The terms of the right-hand operator.
+/// * `anticommutator` - Whether to build the anticommutator instead of the commutator.
+///
+/// # Returns
+///
+/// * `PauliOperator` - The resulting (anti-)commutator.
+///
+/// # Panics
+///
+/// * Internal error in add_operator_product.
+pub(crate) fn commutator_of_pauli_products<'a, L, R, VL, VR>(
+    left: L,
+    right: R,
+    anticommutator: bool,
+) -> PauliOperator
+where
+    L: Iterator<Item = (&'a PauliProduct, &'a VL)>,
+    R: Iterator<Item = (&'a PauliProduct, &'a VR)> + Clone,
+    VL: Into<CalculatorComplex> + Clone + 'a,
+    VR: Into<CalculatorComplex> + Clone + 'a,
+{
+    let mut qubit_op = PauliOperator::new();
+    for (ppl, vall) in left {
+        for (ppr, valr) in right.clone() {
+            if ppl.commutes_with(ppr) != anticommutator {
+                continue;
+            }
+            let (ppp, coefficient) = PauliProduct::multiply(ppl.clone(), ppr.clone());
+            let coefficient = Into::<CalculatorComplex>::into(vall.clone())
+                * Into::<CalculatorComplex>::into(valr.clone())
+                * coefficient
+                * 2.0;
+            qubit_op
+                .add_operator_product(ppp, coefficient)
+                .expect("Internal bug in add_operator_product");
+        }
+    }
+    qubit_op
 }
 
 impl From<PauliHamiltonian> for PauliOperator {
@@ -445,16 +769,29 @@ impl ops::Mul<PauliOperator> for PauliOperator {
     /// * Internal error in add_operator_product.
     fn mul(self, other: PauliOperator) -> Self {
         let mut qubit_op = PauliOperator::with_capacity(self.len() * other.len());
-        for (pps, vals) in self {
-            for (ppo, valo) in other.iter() {
-                let (ppp, coefficient) = pps.clone() * ppo.clone();
-                let coefficient =
-                    Into::<CalculatorComplex>::into(valo) * coefficient * vals.clone();
-                qubit_op
-                    .add_operator_product(ppp, coefficient)
-                    .expect("Internal bug in add_operator_product");
-            }
-        }
+        let left: Vec<(&PauliProduct, &CalculatorComplex)> = self.iter().collect();
+        let right: Vec<(&PauliProduct, &CalculatorComplex)> = other.iter().collect();
+        multiply_terms(
+            &left,
+            &right,
+            |(pps, vals), right| {
+                let mut partial = PauliOperator::with_capacity(right.len());
+                for (ppo, valo) in right {
+                    let (ppp, coefficient) = (*pps).clone() * (*ppo).clone();
+                    let coefficient =
+                        Into::<CalculatorComplex>::into(*valo) * coefficient * (*vals).clone();
+                    partial.add_operator_product(ppp, coefficient)?;
+                }
+                Ok(partial)
+            },
+            |partial| {
+                for (ppp, coefficient) in partial {
+                    qubit_op.add_operator_product(ppp, coefficient)?;
+                }
+                Ok(())
+            },
+        )
+        .expect("Internal bug in add_operator_product");
         qubit_op
     }
 }
@@ -639,6 +976,67 @@ impl JordanWignerSpinToFermion for PauliOperator {
     }
 }
 
+impl BravyiKitaevSpinToFermion for PauliOperator {
+    type Output = FermionOperator;
+
+    /// Implements BravyiKitaevSpinToFermion for a PauliOperator.
+    ///
+    /// The convention used is that |0> represents an even and |1> an odd parity of the modes
+    /// stored in a qubit.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins (and fermionic modes) of the mapping.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(FermionOperator)` - The fermionic operator that results from the transformation.
+    /// * `Err(StruqtureError::GenericError)` - The PauliOperator acts on more than `number_spins` spins.
+    fn bravyi_kitaev(&self, number_spins: usize) -> Result<Self::Output, StruqtureError> {
+        let gates = inverse_bravyi_kitaev_gates(number_spins, self.current_number_spins())?;
+        Ok(self
+            .conjugate_with_clifford_sequence(&gates)?
+            .jordan_wigner())
+    }
+}
+
+impl SpinToBoson for PauliOperator {
+    type Output = BosonOperator;
+
+    /// Implements the Holstein-Primakoff mapping for a PauliOperator.
+    ///
+    /// The convention used is that |0> of spin i is the vacuum of the bosonic mode i.
+    ///
+    /// # Arguments
+    ///
+    /// * `spin` - The spin S, a positive multiple of 1/2.
+    /// * `order` - The highest power of the occupation in the expansion of the square root.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BosonOperator)` - The bosonic operator that results from the transformation.
+    /// * `Err(StruqtureError::GenericError)` - The spin is not a positive multiple of 1/2.
+    fn holstein_primakoff_mapping(
+        &self,
+        spin: f64,
+        order: usize,
+    ) -> Result<Self::Output, StruqtureError> {
+        SpinBosonRepresentation::holstein_primakoff(spin, order)?.map_pauli_operator(self)
+    }
+
+    /// Implements the Schwinger-boson mapping for a PauliOperator.
+    ///
+    /// The convention used is that spin i is represented by the bosonic modes 2i and 2i + 1.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BosonOperator)` - The bosonic operator that results from the transformation.
+    /// * `Err(StruqtureError)` - Error adding an operator product.
+    fn schwinger_boson_mapping(&self) -> Result<Self::Output, StruqtureError> {
+        SpinBosonRepresentation::SchwingerBoson.map_pauli_operator(self)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
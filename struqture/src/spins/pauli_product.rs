@@ -10,10 +10,14 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::bosons::BosonOperator;
 use crate::fermions::FermionOperator;
-use crate::mappings::JordanWignerSpinToFermion;
+use crate::mappings::bravyi_kitaev::inverse_bravyi_kitaev_gates;
+use crate::mappings::spin_boson::SpinBosonRepresentation;
+use crate::mappings::SpinToBoson;
+use crate::mappings::{BravyiKitaevSpinToFermion, JordanWignerSpinToFermion};
 use crate::prelude::*;
-use crate::spins::{PauliOperator, PlusMinusOperator};
+use crate::spins::{conjugate_pauli_product, CliffordGate, PauliOperator, PlusMinusOperator};
 use crate::{CorrespondsTo, GetValue, SpinIndex, StruqtureError, SymmetricIndex};
 use num_complex::Complex64;
 use qoqo_calculator::CalculatorComplex;
@@ -650,6 +654,187 @@ impl PauliProduct {
             items: TinyVec::<[(usize, SinglePauliOperator); 5]>::with_capacity(cap),
         }
     }
+
+    /// Returns whether the PauliProduct commutes with another PauliProduct.
+    ///
+    /// Two PauliProducts either commute or anti-commute. They commute when the number of qubits
+    /// on which both act with different non-identity Pauli matrices is even.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The PauliProduct to check commutation with.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the two PauliProducts commute.
+    pub fn commutes_with(&self, other: &PauliProduct) -> bool {
+        let mut number_anticommuting = 0;
+        let mut other_iter = other.iter().peekable();
+        for (index, left_operator) in self.iter() {
+            while let Some((other_index, _)) = other_iter.peek() {
+                if other_index < index {
+                    other_iter.next();
+                } else {
+                    break;
+                }
+            }
+            if let Some((other_index, right_operator)) = other_iter.peek() {
+                if other_index == index
+                    && left_operator != right_operator
+                    && *left_operator != SinglePauliOperator::Identity
+                    && *right_operator != SinglePauliOperator::Identity
+                {
+                    number_anticommuting += 1;
+                }
+            }
+        }
+        number_anticommuting % 2 == 0
+    }
+
+    /// Returns whether two PauliProducts commute qubit-wise.
+    ///
+    /// Two PauliProducts commute qubit-wise when they act with the same Pauli matrix on every qubit
+    /// on which both act with a non-identity Pauli matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The PauliProduct to check qubit-wise commutation with.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the two PauliProducts commute qubit-wise.
+    pub fn qubit_wise_commutes_with(&self, other: &PauliProduct) -> bool {
+        self.iter().all(|(index, pauli)| match other.get(index) {
+            Some(other_pauli) => {
+                pauli == other_pauli
+                    || *pauli == SinglePauliOperator::Identity
+                    || *other_pauli == SinglePauliOperator::Identity
+            }
+            None => true,
+        })
+    }
+
+    /// Returns the binary symplectic representation (x, z) of the PauliProduct.
+    ///
+    /// The Pauli matrix on qubit i is determined by (x_i, z_i): (0, 0) is the identity, (1, 0) is X,
+    /// (1, 1) is Y and (0, 1) is Z.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The length of the bit vectors.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Vec<bool>, Vec<bool>))` - The x and z bit vectors.
+    /// * `Err(StruqtureError::GenericError)` - The number of spins is smaller than the number of spins the PauliProduct acts on.
+    pub fn to_symplectic(
+        &self,
+        number_spins: usize,
+    ) -> Result<(Vec<bool>, Vec<bool>), StruqtureError> {
+        if number_spins < self.current_number_spins() {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "Cannot represent a PauliProduct acting on {} spins with {number_spins} spins.",
+                    self.current_number_spins()
+                ),
+            });
+        }
+        let mut x = vec![false; number_spins];
+        let mut z = vec![false; number_spins];
+        for (index, pauli) in self.iter() {
+            match pauli {
+                SinglePauliOperator::Identity => (),
+                SinglePauliOperator::X => x[*index] = true,
+                SinglePauliOperator::Y => {
+                    x[*index] = true;
+                    z[*index] = true;
+                }
+                SinglePauliOperator::Z => z[*index] = true,
+            }
+        }
+        Ok((x, z))
+    }
+
+    /// Creates a PauliProduct from its binary symplectic representation (x, z).
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The x bit vector.
+    /// * `z` - The z bit vector.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The PauliProduct with the Pauli matrix determined by (x_i, z_i) on every qubit i.
+    /// * `Err(StruqtureError::GenericError)` - The bit vectors have different lengths.
+    pub fn from_symplectic(x: &[bool], z: &[bool]) -> Result<Self, StruqtureError> {
+        if x.len() != z.len() {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "Lengths of the x bit vector {} and the z bit vector {} do not match.",
+                    x.len(),
+                    z.len()
+                ),
+            });
+        }
+        let mut product = PauliProduct::new();
+        for (index, (x_bit, z_bit)) in x.iter().zip(z.iter()).enumerate() {
+            let pauli = match (x_bit, z_bit) {
+                (false, false) => continue,
+                (true, false) => SinglePauliOperator::X,
+                (true, true) => SinglePauliOperator::Y,
+                (false, true) => SinglePauliOperator::Z,
+            };
+            product = product.set_pauli(index, pauli);
+        }
+        Ok(product)
+    }
+
+    /// Conjugates the PauliProduct with a Clifford gate.
+    ///
+    /// # Arguments
+    ///
+    /// * `gate` - The Clifford gate C.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((PauliProduct, Complex64))` - The PauliProduct and the phase of C P C^†.
+    /// * `Err(StruqtureError::GenericError)` - A two-qubit gate acts twice on the same qubit.
+    pub fn conjugate_with_clifford(
+        &self,
+        gate: &CliffordGate,
+    ) -> Result<(PauliProduct, Complex64), StruqtureError> {
+        conjugate_pauli_product(self, std::slice::from_ref(gate))
+    }
+
+    /// Conjugates the PauliProduct with a sequence of Clifford gates.
+    ///
+    /// # Arguments
+    ///
+    /// * `gates` - The Clifford gates C_0, C_1, ... in the order they are applied.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((PauliProduct, Complex64))` - The PauliProduct and the phase of ... C_1 C_0 P C_0^† C_1^† ...
+    /// * `Err(StruqtureError::GenericError)` - A two-qubit gate acts twice on the same qubit.
+    pub fn conjugate_with_clifford_sequence(
+        &self,
+        gates: &[CliffordGate],
+    ) -> Result<(PauliProduct, Complex64), StruqtureError> {
+        conjugate_pauli_product(self, gates)
+    }
+
+    /// Returns whether the PauliProduct commutes with the Z-parity operator Z_0 Z_1 ... Z_n.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the PauliProduct contains an even number of X and Y operators.
+    pub(crate) fn conserves_z_parity(&self) -> bool {
+        self.iter()
+            .filter(|(_, pauli)| matches!(pauli, SinglePauliOperator::X | SinglePauliOperator::Y))
+            .count()
+            % 2
+            == 0
+    }
 }
 
 /// Implements the default function (Default trait) of PauliProduct (an empty PauliProduct).
@@ -838,3 +1023,63 @@ impl JordanWignerSpinToFermion for PauliProduct {
         plus_minus_operator.jordan_wigner()
     }
 }
+
+impl BravyiKitaevSpinToFermion for PauliProduct {
+    type Output = FermionOperator;
+
+    /// Implements BravyiKitaevSpinToFermion for a PauliProduct.
+    ///
+    /// The convention used is that |0> represents an even and |1> an odd parity of the modes
+    /// stored in a qubit.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins (and fermionic modes) of the mapping.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(FermionOperator)` - The fermionic operator that results from the transformation.
+    /// * `Err(StruqtureError::GenericError)` - The PauliProduct acts on more than `number_spins` spins.
+    fn bravyi_kitaev(&self, number_spins: usize) -> Result<Self::Output, StruqtureError> {
+        let gates = inverse_bravyi_kitaev_gates(number_spins, self.current_number_spins())?;
+        let (product, phase) = self.conjugate_with_clifford_sequence(&gates)?;
+        Ok(product.jordan_wigner() * phase)
+    }
+}
+
+impl SpinToBoson for PauliProduct {
+    type Output = BosonOperator;
+
+    /// Implements the Holstein-Primakoff mapping for a PauliProduct.
+    ///
+    /// The convention used is that |0> of spin i is the vacuum of the bosonic mode i.
+    ///
+    /// # Arguments
+    ///
+    /// * `spin` - The spin S, a positive multiple of 1/2.
+    /// * `order` - The highest power of the occupation in the expansion of the square root.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BosonOperator)` - The bosonic operator that results from the transformation.
+    /// * `Err(StruqtureError::GenericError)` - The spin is not a positive multiple of 1/2.
+    fn holstein_primakoff_mapping(
+        &self,
+        spin: f64,
+        order: usize,
+    ) -> Result<Self::Output, StruqtureError> {
+        SpinBosonRepresentation::holstein_primakoff(spin, order)?.map_pauli_product(self)
+    }
+
+    /// Implements the Schwinger-boson mapping for a PauliProduct.
+    ///
+    /// The convention used is that spin i is represented by the bosonic modes 2i and 2i + 1.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BosonOperator)` - The bosonic operator that results from the transformation.
+    /// * `Err(StruqtureError)` - Error adding an operator product.
+    fn schwinger_boson_mapping(&self) -> Result<Self::Output, StruqtureError> {
+        SpinBosonRepresentation::SchwingerBoson.map_pauli_product(self)
+    }
+}
@@ -10,9 +10,15 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{DecoherenceOperator, DecoherenceProduct, PauliOperator, PauliProduct};
+use super::{
+    apply_terms_to_state, apply_terms_to_state_into, BasisStateAction, DecoherenceOperator,
+    DecoherenceProduct, PauliOperator, PauliProduct,
+};
+use crate::bosons::BosonOperator;
 use crate::fermions::FermionOperator;
+use crate::mappings::spin_boson::SpinBosonRepresentation;
 use crate::mappings::JordanWignerSpinToFermion;
+use crate::mappings::SpinToBoson;
 use crate::spins::{PauliHamiltonian, PlusMinusProduct};
 use crate::{OperateOnDensityMatrix, OperateOnState, StruqtureError, SymmetricIndex};
 use num_complex::Complex64;
@@ -231,6 +237,57 @@ impl PlusMinusOperator {
         }
     }
 
+    /// Applies the PlusMinusOperator to a state vector without constructing its matrix.
+    ///
+    /// The state vector is given in the little endian computational basis and the number of spins
+    /// is inferred from its length.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - The state vector the PlusMinusOperator is applied to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Complex64>)` - The state vector after applying the PlusMinusOperator.
+    /// * `Err(StruqtureError::GenericError)` - The length of the state vector is not a power of two or too small for the PlusMinusOperator.
+    /// * `Err(StruqtureError::CalculatorError)` - CalculatorFloat could not be converted to f64.
+    pub fn apply_to_state(&self, state: &[Complex64]) -> Result<Vec<Complex64>, StruqtureError> {
+        apply_terms_to_state(
+            self.iter()
+                .map(|(product, value)| (BasisStateAction::from(product), value.clone())),
+            self.current_number_spins(),
+            state,
+        )
+    }
+
+    /// Applies the PlusMinusOperator to a state vector without constructing its matrix, writing the result into a given buffer.
+    ///
+    /// Unlike `apply_to_state`, no new vector is allocated. The previous content of the buffer is overwritten.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - The state vector the PlusMinusOperator is applied to.
+    /// * `result` - The buffer the state vector after applying the PlusMinusOperator is written into, of the same length as `state`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The result was written into the buffer.
+    /// * `Err(StruqtureError::GenericError)` - The length of the state vector is not a power of two or too small for the PlusMinusOperator, or the buffer does not have the same length as the state vector.
+    /// * `Err(StruqtureError::CalculatorError)` - CalculatorFloat could not be converted to f64.
+    pub fn apply_to_state_into(
+        &self,
+        state: &[Complex64],
+        result: &mut [Complex64],
+    ) -> Result<(), StruqtureError> {
+        apply_terms_to_state_into(
+            self.iter()
+                .map(|(product, value)| (BasisStateAction::from(product), value.clone())),
+            self.current_number_spins(),
+            state,
+            result,
+        )
+    }
+
     /// Gets the maximum index of the PlusMinusOperator.
     ///
     /// # Returns
@@ -645,6 +702,43 @@ impl JordanWignerSpinToFermion for PlusMinusOperator {
     }
 }
 
+impl SpinToBoson for PlusMinusOperator {
+    type Output = BosonOperator;
+
+    /// Implements the Holstein-Primakoff mapping for a PlusMinusOperator.
+    ///
+    /// The convention used is that |0> of spin i is the vacuum of the bosonic mode i.
+    ///
+    /// # Arguments
+    ///
+    /// * `spin` - The spin S, a positive multiple of 1/2.
+    /// * `order` - The highest power of the occupation in the expansion of the square root.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BosonOperator)` - The bosonic operator that results from the transformation.
+    /// * `Err(StruqtureError::GenericError)` - The spin is not a positive multiple of 1/2.
+    fn holstein_primakoff_mapping(
+        &self,
+        spin: f64,
+        order: usize,
+    ) -> Result<Self::Output, StruqtureError> {
+        SpinBosonRepresentation::holstein_primakoff(spin, order)?.map_plus_minus_operator(self)
+    }
+
+    /// Implements the Schwinger-boson mapping for a PlusMinusOperator.
+    ///
+    /// The convention used is that spin i is represented by the bosonic modes 2i and 2i + 1.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BosonOperator)` - The bosonic operator that results from the transformation.
+    /// * `Err(StruqtureError)` - Error adding an operator product.
+    fn schwinger_boson_mapping(&self) -> Result<Self::Output, StruqtureError> {
+        SpinBosonRepresentation::SchwingerBoson.map_plus_minus_operator(self)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -10,8 +10,11 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::bosons::BosonOperator;
 use crate::fermions::{FermionOperator, FermionProduct};
+use crate::mappings::spin_boson::SpinBosonRepresentation;
 use crate::mappings::JordanWignerSpinToFermion;
+use crate::mappings::SpinToBoson;
 use crate::prelude::*;
 use crate::{StruqtureError, SymmetricIndex};
 use num_complex::Complex64;
@@ -830,6 +833,24 @@ impl PlusMinusProduct {
             items: TinyVec::<[(usize, SinglePlusMinusOperator); 5]>::with_capacity(cap),
         }
     }
+
+    /// Returns whether the PlusMinusProduct commutes with the total Sz of the spins.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the PlusMinusProduct contains as many raising as lowering operators.
+    pub(crate) fn conserves_total_sz(&self) -> bool {
+        let number_plus = self
+            .iter()
+            .filter(|(_, pauli)| *pauli == SinglePlusMinusOperator::Plus)
+            .count();
+        let number_minus = self
+            .iter()
+            .filter(|(_, pauli)| *pauli == SinglePlusMinusOperator::Minus)
+            .count();
+        number_plus == number_minus
+    }
+
     /// Export to struqture_1 format.
     #[cfg(feature = "struqture_1_export")]
     pub fn to_struqture_1(&self) -> Result<struqture_1::spins::PlusMinusProduct, StruqtureError> {
@@ -1139,6 +1160,43 @@ impl JordanWignerSpinToFermion for PlusMinusProduct {
     }
 }
 
+impl SpinToBoson for PlusMinusProduct {
+    type Output = BosonOperator;
+
+    /// Implements the Holstein-Primakoff mapping for a PlusMinusProduct.
+    ///
+    /// The convention used is that |0> of spin i is the vacuum of the bosonic mode i.
+    ///
+    /// # Arguments
+    ///
+    /// * `spin` - The spin S, a positive multiple of 1/2.
+    /// * `order` - The highest power of the occupation in the expansion of the square root.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BosonOperator)` - The bosonic operator that results from the transformation.
+    /// * `Err(StruqtureError::GenericError)` - The spin is not a positive multiple of 1/2.
+    fn holstein_primakoff_mapping(
+        &self,
+        spin: f64,
+        order: usize,
+    ) -> Result<Self::Output, StruqtureError> {
+        SpinBosonRepresentation::holstein_primakoff(spin, order)?.map_plus_minus_product(self)
+    }
+
+    /// Implements the Schwinger-boson mapping for a PlusMinusProduct.
+    ///
+    /// The convention used is that spin i is represented by the bosonic modes 2i and 2i + 1.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BosonOperator)` - The bosonic operator that results from the transformation.
+    /// * `Err(StruqtureError)` - Error adding an operator product.
+    fn schwinger_boson_mapping(&self) -> Result<Self::Output, StruqtureError> {
+        SpinBosonRepresentation::SchwingerBoson.map_plus_minus_product(self)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -0,0 +1,418 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{OperateOnSpins, PauliHamiltonian, PauliOperator, PauliProduct, SinglePauliOperator};
+use crate::{OperateOnDensityMatrix, SpinIndex, StruqtureError};
+use std::f64::consts::FRAC_1_SQRT_2;
+
+/// The Z2 symmetries of a PauliHamiltonian and the Clifford rotations used to taper them off.
+///
+/// The symmetries are independent, mutually commuting PauliProducts that commute with every term of the
+/// PauliHamiltonian they were found for. The Clifford U = V_{m-1} ... V_1 V_0 maps every symmetry onto a
+/// single-qubit Z (up to a sign) on one of the tapered qubits, where every V_i = (A_i + B_i) / √2 is a
+/// rotation built from two anticommuting PauliProducts A_i and B_i. The rotations are hermitian and unitary,
+/// so U^† = V_0 V_1 ... V_{m-1}.
+///
+/// After the transformation U H U^†, the Hamiltonian acts on the tapered qubits only with Z and identity operators.
+/// In a sector with fixed eigenvalues of the symmetries, these Z operators are replaced by ±1 and the tapered
+/// qubits are removed.
+///
+/// # Example
+/// ```
+/// use struqture::prelude::*;
+/// use struqture::spins::{PauliHamiltonian, PauliProduct, Z2Symmetries};
+///
+/// let mut hamiltonian = PauliHamiltonian::new();
+/// hamiltonian.add_operator_product(PauliProduct::new().z(0).z(1), 1.0.into()).unwrap();
+/// hamiltonian.add_operator_product(PauliProduct::new().x(0), 0.5.into()).unwrap();
+///
+/// let symmetries = Z2Symmetries::find(&hamiltonian);
+/// assert_eq!(symmetries.symmetries(), &[PauliProduct::new().x(0).x(1)]);
+///
+/// let tapered = symmetries.taper(&hamiltonian, &[1]).unwrap();
+/// assert_eq!(tapered.current_number_spins(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Z2Symmetries {
+    /// The independent, mutually commuting symmetries.
+    symmetries: Vec<PauliProduct>,
+    /// The anticommuting pairs (A, B) of the rotations (A + B) / √2 in the order they are applied.
+    rotations: Vec<(PauliProduct, PauliProduct)>,
+    /// The tapered qubits.
+    tapered_qubits: Vec<usize>,
+    /// The sign s and the symmetries S with U (Π_{a ∈ S} τ_a) U^† = s Z_q for every tapered qubit q.
+    tapered_values: Vec<(f64, Vec<usize>)>,
+}
+
+impl Z2Symmetries {
+    /// Finds the Z2 symmetries of a PauliHamiltonian and the Clifford rotations to taper them off.
+    ///
+    /// The symmetries are the PauliProducts acting on the spins of the PauliHamiltonian that commute with every term.
+    /// They are found as the kernel of the binary symplectic matrix of the terms, from which a largest set of
+    /// independent, mutually commuting generators is chosen.
+    ///
+    /// # Arguments
+    ///
+    /// * `hamiltonian` - The PauliHamiltonian to find the symmetries of.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The symmetries and the Clifford rotations.
+    pub fn find(hamiltonian: &PauliHamiltonian) -> Self {
+        let number_spins = hamiltonian.current_number_spins();
+        // A PauliProduct (x, z) commutes with a term (x_k, z_k) when x_k · z + z_k · x = 0,
+        // so the symmetries are the kernel of the matrix with the rows (z_k, x_k)
+        let rows: Vec<Vec<bool>> = hamiltonian
+            .keys()
+            .map(|product| {
+                let vector = symplectic_vector(product, number_spins);
+                let (x, z) = vector.split_at(number_spins);
+                [z, x].concat()
+            })
+            .collect();
+        let symmetries: Vec<PauliProduct> =
+            commuting_generators(gf2_kernel(rows, 2 * number_spins), number_spins)
+                .iter()
+                .map(|vector| product_from_symplectic_vector(vector, number_spins))
+                .collect();
+
+        // The current form U W_i U^† = sign * product of every generator W_i = Π_{a ∈ S_i} τ_a
+        let mut generators: Vec<(PauliProduct, f64, Vec<bool>)> = symmetries
+            .iter()
+            .enumerate()
+            .map(|(index, symmetry)| {
+                let mut included = vec![false; symmetries.len()];
+                included[index] = true;
+                (symmetry.clone(), 1.0, included)
+            })
+            .collect();
+        let mut rotations: Vec<(PauliProduct, PauliProduct)> = Vec::new();
+        let mut tapered_qubits: Vec<usize> = Vec::new();
+        for index in 0..generators.len() {
+            // Removes the Z operators on the qubits already tapered by multiplying with their generators
+            for (previous, qubit) in tapered_qubits.iter().enumerate() {
+                if generators[index].0.get(qubit).is_some() {
+                    let (product, phase) =
+                        generators[index].0.clone() * generators[previous].0.clone();
+                    generators[index] = (
+                        product,
+                        generators[index].1 * generators[previous].1 * phase.re,
+                        generators[index]
+                            .2
+                            .iter()
+                            .zip(generators[previous].2.iter())
+                            .map(|(left, right)| left ^ right)
+                            .collect(),
+                    );
+                }
+            }
+            let product = generators[index].0.clone();
+            let new_rotations = match product
+                .iter()
+                .find(|(_, pauli)| *pauli != SinglePauliOperator::Z)
+                .map(|(qubit, _)| *qubit)
+            {
+                Some(qubit) => {
+                    tapered_qubits.push(qubit);
+                    vec![(product, PauliProduct::new().z(qubit))]
+                }
+                None => {
+                    let (qubit, _) = *product
+                        .iter()
+                        .next()
+                        .expect("Internal error: the symmetries are independent.");
+                    tapered_qubits.push(qubit);
+                    if product.len() == 1 {
+                        vec![]
+                    } else {
+                        vec![
+                            (product, PauliProduct::new().x(qubit)),
+                            (PauliProduct::new().x(qubit), PauliProduct::new().z(qubit)),
+                        ]
+                    }
+                }
+            };
+            for rotation in new_rotations {
+                for generator in generators.iter_mut() {
+                    let (product, sign) = conjugate_with_rotation(&generator.0, &rotation);
+                    generator.0 = product;
+                    generator.1 *= sign;
+                }
+                rotations.push(rotation);
+            }
+        }
+        let tapered_values = generators
+            .into_iter()
+            .map(|(_, sign, included)| {
+                (
+                    sign,
+                    included
+                        .iter()
+                        .enumerate()
+                        .filter_map(|(index, included)| included.then_some(index))
+                        .collect(),
+                )
+            })
+            .collect();
+
+        Z2Symmetries {
+            symmetries,
+            rotations,
+            tapered_qubits,
+            tapered_values,
+        }
+    }
+
+    /// Returns the independent, mutually commuting symmetries.
+    ///
+    /// # Returns
+    ///
+    /// * `&[PauliProduct]` - The symmetries in the order of the eigenvalues of [Z2Symmetries::taper].
+    pub fn symmetries(&self) -> &[PauliProduct] {
+        &self.symmetries
+    }
+
+    /// Returns the qubits that are tapered off.
+    ///
+    /// # Returns
+    ///
+    /// * `&[usize]` - The qubit onto which the Clifford maps each symmetry (or a product of the symmetries).
+    pub fn tapered_qubits(&self) -> &[usize] {
+        &self.tapered_qubits
+    }
+
+    /// Returns the Clifford rotations in the order they are applied.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<PauliOperator>` - The rotations V_i = (A_i + B_i) / √2, the Clifford is U = V_{m-1} ... V_1 V_0.
+    pub fn cliffords(&self) -> Vec<PauliOperator> {
+        self.rotations
+            .iter()
+            .map(|(left, right)| {
+                let mut rotation = PauliOperator::with_capacity(2);
+                rotation
+                    .add_operator_product(left.clone(), FRAC_1_SQRT_2.into())
+                    .expect("Internal error: a PauliProduct can always be added.");
+                rotation
+                    .add_operator_product(right.clone(), FRAC_1_SQRT_2.into())
+                    .expect("Internal error: a PauliProduct can always be added.");
+                rotation
+            })
+            .collect()
+    }
+
+    /// Tapers the qubits off a PauliHamiltonian in a sector with fixed eigenvalues of the symmetries.
+    ///
+    /// The PauliHamiltonian is transformed with the Clifford, the Z operators on the tapered qubits are replaced
+    /// by the eigenvalues and the remaining qubits are relabelled consecutively, keeping their order.
+    ///
+    /// # Arguments
+    ///
+    /// * `hamiltonian` - The PauliHamiltonian to taper, it has to commute with all symmetries.
+    /// * `eigenvalues` - The eigenvalue (1 or -1) of each symmetry in the sector.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PauliHamiltonian)` - The PauliHamiltonian without the tapered qubits.
+    /// * `Err(StruqtureError::GenericError)` - The number of eigenvalues does not match the number of symmetries, an eigenvalue is not 1 or -1 or a term does not commute with a symmetry.
+    pub fn taper(
+        &self,
+        hamiltonian: &PauliHamiltonian,
+        eigenvalues: &[i8],
+    ) -> Result<PauliHamiltonian, StruqtureError> {
+        if eigenvalues.len() != self.symmetries.len() {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "Number of eigenvalues {} does not match the number of symmetries {}.",
+                    eigenvalues.len(),
+                    self.symmetries.len()
+                ),
+            });
+        }
+        if let Some(eigenvalue) = eigenvalues.iter().find(|value| value.abs() != 1) {
+            return Err(StruqtureError::GenericError {
+                msg: format!("Eigenvalue {eigenvalue} of a Z2 symmetry is not 1 or -1."),
+            });
+        }
+        let tapered_values: Vec<f64> = self
+            .tapered_values
+            .iter()
+            .map(|(sign, included)| {
+                included
+                    .iter()
+                    .fold(*sign, |value, index| value * f64::from(eigenvalues[*index]))
+            })
+            .collect();
+
+        let mut tapered = PauliHamiltonian::with_capacity(hamiltonian.len());
+        for (product, value) in hamiltonian.iter() {
+            if let Some(symmetry) = self
+                .symmetries
+                .iter()
+                .find(|symmetry| !symmetry.commutes_with(product))
+            {
+                return Err(StruqtureError::GenericError {
+                    msg: format!("Term {product} does not commute with the symmetry {symmetry}."),
+                });
+            }
+            let mut product = product.clone();
+            let mut factor = 1.0;
+            for rotation in self.rotations.iter() {
+                let (new_product, sign) = conjugate_with_rotation(&product, rotation);
+                product = new_product;
+                factor *= sign;
+            }
+            for (qubit, tapered_value) in self.tapered_qubits.iter().zip(tapered_values.iter()) {
+                if product.get(qubit).is_some() {
+                    factor *= tapered_value;
+                }
+            }
+            let mut new_product = PauliProduct::with_capacity(product.len());
+            for (qubit, pauli) in product.iter() {
+                if !self.tapered_qubits.contains(qubit) {
+                    let shift = self
+                        .tapered_qubits
+                        .iter()
+                        .filter(|tapered_qubit| *tapered_qubit < qubit)
+                        .count();
+                    new_product = new_product.set_pauli(qubit - shift, *pauli);
+                }
+            }
+            tapered.add_operator_product(new_product, value.clone() * factor)?;
+        }
+        Ok(tapered)
+    }
+}
+
+/// Returns the binary symplectic vector (x, z) of a PauliProduct.
+fn symplectic_vector(product: &PauliProduct, number_spins: usize) -> Vec<bool> {
+    let (mut x, z) = product
+        .to_symplectic(number_spins)
+        .expect("Internal error: the Hamiltonian acts on at most number_spins spins.");
+    x.extend(z);
+    x
+}
+
+/// Returns the PauliProduct of a binary symplectic vector (x, z).
+fn product_from_symplectic_vector(vector: &[bool], number_spins: usize) -> PauliProduct {
+    let (x, z) = vector.split_at(number_spins);
+    PauliProduct::from_symplectic(x, z)
+        .expect("Internal error: the x and z parts of a symplectic vector have the same length.")
+}
+
+/// Returns whether the PauliProducts of two binary symplectic vectors anticommute.
+fn symplectic_inner_product(left: &[bool], right: &[bool], number_spins: usize) -> bool {
+    (0..number_spins).fold(false, |anticommute, qubit| {
+        anticommute
+            ^ (left[qubit] & right[number_spins + qubit])
+            ^ (left[number_spins + qubit] & right[qubit])
+    })
+}
+
+/// Returns a basis of the kernel of a binary matrix.
+///
+/// # Arguments
+///
+/// * `rows` - The rows of the matrix.
+/// * `number_columns` - The number of columns of the matrix.
+///
+/// # Returns
+///
+/// * `Vec<Vec<bool>>` - One kernel vector for every column without a pivot in the reduced row echelon form.
+fn gf2_kernel(mut rows: Vec<Vec<bool>>, number_columns: usize) -> Vec<Vec<bool>> {
+    let mut pivot_columns: Vec<usize> = Vec::new();
+    for column in 0..number_columns {
+        let rank = pivot_columns.len();
+        if let Some(pivot) = (rank..rows.len()).find(|row| rows[*row][column]) {
+            rows.swap(rank, pivot);
+            let pivot_row = rows[rank].clone();
+            for (index, row) in rows.iter_mut().enumerate() {
+                if index != rank && row[column] {
+                    row.iter_mut()
+                        .zip(pivot_row.iter())
+                        .for_each(|(entry, pivot_entry)| *entry ^= pivot_entry);
+                }
+            }
+            pivot_columns.push(column);
+        }
+    }
+    (0..number_columns)
+        .filter(|column| !pivot_columns.contains(column))
+        .map(|free_column| {
+            let mut vector = vec![false; number_columns];
+            vector[free_column] = true;
+            for (row, pivot_column) in pivot_columns.iter().enumerate() {
+                vector[*pivot_column] = rows[row][free_column];
+            }
+            vector
+        })
+        .collect()
+}
+
+/// Chooses a largest set of mutually commuting generators from a basis of binary symplectic vectors.
+///
+/// Pairs of anticommuting vectors are found with a symplectic Gram-Schmidt procedure,
+/// only the first vector of every pair is kept.
+fn commuting_generators(mut basis: Vec<Vec<bool>>, number_spins: usize) -> Vec<Vec<bool>> {
+    let mut generators: Vec<Vec<bool>> = Vec::new();
+    basis.reverse();
+    while let Some(vector) = basis.pop() {
+        if let Some(partner_index) = basis
+            .iter()
+            .position(|other| symplectic_inner_product(&vector, other, number_spins))
+        {
+            let partner = basis.remove(partner_index);
+            for other in basis.iter_mut() {
+                if symplectic_inner_product(other, &partner, number_spins) {
+                    other
+                        .iter_mut()
+                        .zip(vector.iter())
+                        .for_each(|(entry, added)| *entry ^= added);
+                }
+                if symplectic_inner_product(other, &vector, number_spins) {
+                    other
+                        .iter_mut()
+                        .zip(partner.iter())
+                        .for_each(|(entry, added)| *entry ^= added);
+                }
+            }
+        }
+        generators.push(vector);
+    }
+    generators
+}
+
+/// Conjugates a PauliProduct P with the Clifford rotation V = (A + B) / √2 of two anticommuting PauliProducts.
+///
+/// V P V^† is P when P commutes with A and B, -P when it anticommutes with both, P A B when it only
+/// anticommutes with B and P B A when it only anticommutes with A.
+///
+/// # Returns
+///
+/// * `(PauliProduct, f64)` - The conjugated PauliProduct and its sign.
+fn conjugate_with_rotation(
+    product: &PauliProduct,
+    rotation: &(PauliProduct, PauliProduct),
+) -> (PauliProduct, f64) {
+    let (left, right) = rotation;
+    let (first, second) = match (product.commutes_with(left), product.commutes_with(right)) {
+        (true, true) => return (product.clone(), 1.0),
+        (false, false) => return (product.clone(), -1.0),
+        (true, false) => (left, right),
+        (false, true) => (right, left),
+    };
+    let (intermediate, first_phase) = product.clone() * first.clone();
+    let (conjugated, second_phase) = intermediate * second.clone();
+    // The conjugate of a hermitian PauliProduct is hermitian, so the phase is real
+    (conjugated, (first_phase * second_phase).re)
+}
@@ -0,0 +1,99 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Sums of possibly symbolic coefficients that can be checked for cancellation.
+//!
+//! Multiplying a symbolic CalculatorFloat with a number creates a new expression, so that the
+//! arithmetic of qoqo_calculator does not cancel `J - J`. A SymbolicSum instead collects the
+//! numerical prefactors of every distinct expression.
+
+use num_complex::Complex64;
+use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
+use std::collections::HashMap;
+
+/// The tolerance relative to the magnitude of the added prefactors below which the prefactor of an
+/// expression is considered to be zero, allowing for the rounding errors of a few additions.
+pub(crate) const RELATIVE_CANCELLATION_TOLERANCE: f64 = 16.0 * f64::EPSILON;
+
+/// A linear combination of symbolic expressions and a number with complex prefactors.
+///
+/// Symbolic expressions only cancel if they are written identically, `J` and `1 * J` are treated as
+/// different expressions.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SymbolicSum {
+    /// The prefactor of every expression and the sum of the magnitudes of the added prefactors,
+    /// None is the key of the numerical part.
+    prefactors: HashMap<Option<String>, (Complex64, f64)>,
+}
+
+impl SymbolicSum {
+    /// Adds a real coefficient multiplied with a prefactor to the sum.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The numerical or symbolic coefficient.
+    /// * `prefactor` - The numerical prefactor of the coefficient.
+    pub(crate) fn add_float(&mut self, value: &CalculatorFloat, prefactor: Complex64) {
+        let (expression, prefactor) = match value {
+            CalculatorFloat::Float(x) => (None, prefactor * x),
+            CalculatorFloat::Str(expression) => (Some(expression.clone()), prefactor),
+        };
+        let (sum, magnitude) = self
+            .prefactors
+            .entry(expression)
+            .or_insert((Complex64::new(0.0, 0.0), 0.0));
+        *sum += prefactor;
+        *magnitude += prefactor.norm();
+    }
+
+    /// Adds a complex coefficient multiplied with a prefactor to the sum.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The numerical or symbolic coefficient.
+    /// * `prefactor` - The numerical prefactor of the coefficient.
+    pub(crate) fn add_complex(&mut self, value: &CalculatorComplex, prefactor: Complex64) {
+        self.add_float(&value.re, prefactor);
+        self.add_float(&value.im, prefactor * Complex64::i());
+    }
+
+    /// Adds the complex conjugate of a coefficient multiplied with a prefactor to the sum.
+    ///
+    /// The expressions of the coefficient are assumed to be real.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The numerical or symbolic coefficient.
+    /// * `prefactor` - The numerical prefactor of the complex conjugated coefficient.
+    pub(crate) fn add_complex_conjugate(
+        &mut self,
+        value: &CalculatorComplex,
+        prefactor: Complex64,
+    ) {
+        self.add_float(&value.re, prefactor);
+        self.add_float(&value.im, -prefactor * Complex64::i());
+    }
+
+    /// Returns whether the prefactors of all expressions and of the numerical part vanish.
+    ///
+    /// A prefactor vanishes when it is small compared to the prefactors that were added up to it,
+    /// so that small but non-zero coefficients do not cancel.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the sum cancels structurally.
+    pub(crate) fn cancels(&self) -> bool {
+        self.prefactors
+            .values()
+            .all(|(sum, magnitude)| sum.norm() <= RELATIVE_CANCELLATION_TOLERANCE * magnitude)
+    }
+}
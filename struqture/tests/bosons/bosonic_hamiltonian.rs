@@ -12,6 +12,7 @@
 
 //! Integration test for public API of BosonHamiltonian
 
+use super::{assert_sparse_matrices_close, dense_lindblad_superoperator, dense_matrix};
 use bincode::config;
 use bincode::serde::{decode_from_slice, encode_to_vec};
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
@@ -21,7 +22,10 @@ use std::iter::{FromIterator, IntoIterator};
 #[cfg(feature = "struqture_1_import")]
 #[cfg(feature = "struqture_1_export")]
 use std::str::FromStr;
-use struqture::bosons::{BosonHamiltonian, BosonOperator, BosonProduct, HermitianBosonProduct};
+use struqture::bosons::{
+    BosonHamiltonian, BosonOperator, BosonProduct, HermitianBosonProduct,
+    ToBosonSparseMatrixOperator, ToBosonSparseMatrixSuperOperator,
+};
 use struqture::{
     ModeIndex, OperateOnDensityMatrix, OperateOnModes, StruqtureError, STRUQTURE_VERSION,
 };
@@ -300,6 +304,33 @@ fn from_operator_fail() {
     );
 }
 
+// Test the conversion of a BosonHamiltonian with a complex coefficient into a BosonOperator
+#[test]
+fn into_operator_complex_coefficient() {
+    let mut hamiltonian = BosonHamiltonian::new();
+    hamiltonian
+        .add_operator_product(
+            HermitianBosonProduct::new([0], [1]).unwrap(),
+            CalculatorComplex::new(1.0, 0.5),
+        )
+        .unwrap();
+    let mut operator = BosonOperator::new();
+    operator
+        .add_operator_product(
+            BosonProduct::new([0], [1]).unwrap(),
+            CalculatorComplex::new(1.0, 0.5),
+        )
+        .unwrap();
+    operator
+        .add_operator_product(
+            BosonProduct::new([1], [0]).unwrap(),
+            CalculatorComplex::new(1.0, -0.5),
+        )
+        .unwrap();
+
+    assert_eq!(BosonOperator::from(hamiltonian), operator);
+}
+
 // Test the Iter traits of BosonHamiltonian: into_iter, from_iter and extend
 #[test]
 fn into_iter_from_iter_extend() {
@@ -509,6 +540,109 @@ fn serde_compact() {
     );
 }
 
+// Test the commutator and anticommutator functions of the BosonHamiltonian
+#[test]
+fn commutator_anticommutator() {
+    let mut left = BosonHamiltonian::new();
+    left.set(
+        HermitianBosonProduct::new([0], [1]).unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    left.set(
+        HermitianBosonProduct::new([2], [2]).unwrap(),
+        CalculatorComplex::from(0.3),
+    )
+    .unwrap();
+    let mut right = BosonHamiltonian::new();
+    right
+        .set(
+            HermitianBosonProduct::new([1], [2]).unwrap(),
+            CalculatorComplex::from(1.5),
+        )
+        .unwrap();
+    right
+        .set(
+            HermitianBosonProduct::new([0, 1], [0, 1]).unwrap(),
+            CalculatorComplex::from(0.7),
+        )
+        .unwrap();
+
+    let left_right = left.clone() * right.clone();
+    let right_left = right.clone() * left.clone();
+    assert_eq!(
+        left.commutator(&right),
+        left_right.clone() - right_left.clone()
+    );
+    assert_eq!(left.anticommutator(&right), left_right + right_left);
+}
+
+// Test the sparse_matrix and sparse_matrix_superoperator functions of the BosonHamiltonian
+#[test]
+fn sparse_matrix() {
+    let mut hamiltonian = BosonHamiltonian::new();
+    hamiltonian
+        .set(
+            HermitianBosonProduct::new([0], [1]).unwrap(),
+            CalculatorComplex::new(0.3, 0.4),
+        )
+        .unwrap();
+    hamiltonian
+        .set(
+            HermitianBosonProduct::new([1], [1]).unwrap(),
+            CalculatorComplex::from(0.7),
+        )
+        .unwrap();
+    hamiltonian
+        .set(
+            HermitianBosonProduct::new([], [0, 0]).unwrap(),
+            CalculatorComplex::from(-0.2),
+        )
+        .unwrap();
+
+    let matrix = hamiltonian.sparse_matrix(2, vec![2, 1]).unwrap();
+    assert_eq!(
+        matrix,
+        BosonOperator::from(hamiltonian.clone())
+            .sparse_matrix(2, vec![2, 1])
+            .unwrap()
+    );
+    for ((row, column), value) in matrix.iter() {
+        let transposed = matrix.get(&(*column, *row)).copied().unwrap_or_default();
+        assert!((value - transposed.conj()).norm() < 1e-12);
+    }
+
+    let hamiltonian_matrix = dense_matrix(&matrix, 6);
+    assert_sparse_matrices_close(
+        &hamiltonian
+            .sparse_matrix_superoperator(2, vec![2, 1])
+            .unwrap(),
+        &dense_lindblad_superoperator(&hamiltonian_matrix, &[]),
+    );
+    assert!(hamiltonian.sparse_matrix(1, 2).is_err());
+    assert!(hamiltonian.sparse_matrix_superoperator(2, vec![2]).is_err());
+}
+
+// Test the conserves_particle_number and conserves_parity functions of the BosonHamiltonian
+#[test_case(vec![], true, true; "empty")]
+#[test_case(vec![(vec![0], vec![1]), (vec![0, 0], vec![0, 0])], true, true; "bose_hubbard")]
+#[test_case(vec![(vec![], vec![0, 0])], false, true; "squeezing")]
+#[test_case(vec![(vec![], vec![0])], false, false; "drive")]
+#[test_case(vec![(vec![0, 1], vec![1])], false, false; "cubic")]
+fn conserved_quantities(terms: Vec<(Vec<usize>, Vec<usize>)>, particle_number: bool, parity: bool) {
+    let mut hamiltonian = BosonHamiltonian::new();
+    for (creators, annihilators) in terms {
+        hamiltonian
+            .add_operator_product(
+                HermitianBosonProduct::new(creators, annihilators).unwrap(),
+                1.0.into(),
+            )
+            .unwrap();
+    }
+    assert_eq!(hamiltonian.conserves_particle_number(), particle_number);
+    assert_eq!(hamiltonian.conserves_parity(), parity);
+}
+
 #[cfg(feature = "json_schema")]
 #[test]
 fn test_boson_hamiltonian_schema() {
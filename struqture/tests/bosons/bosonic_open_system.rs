@@ -12,6 +12,9 @@
 
 //! Integration test for public API of BosonLindbladOpenSystem
 
+use super::{assert_sparse_matrices_close, dense_lindblad_superoperator, dense_matrix};
+use nalgebra::DMatrix;
+use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde_test::{assert_tokens, Configure, Token};
 use std::collections::BTreeMap;
@@ -19,8 +22,8 @@ use std::collections::BTreeMap;
 #[cfg(feature = "struqture_1_export")]
 use std::str::FromStr;
 use struqture::bosons::{
-    BosonHamiltonian, BosonLindbladNoiseOperator, BosonLindbladOpenSystem, BosonProduct,
-    HermitianBosonProduct,
+    BosonHamiltonian, BosonLindbladNoiseOperator, BosonLindbladOpenSystem, BosonOperator,
+    BosonProduct, HermitianBosonProduct,
 };
 use struqture::ModeIndex;
 use struqture::{prelude::*, STRUQTURE_VERSION};
@@ -803,6 +806,105 @@ fn test_truncate() {
     assert_eq!(test_system2, comparison_system2);
 }
 
+// Test the sparse_matrix_superoperator function of the BosonLindbladOpenSystem
+#[test]
+fn sparse_matrix_superoperator() {
+    let mut system = BosonLindbladOpenSystem::new();
+    system
+        .system_mut()
+        .add_operator_product(
+            HermitianBosonProduct::new([0], [1]).unwrap(),
+            CalculatorComplex::from(0.6),
+        )
+        .unwrap();
+    system
+        .system_mut()
+        .add_operator_product(
+            HermitianBosonProduct::new([1, 1], [1, 1]).unwrap(),
+            CalculatorComplex::from(-0.4),
+        )
+        .unwrap();
+    let decay = BosonProduct::new([], [0]).unwrap();
+    let hopping = BosonProduct::new([1], [0]).unwrap();
+    system
+        .noise_mut()
+        .add_operator_product((decay.clone(), decay.clone()), CalculatorComplex::from(0.5))
+        .unwrap();
+    system
+        .noise_mut()
+        .add_operator_product(
+            (hopping.clone(), decay.clone()),
+            CalculatorComplex::new(0.3, 0.1),
+        )
+        .unwrap();
+
+    let cutoffs = vec![2, 1];
+    let product_matrix = |product: &BosonProduct| {
+        let mut operator = BosonOperator::new();
+        operator
+            .set(product.clone(), CalculatorComplex::from(1.0))
+            .unwrap();
+        dense_matrix(&operator.sparse_matrix(2, cutoffs.clone()).unwrap(), 6)
+    };
+    let hamiltonian_matrix = dense_matrix(
+        &system.system().sparse_matrix(2, cutoffs.clone()).unwrap(),
+        6,
+    );
+    let expected = dense_lindblad_superoperator(
+        &hamiltonian_matrix,
+        &[
+            (
+                product_matrix(&decay),
+                product_matrix(&decay),
+                Complex64::new(0.5, 0.0),
+            ),
+            (
+                product_matrix(&hopping),
+                product_matrix(&decay),
+                Complex64::new(0.3, 0.1),
+            ),
+        ],
+    );
+    assert_sparse_matrices_close(
+        &system
+            .sparse_matrix_superoperator(2, cutoffs.clone())
+            .unwrap(),
+        &expected,
+    );
+
+    let noise_expected = dense_lindblad_superoperator(
+        &DMatrix::<Complex64>::zeros(6, 6),
+        &[
+            (
+                product_matrix(&decay),
+                product_matrix(&decay),
+                Complex64::new(0.5, 0.0),
+            ),
+            (
+                product_matrix(&hopping),
+                product_matrix(&decay),
+                Complex64::new(0.3, 0.1),
+            ),
+        ],
+    );
+    assert_sparse_matrices_close(
+        &system
+            .noise()
+            .sparse_matrix_superoperator(2, cutoffs.clone())
+            .unwrap(),
+        &noise_expected,
+    );
+    assert_eq!(
+        system
+            .sparse_matrix_superoperator_coo(2, 1)
+            .unwrap()
+            .0
+            .len(),
+        system.sparse_matrix_superoperator(2, 1).unwrap().len()
+    );
+    assert!(system.sparse_matrix_superoperator(1, 2).is_err());
+}
+
 #[cfg(feature = "json_schema")]
 #[test]
 fn test_boson_open_system_schema() {
@@ -12,14 +12,19 @@
 
 //! Integration test for public API of BosonOperator
 
+use super::{assert_sparse_matrices_close, dense_lindblad_superoperator, dense_matrix};
+use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde_test::{assert_tokens, Configure, Token};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 use std::iter::{FromIterator, IntoIterator};
 #[cfg(feature = "struqture_1_import")]
 #[cfg(feature = "struqture_1_export")]
 use std::str::FromStr;
-use struqture::bosons::{BosonHamiltonian, BosonOperator, BosonProduct, HermitianBosonProduct};
+use struqture::bosons::{
+    BosonCutoff, BosonHamiltonian, BosonOperator, BosonProduct, HermitianBosonProduct,
+    ToBosonSparseMatrixOperator, ToBosonSparseMatrixSuperOperator,
+};
 use struqture::{
     ModeIndex, OperateOnDensityMatrix, OperateOnModes, OperateOnState, STRUQTURE_VERSION,
 };
@@ -487,6 +492,160 @@ fn serde_compact() {
     );
 }
 
+// Test the commutator and anticommutator functions of the BosonOperator
+#[test]
+fn commutator_anticommutator() {
+    let mut left = BosonOperator::new();
+    left.set(
+        BosonProduct::new([0], [1]).unwrap(),
+        CalculatorComplex::new(1.0, 0.5),
+    )
+    .unwrap();
+    left.set(
+        BosonProduct::new([2], []).unwrap(),
+        CalculatorComplex::from(0.3),
+    )
+    .unwrap();
+    left.set(
+        BosonProduct::new([0, 3], [0, 3]).unwrap(),
+        CalculatorComplex::from(2.0),
+    )
+    .unwrap();
+    let mut right = BosonOperator::new();
+    right
+        .set(
+            BosonProduct::new([1], [0]).unwrap(),
+            CalculatorComplex::from(1.5),
+        )
+        .unwrap();
+    right
+        .set(
+            BosonProduct::new([], [2]).unwrap(),
+            CalculatorComplex::new(0.0, 1.0),
+        )
+        .unwrap();
+    right
+        .set(
+            BosonProduct::new([4], []).unwrap(),
+            CalculatorComplex::from(0.7),
+        )
+        .unwrap();
+
+    let commutator = left.clone() * right.clone() - right.clone() * left.clone();
+    let anticommutator = left.clone() * right.clone() + right.clone() * left.clone();
+    assert_eq!(left.commutator(&right), commutator);
+    assert_eq!(left.anticommutator(&right), anticommutator);
+
+    // [b_0, b_0^dagger] = 1
+    let mut annihilator = BosonOperator::new();
+    annihilator
+        .set(
+            BosonProduct::new([], [0]).unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    let mut creator = BosonOperator::new();
+    creator
+        .set(
+            BosonProduct::new([0], []).unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    let mut identity = BosonOperator::new();
+    identity
+        .set(
+            BosonProduct::new([], []).unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    assert_eq!(annihilator.commutator(&creator), identity);
+}
+
+// Test the sparse_matrix and sparse_matrix_superoperator functions of the BosonOperator
+#[test]
+fn sparse_matrix() {
+    let sqrt_2 = 2.0_f64.sqrt();
+    let mut annihilator = BosonOperator::new();
+    annihilator
+        .set(
+            BosonProduct::new([], [0]).unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    let matrix = annihilator.sparse_matrix(1, 2).unwrap();
+    assert_eq!(matrix.len(), 2);
+    assert_eq!(matrix.get(&(0, 1)), Some(&Complex64::new(1.0, 0.0)));
+    assert_eq!(matrix.get(&(1, 2)), Some(&Complex64::new(sqrt_2, 0.0)));
+
+    let mut number = BosonOperator::new();
+    number
+        .set(
+            BosonProduct::new([0], [0]).unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    let mut expected: HashMap<(usize, usize), Complex64> = HashMap::new();
+    expected.insert((1, 1), Complex64::new(1.0, 0.0));
+    expected.insert((2, 2), Complex64::new(2.0, 0.0));
+    assert_eq!(number.sparse_matrix(1, 2).unwrap(), expected);
+
+    // Mode 0 holds at most one boson and mode 1 at most two, the index is n_0 + 2 n_1
+    let mut hopping = BosonOperator::new();
+    hopping
+        .set(
+            BosonProduct::new([0], [1]).unwrap(),
+            CalculatorComplex::new(0.5, 0.5),
+        )
+        .unwrap();
+    let mut expected: HashMap<(usize, usize), Complex64> = HashMap::new();
+    expected.insert((1, 2), Complex64::new(0.5, 0.5));
+    expected.insert((3, 4), Complex64::new(0.5, 0.5) * sqrt_2);
+    assert_eq!(hopping.sparse_matrix(2, vec![1, 2]).unwrap(), expected);
+    assert_eq!(
+        hopping
+            .sparse_matrix(2, BosonCutoff::PerMode(vec![1, 2]))
+            .unwrap(),
+        expected
+    );
+    let coo = hopping.sparse_matrix_coo(2, vec![1, 2]).unwrap();
+    assert_eq!(
+        coo,
+        (
+            vec![Complex64::new(0.5, 0.5), Complex64::new(0.5, 0.5) * sqrt_2],
+            (vec![1, 3], vec![2, 4])
+        )
+    );
+    assert_eq!(hopping.sparse_matrix(2, 1).unwrap().len(), 1);
+    assert_eq!(
+        hopping.sparse_matrix(3, 1).unwrap(),
+        hopping.sparse_matrix(3, vec![1, 1, 1]).unwrap()
+    );
+
+    let hopping_matrix = dense_matrix(&hopping.sparse_matrix(2, vec![1, 2]).unwrap(), 6);
+    assert_sparse_matrices_close(
+        &hopping.sparse_matrix_superoperator(2, vec![1, 2]).unwrap(),
+        &dense_lindblad_superoperator(&hopping_matrix, &[]),
+    );
+    assert_eq!(
+        hopping
+            .sparse_matrix_superoperator_coo(2, vec![1, 2])
+            .unwrap()
+            .0
+            .len(),
+        hopping
+            .sparse_matrix_superoperator(2, vec![1, 2])
+            .unwrap()
+            .len()
+    );
+
+    assert!(hopping.sparse_matrix(1, 2).is_err());
+    assert!(hopping.sparse_matrix(2, vec![2]).is_err());
+    assert!(hopping
+        .sparse_matrix_superoperator(2, vec![1, 2, 3])
+        .is_err());
+    assert!(hopping.sparse_matrix(2, usize::MAX).is_err());
+}
+
 #[cfg(feature = "json_schema")]
 #[test]
 fn test_boson_operator_schema() {
@@ -16,3 +16,70 @@ mod bosonic_open_system;
 mod bosonic_operator;
 mod bosonic_product;
 mod hermitian_bosonic_product;
+
+use nalgebra::DMatrix;
+use num_complex::Complex64;
+use std::collections::HashMap;
+
+/// Asserts that two sparse matrices agree up to numerical precision, treating missing entries as zero.
+fn assert_sparse_matrices_close(
+    left: &HashMap<(usize, usize), Complex64>,
+    right: &HashMap<(usize, usize), Complex64>,
+) {
+    for (key, value) in left.iter() {
+        let other = right.get(key).copied().unwrap_or_default();
+        assert!(
+            (value - other).norm() < 1e-12,
+            "{key:?}: {value} != {other}"
+        );
+    }
+    for (key, value) in right.iter() {
+        let other = left.get(key).copied().unwrap_or_default();
+        assert!(
+            (value - other).norm() < 1e-12,
+            "{key:?}: {other} != {value}"
+        );
+    }
+}
+
+/// Converts a sparse matrix into a dense nalgebra matrix.
+fn dense_matrix(
+    matrix: &HashMap<(usize, usize), Complex64>,
+    dimension: usize,
+) -> DMatrix<Complex64> {
+    let mut dense = DMatrix::<Complex64>::zeros(dimension, dimension);
+    for ((row, column), value) in matrix.iter() {
+        dense[(*row, *column)] += value;
+    }
+    dense
+}
+
+/// Constructs the Lindblad superoperator acting on the row-major flattened density matrix from dense matrices.
+///
+/// The noise is given as (L, R, γ) contributing `γ (L p R^† - 1/2 {R^† L, p})`.
+fn dense_lindblad_superoperator(
+    hamiltonian: &DMatrix<Complex64>,
+    noise: &[(DMatrix<Complex64>, DMatrix<Complex64>, Complex64)],
+) -> HashMap<(usize, usize), Complex64> {
+    let dimension = hamiltonian.nrows();
+    let identity = DMatrix::<Complex64>::identity(dimension, dimension);
+    let mut superoperator = (hamiltonian.kronecker(&identity)
+        - identity.kronecker(&hamiltonian.transpose()))
+        * Complex64::new(0.0, -1.0);
+    for (left, right, rate) in noise.iter() {
+        let product = right.adjoint() * left;
+        superoperator += (left.kronecker(&right.conjugate())
+            - (product.kronecker(&identity) + identity.kronecker(&product.transpose()))
+                * Complex64::new(0.5, 0.0))
+            * *rate;
+    }
+    let mut matrix = HashMap::new();
+    for row in 0..superoperator.nrows() {
+        for column in 0..superoperator.ncols() {
+            if superoperator[(row, column)].norm() > 1e-14 {
+                matrix.insert((row, column), superoperator[(row, column)]);
+            }
+        }
+    }
+    matrix
+}
@@ -12,6 +12,7 @@
 
 //! Integration test for public API of FermionHamiltonian
 
+use super::assert_sparse_matrices_close;
 use bincode::config;
 use bincode::serde::{decode_from_slice, encode_to_vec};
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
@@ -22,8 +23,11 @@ use std::iter::{FromIterator, IntoIterator};
 #[cfg(feature = "struqture_1_export")]
 use std::str::FromStr;
 use struqture::fermions::{
-    FermionHamiltonian, FermionOperator, FermionProduct, HermitianFermionProduct,
+    fermionic_fock_basis, FermionHamiltonian, FermionOperator, FermionProduct,
+    HermitianFermionProduct, ToFermionSparseMatrixOperator, ToFermionSparseMatrixSuperOperator,
 };
+use struqture::mappings::JordanWignerFermionToSpin;
+use struqture::spins::{ToSparseMatrixOperator, ToSparseMatrixSuperOperator};
 use struqture::{
     ModeIndex, OperateOnDensityMatrix, OperateOnModes, OperateOnState, StruqtureError,
     STRUQTURE_VERSION,
@@ -330,6 +334,33 @@ fn from_operator_fail() {
     );
 }
 
+// Test the conversion of a FermionHamiltonian with a complex coefficient into a FermionOperator
+#[test]
+fn into_operator_complex_coefficient() {
+    let mut hamiltonian = FermionHamiltonian::new();
+    hamiltonian
+        .add_operator_product(
+            HermitianFermionProduct::new([0], [1]).unwrap(),
+            CalculatorComplex::new(1.0, 0.5),
+        )
+        .unwrap();
+    let mut operator = FermionOperator::new();
+    operator
+        .add_operator_product(
+            FermionProduct::new([0], [1]).unwrap(),
+            CalculatorComplex::new(1.0, 0.5),
+        )
+        .unwrap();
+    operator
+        .add_operator_product(
+            FermionProduct::new([1], [0]).unwrap(),
+            CalculatorComplex::new(1.0, -0.5),
+        )
+        .unwrap();
+
+    assert_eq!(FermionOperator::from(hamiltonian), operator);
+}
+
 // Test the Iter traits of FermionHamiltonian: into_iter, from_iter and extend
 #[test]
 fn into_iter_from_iter_extend() {
@@ -539,6 +570,167 @@ fn serde_compact() {
     );
 }
 
+// Test the commutator and anticommutator functions of the FermionHamiltonian
+#[test]
+fn commutator_anticommutator() {
+    let mut left = FermionHamiltonian::new();
+    left.set(
+        HermitianFermionProduct::new([0], [1]).unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    left.set(
+        HermitianFermionProduct::new([2], [2]).unwrap(),
+        CalculatorComplex::from(0.3),
+    )
+    .unwrap();
+    let mut right = FermionHamiltonian::new();
+    right
+        .set(
+            HermitianFermionProduct::new([1], [2]).unwrap(),
+            CalculatorComplex::from(1.5),
+        )
+        .unwrap();
+    right
+        .set(
+            HermitianFermionProduct::new([0, 1], [0, 1]).unwrap(),
+            CalculatorComplex::from(0.7),
+        )
+        .unwrap();
+
+    let left_right = (left.clone() * right.clone()).unwrap();
+    let right_left = (right.clone() * left.clone()).unwrap();
+    assert_eq!(
+        left.commutator(&right),
+        left_right.clone() - right_left.clone()
+    );
+    assert_eq!(left.anticommutator(&right), left_right + right_left);
+}
+
+// Test the sparse_matrix and sparse_matrix_superoperator functions of the FermionHamiltonian
+#[test]
+fn sparse_matrix_superoperator() {
+    let mut fh = FermionHamiltonian::new();
+    fh.add_operator_product(
+        HermitianFermionProduct::new([0], [2]).unwrap(),
+        CalculatorComplex::from(0.5),
+    )
+    .unwrap();
+    fh.add_operator_product(
+        HermitianFermionProduct::new([1], [1]).unwrap(),
+        CalculatorComplex::from(-0.3),
+    )
+    .unwrap();
+    fh.add_operator_product(
+        HermitianFermionProduct::new([0, 1], [1, 3]).unwrap(),
+        CalculatorComplex::from(0.8),
+    )
+    .unwrap();
+
+    let spin_hamiltonian = fh.jordan_wigner();
+    assert_sparse_matrices_close(
+        &fh.sparse_matrix(4, None).unwrap(),
+        &spin_hamiltonian.sparse_matrix(4).unwrap(),
+    );
+    let full = fh.sparse_matrix_superoperator(4, None).unwrap();
+    assert_sparse_matrices_close(
+        &full,
+        &spin_hamiltonian.sparse_matrix_superoperator(4).unwrap(),
+    );
+
+    // The superoperator in a particle-number sector is a block of the full superoperator
+    let basis = fermionic_fock_basis(4, Some(2)).unwrap();
+    let dimension = basis.len();
+    let sector = fh.sparse_matrix_superoperator(4, Some(2)).unwrap();
+    let mut block = std::collections::HashMap::new();
+    for (row, (row_left, row_right)) in basis
+        .iter()
+        .flat_map(|left| basis.iter().map(move |right| (*left, *right)))
+        .enumerate()
+    {
+        for (column, (column_left, column_right)) in basis
+            .iter()
+            .flat_map(|left| basis.iter().map(move |right| (*left, *right)))
+            .enumerate()
+        {
+            if let Some(value) =
+                full.get(&(row_left * 16 + row_right, column_left * 16 + column_right))
+            {
+                block.insert((row, column), *value);
+            }
+        }
+    }
+    assert!(sector
+        .keys()
+        .all(|(row, column)| *row < dimension.pow(2) && *column < dimension.pow(2)));
+    assert_sparse_matrices_close(&sector, &block);
+}
+
+// Test the particle_hole_transformation function of the FermionHamiltonian
+#[test]
+fn particle_hole_transformation() {
+    let mut fh = FermionHamiltonian::new();
+    fh.add_operator_product(HermitianFermionProduct::new([0], [0]).unwrap(), 1.0.into())
+        .unwrap();
+    fh.add_operator_product(
+        HermitianFermionProduct::new([0], [1]).unwrap(),
+        CalculatorComplex::new(0.5, 0.5),
+    )
+    .unwrap();
+
+    // n0 -> 1 - n0 and c0 a1 + h.c. -> a0 a1 + h.c.
+    let mut expected = FermionHamiltonian::new();
+    expected
+        .add_operator_product(HermitianFermionProduct::new([], []).unwrap(), 1.0.into())
+        .unwrap();
+    expected
+        .add_operator_product(
+            HermitianFermionProduct::new([0], [0]).unwrap(),
+            (-1.0).into(),
+        )
+        .unwrap();
+    expected
+        .add_operator_product(
+            HermitianFermionProduct::new([], [0, 1]).unwrap(),
+            CalculatorComplex::new(0.5, 0.5),
+        )
+        .unwrap();
+    let transformed = fh.particle_hole_transformation(&[0]).unwrap();
+    assert_eq!(transformed, expected);
+    assert_eq!(
+        FermionOperator::from(transformed),
+        FermionOperator::from(fh).particle_hole_transformation(&[0])
+    );
+}
+
+// Test the conserves_particle_number, conserves_parity and conserves_fermionic_sz functions of the FermionHamiltonian
+#[test_case(vec![], true, true, true; "empty")]
+#[test_case(vec![(vec![0], vec![2]), (vec![0, 1], vec![0, 1])], true, true, true; "hubbard")]
+#[test_case(vec![(vec![0], vec![1])], true, true, false; "spin_flip")]
+#[test_case(vec![(vec![], vec![0, 1])], false, true, true; "singlet_pairing")]
+#[test_case(vec![(vec![], vec![0, 2])], false, true, false; "triplet_pairing")]
+#[test_case(vec![(vec![], vec![0])], false, false, false; "annihilator")]
+#[test_case(vec![(vec![0, 3], vec![1, 2])], true, true, true; "spin_exchange")]
+fn conserved_quantities(
+    terms: Vec<(Vec<usize>, Vec<usize>)>,
+    particle_number: bool,
+    parity: bool,
+    fermionic_sz: bool,
+) {
+    let mut hamiltonian = FermionHamiltonian::new();
+    for (creators, annihilators) in terms {
+        hamiltonian
+            .add_operator_product(
+                HermitianFermionProduct::new(creators, annihilators).unwrap(),
+                1.0.into(),
+            )
+            .unwrap();
+    }
+    assert_eq!(hamiltonian.conserves_particle_number(), particle_number);
+    assert_eq!(hamiltonian.conserves_parity(), parity);
+    assert_eq!(hamiltonian.conserves_fermionic_sz(), fermionic_sz);
+}
+
 #[cfg(feature = "json_schema")]
 #[test]
 fn test_fermion_hamiltonian_schema() {
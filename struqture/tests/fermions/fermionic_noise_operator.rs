@@ -16,13 +16,15 @@ use bincode::config;
 use bincode::serde::{decode_from_slice, encode_to_vec};
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde_test::{assert_tokens, Configure, Token};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::iter::{FromIterator, IntoIterator};
 #[cfg(feature = "struqture_1_import")]
 #[cfg(feature = "struqture_1_export")]
 use std::str::FromStr;
 use struqture::fermions::{FermionLindbladNoiseOperator, FermionOperator, FermionProduct};
-use struqture::{ModeIndex, OperateOnDensityMatrix, OperateOnModes, STRUQTURE_VERSION};
+use struqture::{
+    ModeIndex, OperateOnDensityMatrix, OperateOnModes, StruqtureError, STRUQTURE_VERSION,
+};
 
 // Test the new function of the FermionLindbladNoiseOperator
 #[test]
@@ -137,6 +139,27 @@ fn internal_map_set_get_remove() {
     assert_eq!(so, FermionLindbladNoiseOperator::new());
 }
 
+// Test the free_parameters function of the FermionLindbladNoiseOperator
+#[test]
+fn free_parameters() {
+    let fp: FermionProduct = FermionProduct::new([0], [1]).unwrap();
+    let mut so = FermionLindbladNoiseOperator::new();
+    so.set(
+        (fp.clone(), fp.clone()),
+        CalculatorComplex::new("gamma", 0.0),
+    )
+    .unwrap();
+    so.set(
+        (fp.clone(), FermionProduct::new([1], [1]).unwrap()),
+        CalculatorComplex::new(1.0, "delta ** 2"),
+    )
+    .unwrap();
+    assert_eq!(
+        so.free_parameters(),
+        BTreeSet::from(["delta".to_string(), "gamma".to_string()])
+    );
+}
+
 // Test the add_operator_product function of the FermionLindbladNoiseOperator
 #[test]
 fn internal_map_add_operator_product() {
@@ -554,6 +577,53 @@ fn serde_compact() {
     );
 }
 
+// Test the particle_hole_transformation function of the FermionLindbladNoiseOperator
+#[test]
+fn particle_hole_transformation() {
+    let mut noise = FermionLindbladNoiseOperator::new();
+    let annihilator = FermionProduct::new([], [0]).unwrap();
+    let number = FermionProduct::new([0], [0]).unwrap();
+    noise
+        .add_operator_product((annihilator.clone(), annihilator), 2.0.into())
+        .unwrap();
+    noise
+        .add_operator_product((number.clone(), number.clone()), 0.5.into())
+        .unwrap();
+
+    // a0 -> c0 and n0 -> 1 - n0, where the terms with an identity on one side are dropped
+    let creator = FermionProduct::new([0], []).unwrap();
+    let mut expected = FermionLindbladNoiseOperator::new();
+    expected
+        .add_operator_product((creator.clone(), creator), 2.0.into())
+        .unwrap();
+    expected
+        .add_operator_product((number.clone(), number), 0.5.into())
+        .unwrap();
+    assert_eq!(noise.particle_hole_transformation(&[0]).unwrap(), expected);
+}
+
+// Test that the particle_hole_transformation function fails if the dropped terms have a coherent part
+#[test]
+fn particle_hole_transformation_coherent_part() {
+    let annihilator = FermionProduct::new([], [0]).unwrap();
+    let number = FermionProduct::new([0], [0]).unwrap();
+    let mut noise = FermionLindbladNoiseOperator::new();
+    noise
+        .add_operator_product((number.clone(), annihilator.clone()), 1.0.into())
+        .unwrap();
+    noise
+        .add_operator_product((annihilator, number), 1.0.into())
+        .unwrap();
+
+    // n0 -> 1 - n0 and a0 -> c0, the terms (1, c0) and (c0, 1) act as the Hamiltonian i (c0 - a0) / 2
+    assert_eq!(
+        noise.particle_hole_transformation(&[0]),
+        Err(StruqtureError::GenericError {
+            msg: "The noise terms with an identity on one side act as a Hamiltonian on a0, which cannot be represented in the noise operator.".to_string()
+        })
+    );
+}
+
 #[cfg(feature = "json_schema")]
 #[test]
 fn test_fermion_noise_operator_schema() {
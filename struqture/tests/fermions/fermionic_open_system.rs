@@ -12,6 +12,7 @@
 
 //! Integration test for public API of FermionLindbladOpenSystem
 
+use super::assert_sparse_matrices_close;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde_test::{assert_tokens, Configure, Token};
 use std::collections::BTreeMap;
@@ -22,6 +23,7 @@ use struqture::fermions::{
     FermionHamiltonian, FermionLindbladNoiseOperator, FermionLindbladOpenSystem, FermionProduct,
     HermitianFermionProduct,
 };
+use struqture::mappings::JordanWignerFermionToSpin;
 use struqture::ModeIndex;
 use struqture::{prelude::*, STRUQTURE_VERSION};
 
@@ -811,6 +813,114 @@ fn test_truncate() {
     assert_eq!(test_system2, comparison_system2);
 }
 
+// Test the sparse_matrix_superoperator function of the FermionLindbladOpenSystem
+#[test]
+fn sparse_matrix_superoperator() {
+    let mut system = FermionLindbladOpenSystem::new();
+    system
+        .system_mut()
+        .add_operator_product(
+            HermitianFermionProduct::new([0], [1]).unwrap(),
+            CalculatorComplex::from(0.6),
+        )
+        .unwrap();
+    system
+        .system_mut()
+        .add_operator_product(
+            HermitianFermionProduct::new([1, 2], [1, 2]).unwrap(),
+            CalculatorComplex::from(-0.4),
+        )
+        .unwrap();
+    system
+        .noise_mut()
+        .add_operator_product(
+            (
+                FermionProduct::new([0], [1]).unwrap(),
+                FermionProduct::new([2], [1]).unwrap(),
+            ),
+            CalculatorComplex::new(0.3, 0.1),
+        )
+        .unwrap();
+    system
+        .noise_mut()
+        .add_operator_product(
+            (
+                FermionProduct::new([1], [2]).unwrap(),
+                FermionProduct::new([1], [2]).unwrap(),
+            ),
+            CalculatorComplex::from(0.2),
+        )
+        .unwrap();
+
+    // Lindblad operators without identity parts after the Jordan-Wigner transformation
+    let spin_system = system.jordan_wigner();
+    assert_sparse_matrices_close(
+        &system.sparse_matrix_superoperator(3, None).unwrap(),
+        &spin_system.sparse_matrix_superoperator(3).unwrap(),
+    );
+    assert!(system.sparse_matrix_superoperator(3, Some(1)).is_ok());
+    assert_eq!(
+        system
+            .sparse_matrix_superoperator_coo(3, Some(1))
+            .unwrap()
+            .0
+            .len(),
+        system
+            .sparse_matrix_superoperator(3, Some(1))
+            .unwrap()
+            .len()
+    );
+
+    // Particle-number changing noise can only be represented in the full Fock space
+    system
+        .noise_mut()
+        .add_operator_product(
+            (
+                FermionProduct::new([], [0]).unwrap(),
+                FermionProduct::new([], [0]).unwrap(),
+            ),
+            CalculatorComplex::from(0.5),
+        )
+        .unwrap();
+    let spin_system = system.jordan_wigner();
+    assert_sparse_matrices_close(
+        &system.sparse_matrix_superoperator(3, None).unwrap(),
+        &spin_system.sparse_matrix_superoperator(3).unwrap(),
+    );
+    assert!(system.sparse_matrix_superoperator(3, Some(1)).is_err());
+}
+
+// Test the particle_hole_transformation function of the FermionLindbladOpenSystem
+#[test]
+fn particle_hole_transformation() {
+    let mut system = FermionHamiltonian::new();
+    system
+        .add_operator_product(HermitianFermionProduct::new([0], [1]).unwrap(), 0.4.into())
+        .unwrap();
+    system
+        .add_operator_product(HermitianFermionProduct::new([1], [1]).unwrap(), 1.0.into())
+        .unwrap();
+    let mut noise = FermionLindbladNoiseOperator::new();
+    noise
+        .add_operator_product(
+            (
+                FermionProduct::new([], [1]).unwrap(),
+                FermionProduct::new([], [1]).unwrap(),
+            ),
+            0.5.into(),
+        )
+        .unwrap();
+    let open_system = FermionLindbladOpenSystem::group(system.clone(), noise.clone()).unwrap();
+    assert_eq!(
+        open_system.particle_hole_transformation(&[1]).unwrap(),
+        FermionLindbladOpenSystem::group(
+            system.particle_hole_transformation(&[1]).unwrap(),
+            noise.particle_hole_transformation(&[1]).unwrap()
+        )
+        .unwrap()
+    );
+}
+
 #[cfg(feature = "json_schema")]
 #[test]
 fn test_fermion_noise_system_schema() {
@@ -12,6 +12,7 @@
 
 //! Integration test for public API of FermionOperator
 
+use super::assert_sparse_matrices_close;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde_test::{assert_tokens, Configure, Token};
 use std::collections::BTreeMap;
@@ -20,8 +21,11 @@ use std::iter::{FromIterator, IntoIterator};
 #[cfg(feature = "struqture_1_export")]
 use std::str::FromStr;
 use struqture::fermions::{
-    FermionHamiltonian, FermionOperator, FermionProduct, HermitianFermionProduct,
+    fermionic_fock_basis, FermionHamiltonian, FermionOperator, FermionProduct,
+    HermitianFermionProduct, ToFermionSparseMatrixOperator,
 };
+use struqture::mappings::JordanWignerFermionToSpin;
+use struqture::spins::ToSparseMatrixOperator;
 use struqture::{
     ModeIndex, OperateOnDensityMatrix, OperateOnModes, OperateOnState, STRUQTURE_VERSION,
 };
@@ -494,6 +498,196 @@ fn serde_compact() {
     );
 }
 
+// Test the commutator and anticommutator functions of the FermionOperator
+#[test]
+fn commutator_anticommutator() {
+    let mut left = FermionOperator::new();
+    left.set(
+        FermionProduct::new([0], [1]).unwrap(),
+        CalculatorComplex::new(1.0, 0.5),
+    )
+    .unwrap();
+    left.set(
+        FermionProduct::new([2], []).unwrap(),
+        CalculatorComplex::from(0.3),
+    )
+    .unwrap();
+    left.set(
+        FermionProduct::new([0, 3], [0, 3]).unwrap(),
+        CalculatorComplex::from(2.0),
+    )
+    .unwrap();
+    let mut right = FermionOperator::new();
+    right
+        .set(
+            FermionProduct::new([1], [0]).unwrap(),
+            CalculatorComplex::from(1.5),
+        )
+        .unwrap();
+    right
+        .set(
+            FermionProduct::new([], [2]).unwrap(),
+            CalculatorComplex::new(0.0, 1.0),
+        )
+        .unwrap();
+    right
+        .set(
+            FermionProduct::new([4], []).unwrap(),
+            CalculatorComplex::from(0.7),
+        )
+        .unwrap();
+
+    let commutator = left.clone() * right.clone() - right.clone() * left.clone();
+    let anticommutator = left.clone() * right.clone() + right.clone() * left.clone();
+    assert_eq!(left.commutator(&right), commutator);
+    assert_eq!(left.anticommutator(&right), anticommutator);
+
+    // {c_0, c_0^dagger} = 1
+    let mut annihilator = FermionOperator::new();
+    annihilator
+        .set(
+            FermionProduct::new([], [0]).unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    let mut creator = FermionOperator::new();
+    creator
+        .set(
+            FermionProduct::new([0], []).unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    let mut identity = FermionOperator::new();
+    identity
+        .set(
+            FermionProduct::new([], []).unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    assert_eq!(annihilator.anticommutator(&creator), identity);
+}
+
+// Test the sparse_matrix and sparse_matrix_coo functions of the FermionOperator
+#[test]
+fn sparse_matrix() {
+    let mut fo = FermionOperator::new();
+    fo.add_operator_product(
+        FermionProduct::new([0], [2]).unwrap(),
+        CalculatorComplex::new(0.5, 0.2),
+    )
+    .unwrap();
+    fo.add_operator_product(
+        FermionProduct::new([1, 2], [0, 3]).unwrap(),
+        CalculatorComplex::from(-0.7),
+    )
+    .unwrap();
+    fo.add_operator_product(
+        FermionProduct::new([3], [1]).unwrap(),
+        CalculatorComplex::from(1.3),
+    )
+    .unwrap();
+    fo.add_operator_product(
+        FermionProduct::new([0, 1], [1, 2]).unwrap(),
+        CalculatorComplex::from(0.4),
+    )
+    .unwrap();
+
+    // The full Fock space reproduces the matrix of the Jordan-Wigner transformed operator
+    let full = fo.sparse_matrix(4, None).unwrap();
+    assert_sparse_matrices_close(&full, &fo.jordan_wigner().sparse_matrix(4).unwrap());
+
+    // The particle-number sector is a block of the full matrix
+    let basis = fermionic_fock_basis(4, Some(2)).unwrap();
+    assert_eq!(basis, vec![3, 5, 6, 9, 10, 12]);
+    let sector = fo.sparse_matrix(4, Some(2)).unwrap();
+    let mut block = std::collections::HashMap::new();
+    for (row, row_state) in basis.iter().enumerate() {
+        for (column, column_state) in basis.iter().enumerate() {
+            if let Some(value) = full.get(&(*row_state, *column_state)) {
+                block.insert((row, column), *value);
+            }
+        }
+    }
+    assert_sparse_matrices_close(&sector, &block);
+
+    let (values, (rows, columns)) = fo.sparse_matrix_coo(4, Some(2)).unwrap();
+    assert_eq!(values.len(), sector.len());
+    for ((row, column), value) in rows.iter().zip(columns.iter()).zip(values.iter()) {
+        assert_eq!(sector.get(&(*row, *column)), Some(value));
+    }
+
+    // Terms that change the particle number cannot be restricted to a sector
+    fo.add_operator_product(
+        FermionProduct::new([], [0]).unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    assert!(fo.sparse_matrix(4, None).is_ok());
+    assert!(fo.sparse_matrix(4, Some(2)).is_err());
+    assert!(fo.sparse_matrix(3, None).is_err());
+}
+
+// Test the multiplication of FermionOperators with more products than are evaluated together with the parallel feature
+#[test]
+fn multiply_many_terms() {
+    let mut operator = FermionOperator::new();
+    for index in 0..260usize {
+        let creators = [index % 4, 4 + index % 3];
+        let annihilators = [index % 5, 5 + (index / 5) % 4];
+        operator
+            .add_operator_product(
+                FermionProduct::new(creators, annihilators).unwrap(),
+                CalculatorComplex::new(0.1 * index as f64, 1.0 / (index + 1) as f64),
+            )
+            .unwrap();
+    }
+    let mut expected = FermionOperator::new();
+    for (left, left_value) in operator.iter() {
+        for (right, right_value) in operator.iter() {
+            let coefficient = right_value.clone() * left_value.clone();
+            for (product, sign) in left.clone() * right.clone() {
+                expected
+                    .add_operator_product(product, coefficient.clone() * sign)
+                    .unwrap();
+            }
+        }
+    }
+    assert_eq!(operator.clone() * operator, expected);
+}
+
+// Test the particle_hole_transformation function of the FermionOperator
+#[test]
+fn particle_hole_transformation() {
+    let mut fo = FermionOperator::new();
+    fo.add_operator_product(FermionProduct::new([0], [1]).unwrap(), 2.0.into())
+        .unwrap();
+    fo.add_operator_product(FermionProduct::new([0], [0]).unwrap(), 0.5.into())
+        .unwrap();
+    fo.add_operator_product(FermionProduct::new([1, 2], [0]).unwrap(), 1.0.into())
+        .unwrap();
+
+    // c0 <-> a0 and c2 <-> a2, a0 c0 = 1 - c0 a0 and c1 a2 c0 = c0 c1 a2
+    let mut expected = FermionOperator::new();
+    expected
+        .add_operator_product(FermionProduct::new([], [0, 1]).unwrap(), 2.0.into())
+        .unwrap();
+    expected
+        .add_operator_product(FermionProduct::new([], []).unwrap(), 0.5.into())
+        .unwrap();
+    expected
+        .add_operator_product(FermionProduct::new([0], [0]).unwrap(), (-0.5).into())
+        .unwrap();
+    expected
+        .add_operator_product(FermionProduct::new([0, 1], [2]).unwrap(), 1.0.into())
+        .unwrap();
+    let transformed = fo.particle_hole_transformation(&[0, 2]);
+    assert_eq!(transformed, expected);
+
+    // The transformation is an involution and leaves the other modes unchanged
+    assert_eq!(transformed.particle_hole_transformation(&[0, 2]), fo);
+    assert_eq!(fo.particle_hole_transformation(&[5]), fo);
+}
+
 #[cfg(feature = "json_schema")]
 #[test]
 fn test_fermion_operator_schema() {
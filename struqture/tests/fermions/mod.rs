@@ -21,3 +21,24 @@ mod fermionic_hamiltonian;
 mod fermionic_noise_operator;
 
 mod fermionic_open_system;
+
+/// Asserts that two sparse matrices agree up to numerical precision, treating missing entries as zero.
+fn assert_sparse_matrices_close(
+    left: &std::collections::HashMap<(usize, usize), num_complex::Complex64>,
+    right: &std::collections::HashMap<(usize, usize), num_complex::Complex64>,
+) {
+    for (key, value) in left.iter() {
+        let other = right.get(key).copied().unwrap_or_default();
+        assert!(
+            (value - other).norm() < 1e-12,
+            "{key:?}: {value} != {other}"
+        );
+    }
+    for (key, value) in right.iter() {
+        let other = left.get(key).copied().unwrap_or_default();
+        assert!(
+            (value - other).norm() < 1e-12,
+            "{key:?}: {other} != {value}"
+        );
+    }
+}
@@ -0,0 +1,302 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use num_complex::Complex64;
+use qoqo_calculator::CalculatorComplex;
+use std::collections::HashMap;
+use struqture::bosons::*;
+use struqture::mappings::{BosonSpinEncoding, BosonToSpinEncoding};
+use struqture::prelude::*;
+use struqture::spins::*;
+use struqture::StruqtureError;
+
+/// Returns the index of the spin state encoding the occupations of the modes.
+fn code_index(encoding: BosonSpinEncoding, occupations: &[usize], cutoffs: &[usize]) -> usize {
+    let mut index = 0;
+    let mut offset = 0;
+    for (occupation, cutoff) in occupations.iter().zip(cutoffs) {
+        index += match encoding {
+            BosonSpinEncoding::Unary => 1 << (offset + occupation),
+            BosonSpinEncoding::Binary => occupation << offset,
+            BosonSpinEncoding::Gray => (occupation ^ (occupation >> 1)) << offset,
+        };
+        offset += encoding.number_spins_per_mode(*cutoff);
+    }
+    index
+}
+
+/// Returns the occupations of the modes of a Fock space basis state.
+fn occupations(index: usize, cutoffs: &[usize]) -> Vec<usize> {
+    let mut index = index;
+    cutoffs
+        .iter()
+        .map(|cutoff| {
+            let occupation = index % (cutoff + 1);
+            index /= cutoff + 1;
+            occupation
+        })
+        .collect()
+}
+
+/// Asserts that the spin matrix restricted to the code space is the Fock space matrix.
+fn assert_encoded_matrix(
+    encoding: BosonSpinEncoding,
+    fock_matrix: &HashMap<(usize, usize), Complex64>,
+    spin_matrix: &HashMap<(usize, usize), Complex64>,
+    cutoffs: &[usize],
+) {
+    let dimension: usize = cutoffs.iter().map(|cutoff| cutoff + 1).product();
+    for row in 0..dimension {
+        for column in 0..dimension {
+            let expected = fock_matrix.get(&(row, column)).copied().unwrap_or_default();
+            let value = spin_matrix
+                .get(&(
+                    code_index(encoding, &occupations(row, cutoffs), cutoffs),
+                    code_index(encoding, &occupations(column, cutoffs), cutoffs),
+                ))
+                .copied()
+                .unwrap_or_default();
+            assert!((expected - value).norm() < 1e-10);
+        }
+    }
+}
+
+fn boson_operator() -> BosonOperator {
+    let mut bo = BosonOperator::new();
+    bo.add_operator_product(BosonProduct::new([0, 0], [0, 0]).unwrap(), 0.5.into())
+        .unwrap();
+    bo.add_operator_product(
+        BosonProduct::new([0], [1]).unwrap(),
+        CalculatorComplex::new(1.0, 0.5),
+    )
+    .unwrap();
+    bo.add_operator_product(BosonProduct::new([0, 0], [1]).unwrap(), 2.0.into())
+        .unwrap();
+    bo.add_operator_product(BosonProduct::new([1], [1, 1]).unwrap(), (-1.5).into())
+        .unwrap();
+    bo.add_operator_product(BosonProduct::new([], [1]).unwrap(), 0.25.into())
+        .unwrap();
+    bo
+}
+
+// Test the encodings of a BosonOperator against its matrix in the truncated Fock space
+#[test]
+fn encoded_boson_operator() {
+    let cutoffs = vec![2, 3];
+    let bo = boson_operator();
+    let fock_matrix = bo.sparse_matrix(2, cutoffs.clone()).unwrap();
+    for encoding in [
+        BosonSpinEncoding::Unary,
+        BosonSpinEncoding::Binary,
+        BosonSpinEncoding::Gray,
+    ] {
+        let number_spins: usize = cutoffs
+            .iter()
+            .map(|cutoff| encoding.number_spins_per_mode(*cutoff))
+            .sum();
+        let encoded = bo
+            .encoded_boson_spin_mapping(encoding, 2, cutoffs.clone())
+            .unwrap();
+        assert!(encoded.current_number_spins() <= number_spins);
+        let spin_matrix = encoded.sparse_matrix(number_spins).unwrap();
+        assert_encoded_matrix(encoding, &fock_matrix, &spin_matrix, &cutoffs);
+    }
+}
+
+// Test the encodings of BosonProducts
+#[test]
+fn encoded_boson_product() {
+    let bp = BosonProduct::new([], [0]).unwrap();
+    let mut so = PauliOperator::new();
+    so.add_operator_product(PauliProduct::new().x(0), 0.5.into())
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().y(0), CalculatorComplex::new(0.0, 0.5))
+        .unwrap();
+    assert_eq!(
+        bp.encoded_boson_spin_mapping(BosonSpinEncoding::Binary, 1, 1)
+            .unwrap(),
+        so
+    );
+
+    // In the unary encoding b†b only acts on the spins of the occupations
+    let bp = BosonProduct::new([1], [1]).unwrap();
+    let mut so = PauliOperator::new();
+    so.add_operator_product(PauliProduct::new(), 1.5.into())
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().z(3), (-0.5).into())
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().z(4), (-1.0).into())
+        .unwrap();
+    assert_eq!(
+        bp.encoded_boson_spin_mapping(BosonSpinEncoding::Unary, 2, vec![1, 2])
+            .unwrap(),
+        so
+    );
+
+    // Annihilating more bosons than the cutoff gives zero
+    let bp = BosonProduct::new([], [0, 0]).unwrap();
+    assert!(bp
+        .encoded_boson_spin_mapping(BosonSpinEncoding::Gray, 1, 1)
+        .unwrap()
+        .is_empty());
+}
+
+// Test the encodings of a HermitianBosonProduct and a BosonHamiltonian
+#[test]
+fn encoded_boson_hamiltonian() {
+    let cutoffs = vec![3, 2];
+    let mut bh = BosonHamiltonian::new();
+    bh.add_operator_product(
+        HermitianBosonProduct::new([0, 0], [0, 0]).unwrap(),
+        0.5.into(),
+    )
+    .unwrap();
+    bh.add_operator_product(
+        HermitianBosonProduct::new([0], [1]).unwrap(),
+        CalculatorComplex::new(1.0, -2.0),
+    )
+    .unwrap();
+    bh.add_operator_product(HermitianBosonProduct::new([0], [1, 1]).unwrap(), 0.3.into())
+        .unwrap();
+    let fock_matrix = bh.sparse_matrix(2, cutoffs.clone()).unwrap();
+    for encoding in [
+        BosonSpinEncoding::Unary,
+        BosonSpinEncoding::Binary,
+        BosonSpinEncoding::Gray,
+    ] {
+        let number_spins: usize = cutoffs
+            .iter()
+            .map(|cutoff| encoding.number_spins_per_mode(*cutoff))
+            .sum();
+        let encoded = bh
+            .encoded_boson_spin_mapping(encoding, 2, cutoffs.clone())
+            .unwrap();
+        let spin_matrix = encoded.sparse_matrix(number_spins).unwrap();
+        assert_encoded_matrix(encoding, &fock_matrix, &spin_matrix, &cutoffs);
+    }
+
+    let hbp = HermitianBosonProduct::new([0], [1]).unwrap();
+    let mut sh = PauliHamiltonian::new();
+    sh.add_operator_product(PauliProduct::new().x(0).x(1), 0.5.into())
+        .unwrap();
+    sh.add_operator_product(PauliProduct::new().y(0).y(1), 0.5.into())
+        .unwrap();
+    assert_eq!(
+        hbp.encoded_boson_spin_mapping(BosonSpinEncoding::Binary, 2, 1)
+            .unwrap(),
+        sh
+    );
+}
+
+// Test the encodings of a BosonLindbladNoiseOperator
+#[test]
+fn encoded_boson_noise_operator() {
+    let mut noise = BosonLindbladNoiseOperator::new();
+    noise
+        .add_operator_product(
+            (
+                BosonProduct::new([], [0]).unwrap(),
+                BosonProduct::new([], [0]).unwrap(),
+            ),
+            2.0.into(),
+        )
+        .unwrap();
+    noise
+        .add_operator_product(
+            (
+                BosonProduct::new([1], [0]).unwrap(),
+                BosonProduct::new([1], [0]).unwrap(),
+            ),
+            0.5.into(),
+        )
+        .unwrap();
+    noise
+        .add_operator_product(
+            (
+                BosonProduct::new([], [0, 0]).unwrap(),
+                BosonProduct::new([], [0, 0]).unwrap(),
+            ),
+            1.0.into(),
+        )
+        .unwrap();
+    // With a cutoff of one the binary encoding uses one spin per mode in the Fock space order
+    let encoded = noise
+        .encoded_boson_spin_mapping(BosonSpinEncoding::Binary, 2, 1)
+        .unwrap();
+    let fock_matrix = noise.sparse_matrix_superoperator(2, 1).unwrap();
+    let spin_matrix = encoded.sparse_matrix_superoperator(2).unwrap();
+    for (key, value) in fock_matrix.iter() {
+        let spin_value = spin_matrix.get(key).copied().unwrap_or_default();
+        assert!((value - spin_value).norm() < 1e-10);
+    }
+    for (key, value) in spin_matrix.iter() {
+        let fock_value = fock_matrix.get(key).copied().unwrap_or_default();
+        assert!((value - fock_value).norm() < 1e-10);
+    }
+}
+
+// Test the code space penalty of the encodings
+#[test]
+fn code_space_penalty() {
+    // The occupation 3 is not in the code space of a binary register with cutoff 2
+    let mut penalty = PauliHamiltonian::new();
+    penalty
+        .add_operator_product(PauliProduct::new(), 0.25.into())
+        .unwrap();
+    penalty
+        .add_operator_product(PauliProduct::new().z(0), (-0.25).into())
+        .unwrap();
+    penalty
+        .add_operator_product(PauliProduct::new().z(1), (-0.25).into())
+        .unwrap();
+    penalty
+        .add_operator_product(PauliProduct::new().z(0).z(1), 0.25.into())
+        .unwrap();
+    assert_eq!(
+        BosonSpinEncoding::Binary.code_space_penalty(1, 2).unwrap(),
+        penalty
+    );
+    assert!(BosonSpinEncoding::Gray
+        .code_space_penalty(2, 3)
+        .unwrap()
+        .is_empty());
+
+    let cutoffs = vec![2, 1];
+    let penalty = BosonSpinEncoding::Unary
+        .code_space_penalty(2, cutoffs)
+        .unwrap();
+    let matrix = penalty.sparse_matrix(5).unwrap();
+    for state in 0..32_usize {
+        let valid_registers = usize::from((state & 0b111).count_ones() == 1)
+            + usize::from((state >> 3).count_ones() == 1);
+        let value = matrix.get(&(state, state)).copied().unwrap_or_default();
+        assert!((value - Complex64::new((2 - valid_registers) as f64, 0.0)).norm() < 1e-10);
+    }
+}
+
+// Test the errors of the encodings
+#[test]
+fn encoded_boson_errors() {
+    let bo = boson_operator();
+    assert_eq!(
+        bo.encoded_boson_spin_mapping(BosonSpinEncoding::Unary, 1, 2),
+        Err(StruqtureError::GenericError {
+            msg: "Cannot encode 1 modes of an object acting on 2 modes.".to_string()
+        })
+    );
+    assert_eq!(
+        bo.encoded_boson_spin_mapping(BosonSpinEncoding::Binary, 2, vec![1, 2, 3]),
+        Err(StruqtureError::GenericError {
+            msg: "Number of cutoffs 3 does not match the number of modes 2.".to_string()
+        })
+    );
+}
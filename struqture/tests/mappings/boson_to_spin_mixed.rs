@@ -0,0 +1,274 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use qoqo_calculator::CalculatorComplex;
+use struqture::bosons::BosonProduct;
+use struqture::fermions::FermionProduct;
+use struqture::mappings::BosonToSpin;
+use struqture::mixed_systems::*;
+use struqture::prelude::*;
+use struqture::spins::{DecoherenceProduct, PauliProduct};
+use struqture::StruqtureError;
+
+// Test the Dicke mapping of a MixedOperator with a number of spins chosen per bosonic subsystem
+#[test]
+fn dicke_mixed_operator() {
+    let annihilator = BosonProduct::new([], [0]).unwrap();
+    let number = BosonProduct::new([1], [1]).unwrap();
+    let fermions = FermionProduct::new([0], [1]).unwrap();
+    let mut mo = MixedOperator::new(1, 2, 1);
+    let product = MixedProduct::new(
+        [PauliProduct::new().x(0)],
+        [annihilator.clone(), number.clone()],
+        [fermions.clone()],
+    )
+    .unwrap();
+    let (conjugate, sign) = product.hermitian_conjugate();
+    mo.add_operator_product(product, CalculatorComplex::new(1.0, 0.5))
+        .unwrap();
+    mo.add_operator_product(conjugate, CalculatorComplex::new(1.0, -0.5) * sign)
+        .unwrap();
+
+    let mapped = mo.dicke_boson_spin_mapping_per_subsystem(&[2, 3]).unwrap();
+    assert_eq!(mapped.current_number_spins(), vec![1, 2, 6]);
+    assert!(mapped.current_number_bosonic_modes().is_empty());
+
+    // The bosonic subsystems become the spin subsystems 1 and 2, b and b† are mapped together
+    let mut expected = MixedOperator::new(3, 0, 1);
+    for (first, first_value) in annihilator.dicke_boson_spin_mapping(2).unwrap() {
+        for (second, second_value) in number.dicke_boson_spin_mapping(3).unwrap() {
+            let product = MixedProduct::new(
+                [PauliProduct::new().x(0), first.clone(), second],
+                [],
+                [fermions.clone()],
+            )
+            .unwrap();
+            let value = first_value.clone() * second_value;
+            let (conjugate, sign) = product.hermitian_conjugate();
+            expected
+                .add_operator_product(product, CalculatorComplex::new(1.0, 0.5) * value.clone())
+                .unwrap();
+            expected
+                .add_operator_product(conjugate, CalculatorComplex::new(1.0, -0.5) * value * sign)
+                .unwrap();
+        }
+    }
+    assert_eq!(mapped, expected);
+
+    // The trait method uses the same number of spins for every bosonic subsystem
+    assert_eq!(
+        mo.dicke_boson_spin_mapping(2).unwrap(),
+        mo.dicke_boson_spin_mapping_per_subsystem(&[2, 2]).unwrap()
+    );
+    assert_eq!(
+        mo.direct_boson_spin_mapping().unwrap(),
+        mo.dicke_boson_spin_mapping_per_subsystem(&[1, 1]).unwrap()
+    );
+}
+
+// Test the Dicke mapping of a MixedOperator containing a single bosonic annihilator and creator
+#[test]
+fn dicke_mixed_operator_hermitian_pair() {
+    let mut mo = MixedOperator::new(1, 1, 0);
+    for bosons in [
+        BosonProduct::new([], [0]).unwrap(),
+        BosonProduct::new([0], []).unwrap(),
+    ] {
+        mo.add_operator_product(
+            MixedProduct::new([PauliProduct::new().z(0)], [bosons], []).unwrap(),
+            1.0.into(),
+        )
+        .unwrap();
+    }
+
+    // Z⊗b + Z⊗b† is mapped to the Dicke representation of Z⊗(b + b†)
+    let mut expected = MixedOperator::new(2, 0, 0);
+    expected
+        .add_operator_product(
+            MixedProduct::new([PauliProduct::new().z(0), PauliProduct::new().x(0)], [], [])
+                .unwrap(),
+            1.0.into(),
+        )
+        .unwrap();
+    assert_eq!(mo.direct_boson_spin_mapping().unwrap(), expected);
+
+    // Without the hermitian conjugate term the operator cannot be mapped
+    let mut annihilator_only = MixedOperator::new(1, 1, 0);
+    annihilator_only
+        .add_operator_product(
+            MixedProduct::new(
+                [PauliProduct::new().z(0)],
+                [BosonProduct::new([], [0]).unwrap()],
+                [],
+            )
+            .unwrap(),
+            1.0.into(),
+        )
+        .unwrap();
+    assert_eq!(
+        annihilator_only.direct_boson_spin_mapping(),
+        Err(StruqtureError::NonHermitianOperator)
+    );
+
+    // Small coefficients are not treated as zero
+    let small_annihilator = annihilator_only * CalculatorComplex::from(1e-20);
+    assert_eq!(
+        small_annihilator.direct_boson_spin_mapping(),
+        Err(StruqtureError::NonHermitianOperator)
+    );
+}
+
+// Test the Dicke mapping of a MixedHamiltonian
+#[test]
+fn dicke_mixed_hamiltonian() {
+    let number = BosonProduct::new([0], [0]).unwrap();
+    let fermions = FermionProduct::new([0], [0]).unwrap();
+    let mut mh = MixedHamiltonian::new(1, 1, 1);
+    mh.add_operator_product(
+        HermitianMixedProduct::new(
+            [PauliProduct::new().z(0)],
+            [number.clone()],
+            [fermions.clone()],
+        )
+        .unwrap(),
+        1.5.into(),
+    )
+    .unwrap();
+
+    let mut expected = MixedHamiltonian::new(2, 0, 1);
+    for (product, value) in number.dicke_boson_spin_mapping(3).unwrap() {
+        expected
+            .add_operator_product(
+                HermitianMixedProduct::new(
+                    [PauliProduct::new().z(0), product],
+                    [],
+                    [fermions.clone()],
+                )
+                .unwrap(),
+                value * 1.5,
+            )
+            .unwrap();
+    }
+    assert_eq!(mh.dicke_boson_spin_mapping(3).unwrap(), expected);
+}
+
+// Test the Dicke mapping of a MixedLindbladNoiseOperator and a MixedLindbladOpenSystem
+#[test]
+fn dicke_mixed_noise_and_open_system() {
+    let lowering = MixedDecoherenceProduct::new(
+        [DecoherenceProduct::new().z(0)],
+        [BosonProduct::new([], [0]).unwrap()],
+        [],
+    )
+    .unwrap();
+    let mut noise = MixedLindbladNoiseOperator::new(1, 1, 0);
+    noise
+        .add_operator_product((lowering.clone(), lowering), 2.0.into())
+        .unwrap();
+
+    // The annihilator maps to (X_0 + X_1) / sqrt(2) on the new spin subsystem
+    let mut expected = MixedLindbladNoiseOperator::new(2, 0, 0);
+    for left in 0..2 {
+        for right in 0..2 {
+            expected
+                .add_operator_product(
+                    (
+                        MixedDecoherenceProduct::new(
+                            [
+                                DecoherenceProduct::new().z(0),
+                                DecoherenceProduct::new().x(left),
+                            ],
+                            [],
+                            [],
+                        )
+                        .unwrap(),
+                        MixedDecoherenceProduct::new(
+                            [
+                                DecoherenceProduct::new().z(0),
+                                DecoherenceProduct::new().x(right),
+                            ],
+                            [],
+                            [],
+                        )
+                        .unwrap(),
+                    ),
+                    1.0.into(),
+                )
+                .unwrap();
+        }
+    }
+    let mapped_noise = noise.dicke_boson_spin_mapping(2).unwrap();
+    assert_eq!(mapped_noise.len(), expected.len());
+    for (key, value) in expected.iter() {
+        assert!(
+            (value.clone() - mapped_noise.get(key))
+                .norm()
+                .float()
+                .unwrap()
+                .abs()
+                < 1e-10
+        );
+    }
+
+    let mut mh = MixedHamiltonian::new(1, 1, 0);
+    mh.add_operator_product(
+        HermitianMixedProduct::new(
+            [PauliProduct::new()],
+            [BosonProduct::new([0], [0]).unwrap()],
+            [],
+        )
+        .unwrap(),
+        0.5.into(),
+    )
+    .unwrap();
+    let open_system = MixedLindbladOpenSystem::group(mh.clone(), noise).unwrap();
+    assert_eq!(
+        open_system
+            .dicke_boson_spin_mapping_per_subsystem(&[2])
+            .unwrap(),
+        MixedLindbladOpenSystem::group(mh.dicke_boson_spin_mapping(2).unwrap(), mapped_noise)
+            .unwrap()
+    );
+}
+
+// Test the errors of the Dicke mapping of mixed systems
+#[test]
+fn dicke_mixed_errors() {
+    let mut mo = MixedOperator::new(0, 2, 0);
+    for (creators, annihilators) in [([0], [1]), ([1], [0])] {
+        mo.add_operator_product(
+            MixedProduct::new(
+                [],
+                [
+                    BosonProduct::new(creators, annihilators).unwrap(),
+                    BosonProduct::new([], []).unwrap(),
+                ],
+                [],
+            )
+            .unwrap(),
+            1.0.into(),
+        )
+        .unwrap();
+    }
+    assert_eq!(
+        mo.dicke_boson_spin_mapping_per_subsystem(&[2]),
+        Err(StruqtureError::GenericError {
+            msg: "The number of spins per bosonic mode is given for 1 bosonic subsystems, but the object has 2 bosonic subsystems.".to_string()
+        })
+    );
+    assert_eq!(
+        mo.dicke_boson_spin_mapping(1),
+        Err(StruqtureError::GenericError {
+            msg: "The boson -> spin transformation is only available for terms such as b†b or (b† + b), but the term here is: c0a1".to_string()
+        })
+    );
+}
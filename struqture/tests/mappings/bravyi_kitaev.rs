@@ -0,0 +1,254 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
+use struqture::fermions::*;
+use struqture::mappings::{
+    BravyiKitaevFermionToSpin, BravyiKitaevSpinToFermion, JordanWignerFermionToSpin,
+};
+use struqture::prelude::*;
+use struqture::spins::*;
+use struqture::StruqtureError;
+
+/// Returns the index of the Bravyi-Kitaev basis state of the Jordan-Wigner basis state with the given index.
+fn bravyi_kitaev_index(index: usize, number_modes: usize) -> usize {
+    (0..number_modes)
+        .map(|qubit| {
+            let parity = ((qubit & (qubit + 1))..=qubit)
+                .map(|mode| (index >> mode) & 1)
+                .sum::<usize>()
+                % 2;
+            parity << qubit
+        })
+        .sum()
+}
+
+fn fermion_operator() -> FermionOperator {
+    let mut fo = FermionOperator::new();
+    fo.add_operator_product(FermionProduct::new([0], [3]).unwrap(), 1.0.into())
+        .unwrap();
+    fo.add_operator_product(
+        FermionProduct::new([1, 4], [2]).unwrap(),
+        CalculatorComplex::new(0.5, -2.0),
+    )
+    .unwrap();
+    fo.add_operator_product(FermionProduct::new([2], [2]).unwrap(), 3.0.into())
+        .unwrap();
+    fo.add_operator_product(FermionProduct::new([], [0, 1, 4]).unwrap(), 0.25.into())
+        .unwrap();
+    fo
+}
+
+fn fermion_hamiltonian() -> FermionHamiltonian {
+    let mut fh = FermionHamiltonian::new();
+    fh.add_operator_product(HermitianFermionProduct::new([0], [3]).unwrap(), 1.0.into())
+        .unwrap();
+    fh.add_operator_product(
+        HermitianFermionProduct::new([1, 2], [1, 2]).unwrap(),
+        2.0.into(),
+    )
+    .unwrap();
+    fh.add_operator_product(
+        HermitianFermionProduct::new([0, 1], [2, 4]).unwrap(),
+        CalculatorComplex::new(0.5, 1.5),
+    )
+    .unwrap();
+    fh
+}
+
+// Test the Bravyi-Kitaev mapping of a FermionOperator against the Jordan-Wigner mapping
+#[test]
+fn bk_fermion_operator_basis_change() {
+    let number_modes = 5;
+    let fo = fermion_operator();
+    let jw_matrix = fo.jordan_wigner().sparse_matrix(number_modes).unwrap();
+    let bk_matrix = fo
+        .bravyi_kitaev(number_modes)
+        .unwrap()
+        .sparse_matrix(number_modes)
+        .unwrap();
+    assert_eq!(jw_matrix.len(), bk_matrix.len());
+    for ((row, column), value) in jw_matrix {
+        let bk_value = bk_matrix
+            .get(&(
+                bravyi_kitaev_index(row, number_modes),
+                bravyi_kitaev_index(column, number_modes),
+            ))
+            .unwrap();
+        assert!((bk_value - value).norm() < 1e-10);
+    }
+}
+
+// Test the Bravyi-Kitaev mapping of FermionProducts
+#[test]
+fn bk_fermion_product() {
+    // The occupation of mode 1 is the parity of qubits 0 and 1
+    let fp = FermionProduct::new([1], [1]).unwrap();
+    let mut so = PauliOperator::new();
+    so.add_operator_product(PauliProduct::new(), 0.5.into())
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().z(0).z(1), (-0.5).into())
+        .unwrap();
+    assert_eq!(fp.bravyi_kitaev(2).unwrap(), so);
+
+    // Creating a fermion in mode 0 also flips the qubits storing the parity of mode 0
+    let fp = FermionProduct::new([0], []).unwrap();
+    let mut so = PauliOperator::new();
+    so.add_operator_product(PauliProduct::new().x(0).x(1).x(3), 0.5.into())
+        .unwrap();
+    so.add_operator_product(
+        PauliProduct::new().y(0).x(1).x(3),
+        CalculatorComplex::new(0.0, -0.5),
+    )
+    .unwrap();
+    assert_eq!(fp.bravyi_kitaev(4).unwrap(), so);
+    assert_eq!(fp.bravyi_kitaev(1).unwrap(), fp.jordan_wigner());
+
+    let fp = FermionProduct::new([], []).unwrap();
+    let mut so = PauliOperator::new();
+    so.add_operator_product(PauliProduct::new(), 1.0.into())
+        .unwrap();
+    assert_eq!(fp.bravyi_kitaev(3).unwrap(), so);
+}
+
+// Test that the Bravyi-Kitaev mapping acts on a logarithmic number of qubits
+#[test]
+fn bk_logarithmic_weight() {
+    let number_modes = 16;
+    for mode in 0..number_modes {
+        let fp = FermionProduct::new([mode], []).unwrap();
+        for (product, _) in fp.bravyi_kitaev(number_modes).unwrap().iter() {
+            assert!(product.len() <= 5);
+        }
+    }
+}
+
+// Test the Bravyi-Kitaev mapping of a HermitianFermionProduct and a FermionHamiltonian
+#[test]
+fn bk_fermion_hamiltonian() {
+    let hfp = HermitianFermionProduct::new([0], [2]).unwrap();
+    let expected = PauliHamiltonian::try_from(
+        PauliOperator::from(hfp.jordan_wigner())
+            .conjugate_with_clifford(&CliffordGate::ControlledNot {
+                control: 0,
+                target: 1,
+            })
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(hfp.bravyi_kitaev(3).unwrap(), expected);
+
+    let number_modes = 5;
+    let fh = fermion_hamiltonian();
+    let bk = fh.bravyi_kitaev(number_modes).unwrap();
+    let fo = FermionOperator::from(fh.clone());
+    assert_eq!(
+        PauliOperator::from(bk.clone()),
+        fo.bravyi_kitaev(number_modes).unwrap()
+    );
+    assert_eq!(bk.bravyi_kitaev(number_modes).unwrap(), fh);
+}
+
+// Test the Bravyi-Kitaev mapping of a FermionLindbladNoiseOperator and a FermionLindbladOpenSystem
+#[test]
+fn bk_fermion_noise_and_open_system() {
+    let number_modes = 4;
+    let mut noise = FermionLindbladNoiseOperator::new();
+    noise
+        .add_operator_product(
+            (
+                FermionProduct::new([0], [2]).unwrap(),
+                FermionProduct::new([3], [1]).unwrap(),
+            ),
+            CalculatorComplex::new(0.5, 0.5),
+        )
+        .unwrap();
+    noise
+        .add_operator_product(
+            (
+                FermionProduct::new([], [3]).unwrap(),
+                FermionProduct::new([], [3]).unwrap(),
+            ),
+            2.0.into(),
+        )
+        .unwrap();
+    let bk_noise = noise.bravyi_kitaev(number_modes).unwrap();
+    assert_eq!(bk_noise.bravyi_kitaev(number_modes).unwrap(), noise);
+
+    let mut system = FermionHamiltonian::new();
+    system
+        .add_operator_product(
+            HermitianFermionProduct::new([1], [3]).unwrap(),
+            CalculatorComplex::new(1.0, 0.0),
+        )
+        .unwrap();
+    let open_system = FermionLindbladOpenSystem::group(system.clone(), noise).unwrap();
+    let bk_open_system = open_system.bravyi_kitaev(number_modes).unwrap();
+    assert_eq!(
+        bk_open_system,
+        PauliLindbladOpenSystem::group(system.bravyi_kitaev(number_modes).unwrap(), bk_noise)
+            .unwrap()
+    );
+    assert_eq!(
+        bk_open_system.bravyi_kitaev(number_modes).unwrap(),
+        open_system
+    );
+}
+
+// Test the Bravyi-Kitaev mapping of PauliProducts and PauliOperators to fermions
+#[test]
+fn bk_spin_to_fermion() {
+    let number_modes = 5;
+    let fo = fermion_operator();
+    let bk = fo.bravyi_kitaev(number_modes).unwrap();
+    assert_eq!(bk.bravyi_kitaev(number_modes).unwrap(), fo);
+
+    // Qubits 0 and 1 store the parities of mode 0 and of modes 0 and 1
+    let pp = PauliProduct::new().z(0).z(1);
+    let mut fo = FermionOperator::new();
+    fo.add_operator_product(FermionProduct::new([], []).unwrap(), 1.0.into())
+        .unwrap();
+    fo.add_operator_product(FermionProduct::new([1], [1]).unwrap(), (-2.0).into())
+        .unwrap();
+    assert_eq!(pp.bravyi_kitaev(2).unwrap(), fo);
+
+    let mut so = PauliOperator::new();
+    so.add_operator_product(pp.clone(), CalculatorComplex::new(0.0, 2.0))
+        .unwrap();
+    assert_eq!(
+        so.bravyi_kitaev(2).unwrap(),
+        fo * CalculatorComplex::new(0.0, 2.0)
+    );
+
+    let mut sh = PauliHamiltonian::new();
+    sh.add_operator_product(pp, CalculatorFloat::from(3.0))
+        .unwrap();
+    assert_eq!(
+        FermionOperator::from(sh.bravyi_kitaev(2).unwrap()),
+        so.bravyi_kitaev(2).unwrap() * CalculatorComplex::new(0.0, -1.5)
+    );
+}
+
+// Test the errors of the Bravyi-Kitaev mapping
+#[test]
+fn bk_too_few_modes() {
+    let fo = fermion_operator();
+    assert_eq!(
+        fo.bravyi_kitaev(4),
+        Err(StruqtureError::GenericError {
+            msg: "Cannot apply the Bravyi-Kitaev mapping for 4 modes to an object acting on 5 modes or spins.".to_string()
+        })
+    );
+    assert!(fermion_hamiltonian().bravyi_kitaev(2).is_err());
+    assert!(PauliProduct::new().x(3).bravyi_kitaev(3).is_err());
+    assert!(PauliOperator::new().bravyi_kitaev(0).is_ok());
+}
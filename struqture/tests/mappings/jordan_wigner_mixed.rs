@@ -0,0 +1,243 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use num_complex::Complex64;
+use qoqo_calculator::CalculatorComplex;
+use std::collections::HashMap;
+use struqture::bosons::BosonProduct;
+use struqture::fermions::FermionProduct;
+use struqture::mappings::JordanWignerFermionToSpin;
+use struqture::mixed_systems::*;
+use struqture::prelude::*;
+use struqture::spins::{DecoherenceProduct, PauliProduct};
+
+/// Asserts that two sparse matrices are equal up to numerical precision.
+fn assert_matrices_close(
+    left: &HashMap<(usize, usize), Complex64>,
+    right: &HashMap<(usize, usize), Complex64>,
+) {
+    for (key, value) in left.iter() {
+        assert!((value - right.get(key).copied().unwrap_or_default()).norm() < 1e-10);
+    }
+    for (key, value) in right.iter() {
+        assert!((value - left.get(key).copied().unwrap_or_default()).norm() < 1e-10);
+    }
+}
+
+fn mixed_operator() -> MixedOperator {
+    let mut mo = MixedOperator::new(1, 0, 2);
+    mo.add_operator_product(
+        MixedProduct::new(
+            [PauliProduct::new().x(0)],
+            [],
+            [
+                FermionProduct::new([0], [2]).unwrap(),
+                FermionProduct::new([1], []).unwrap(),
+            ],
+        )
+        .unwrap(),
+        CalculatorComplex::new(1.0, 0.5),
+    )
+    .unwrap();
+    mo.add_operator_product(
+        MixedProduct::new(
+            [PauliProduct::new().z(1)],
+            [],
+            [
+                FermionProduct::new([], []).unwrap(),
+                FermionProduct::new([0, 1], [0, 1]).unwrap(),
+            ],
+        )
+        .unwrap(),
+        2.0.into(),
+    )
+    .unwrap();
+    mo
+}
+
+// Test the Jordan-Wigner mapping of a MixedProduct and a MixedOperator
+#[test]
+fn jw_mixed_operator() {
+    let mo = mixed_operator();
+    let mapped = mo.jordan_wigner();
+    assert_eq!(mapped.current_number_spins(), vec![2, 3, 2]);
+    assert!(mapped.current_number_fermionic_modes().is_empty());
+
+    // Without bosons the subsystems stay in the same order in the Hilbert space
+    let space = MixedHilbertSpace::new(vec![2], vec![], vec![3, 2]).unwrap();
+    let spin_space = MixedHilbertSpace::new(vec![2, 3, 2], vec![], vec![]).unwrap();
+    assert_matrices_close(
+        &mo.sparse_matrix(&space).unwrap(),
+        &mapped.sparse_matrix(&spin_space).unwrap(),
+    );
+
+    // The boson subsystems are not changed
+    let mp = MixedProduct::new(
+        [PauliProduct::new().y(0)],
+        [BosonProduct::new([0], [1]).unwrap()],
+        [FermionProduct::new([1], [1]).unwrap()],
+    )
+    .unwrap();
+    let mut expected = MixedOperator::new(2, 1, 0);
+    for (product, value) in FermionProduct::new([1], [1]).unwrap().jordan_wigner() {
+        expected
+            .add_operator_product(
+                MixedProduct::new(
+                    [PauliProduct::new().y(0), product],
+                    [BosonProduct::new([0], [1]).unwrap()],
+                    [],
+                )
+                .unwrap(),
+                value,
+            )
+            .unwrap();
+    }
+    assert_eq!(mp.jordan_wigner(), expected);
+}
+
+// Test the Jordan-Wigner mapping of a HermitianMixedProduct and a MixedHamiltonian
+#[test]
+fn jw_mixed_hamiltonian() {
+    let mut mh = MixedHamiltonian::new(1, 0, 1);
+    mh.add_operator_product(
+        HermitianMixedProduct::new(
+            [PauliProduct::new().x(0)],
+            [],
+            [FermionProduct::new([0], [2]).unwrap()],
+        )
+        .unwrap(),
+        CalculatorComplex::new(1.0, 0.5),
+    )
+    .unwrap();
+    mh.add_operator_product(
+        HermitianMixedProduct::new(
+            [PauliProduct::new().z(1)],
+            [],
+            [FermionProduct::new([0, 1], [0, 1]).unwrap()],
+        )
+        .unwrap(),
+        2.0.into(),
+    )
+    .unwrap();
+    mh.add_operator_product(
+        HermitianMixedProduct::new(
+            [PauliProduct::new()],
+            [],
+            [FermionProduct::new([], [1]).unwrap()],
+        )
+        .unwrap(),
+        CalculatorComplex::new(0.0, -1.5),
+    )
+    .unwrap();
+    let mapped = mh.jordan_wigner();
+    assert_eq!(mapped.current_number_spins(), vec![2, 3]);
+    let space = MixedHilbertSpace::new(vec![2], vec![], vec![3]).unwrap();
+    let spin_space = MixedHilbertSpace::new(vec![2, 3], vec![], vec![]).unwrap();
+    assert_matrices_close(
+        &mh.sparse_matrix(&space).unwrap(),
+        &mapped.sparse_matrix(&spin_space).unwrap(),
+    );
+
+    // With a boson subsystem deciding the hermitian conjugate, the coefficients of the Pauli terms are kept
+    let hmp = HermitianMixedProduct::new(
+        [PauliProduct::new().x(0)],
+        [BosonProduct::new([0], [1]).unwrap()],
+        [FermionProduct::new([1], [0]).unwrap()],
+    )
+    .unwrap();
+    let mut expected = MixedHamiltonian::new(2, 1, 0);
+    for (product, value) in FermionProduct::new([1], [0]).unwrap().jordan_wigner() {
+        expected
+            .add_operator_product(
+                HermitianMixedProduct::new(
+                    [PauliProduct::new().x(0), product],
+                    [BosonProduct::new([0], [1]).unwrap()],
+                    [],
+                )
+                .unwrap(),
+                value,
+            )
+            .unwrap();
+    }
+    assert_eq!(hmp.jordan_wigner(), expected);
+}
+
+// Test the Jordan-Wigner mapping of a MixedLindbladNoiseOperator and a MixedLindbladOpenSystem
+#[test]
+fn jw_mixed_noise_and_open_system() {
+    let mut noise = MixedLindbladNoiseOperator::new(1, 0, 1);
+    noise
+        .add_operator_product(
+            (
+                MixedDecoherenceProduct::new(
+                    [DecoherenceProduct::new().z(0)],
+                    [],
+                    [FermionProduct::new([], [1]).unwrap()],
+                )
+                .unwrap(),
+                MixedDecoherenceProduct::new(
+                    [DecoherenceProduct::new().z(0)],
+                    [],
+                    [FermionProduct::new([], [1]).unwrap()],
+                )
+                .unwrap(),
+            ),
+            0.5.into(),
+        )
+        .unwrap();
+    noise
+        .add_operator_product(
+            (
+                MixedDecoherenceProduct::new(
+                    [DecoherenceProduct::new()],
+                    [],
+                    [FermionProduct::new([0], [2]).unwrap()],
+                )
+                .unwrap(),
+                MixedDecoherenceProduct::new(
+                    [DecoherenceProduct::new().x(0)],
+                    [],
+                    [FermionProduct::new([1], [1]).unwrap()],
+                )
+                .unwrap(),
+            ),
+            CalculatorComplex::new(1.0, 0.5),
+        )
+        .unwrap();
+    let mapped_noise = noise.jordan_wigner();
+    let space = MixedHilbertSpace::new(vec![1], vec![], vec![3]).unwrap();
+    let spin_space = MixedHilbertSpace::new(vec![1, 3], vec![], vec![]).unwrap();
+    assert_matrices_close(
+        &noise.sparse_matrix_superoperator(&space).unwrap(),
+        &mapped_noise
+            .sparse_matrix_superoperator(&spin_space)
+            .unwrap(),
+    );
+
+    let mut system = MixedHamiltonian::new(1, 0, 1);
+    system
+        .add_operator_product(
+            HermitianMixedProduct::new(
+                [PauliProduct::new().y(0)],
+                [],
+                [FermionProduct::new([0], [1]).unwrap()],
+            )
+            .unwrap(),
+            1.0.into(),
+        )
+        .unwrap();
+    let open_system = MixedLindbladOpenSystem::group(system.clone(), noise).unwrap();
+    assert_eq!(
+        open_system.jordan_wigner(),
+        MixedLindbladOpenSystem::group(system.jordan_wigner(), mapped_noise).unwrap()
+    );
+}
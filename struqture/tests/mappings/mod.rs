@@ -11,6 +11,14 @@
 // limitations under the License.
 
 mod jordan_wigner_fermion_to_spin;
+mod jordan_wigner_mixed;
 mod jordan_wigner_spin_to_fermion;
 
+mod bravyi_kitaev;
+mod parity;
+
+mod boson_encodings;
 mod boson_to_spin;
+mod boson_to_spin_mixed;
+
+mod spin_to_boson;
@@ -0,0 +1,290 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use qoqo_calculator::CalculatorComplex;
+use struqture::fermions::*;
+use struqture::mappings::{JordanWignerFermionToSpin, ParityFermionToSpin};
+use struqture::prelude::*;
+use struqture::spins::*;
+use struqture::StruqtureError;
+
+/// Returns the index of the parity basis state of the Jordan-Wigner basis state with the given index.
+fn parity_index(index: usize, number_modes: usize) -> usize {
+    (0..number_modes)
+        .map(|qubit| ((index & ((2 << qubit) - 1)).count_ones() as usize % 2) << qubit)
+        .sum()
+}
+
+/// A Hamiltonian conserving the number of spin-up (modes 0 and 1) and spin-down (modes 2 and 3) fermions.
+fn spin_conserving_hamiltonian() -> FermionHamiltonian {
+    let mut fh = FermionHamiltonian::new();
+    fh.add_operator_product(HermitianFermionProduct::new([0], [1]).unwrap(), 1.0.into())
+        .unwrap();
+    fh.add_operator_product(
+        HermitianFermionProduct::new([2], [3]).unwrap(),
+        CalculatorComplex::new(0.5, -0.5),
+    )
+    .unwrap();
+    fh.add_operator_product(HermitianFermionProduct::new([1], [1]).unwrap(), 0.3.into())
+        .unwrap();
+    fh.add_operator_product(
+        HermitianFermionProduct::new([0, 2], [0, 2]).unwrap(),
+        2.0.into(),
+    )
+    .unwrap();
+    fh.add_operator_product(
+        HermitianFermionProduct::new([0, 2], [1, 3]).unwrap(),
+        CalculatorComplex::new(0.25, 0.75),
+    )
+    .unwrap();
+    fh
+}
+
+// Test the parity mapping of a FermionOperator against the Jordan-Wigner mapping
+#[test]
+fn parity_fermion_operator_basis_change() {
+    let number_modes = 5;
+    let mut fo = FermionOperator::new();
+    fo.add_operator_product(FermionProduct::new([0], [3]).unwrap(), 1.0.into())
+        .unwrap();
+    fo.add_operator_product(
+        FermionProduct::new([1, 4], [2]).unwrap(),
+        CalculatorComplex::new(0.5, -2.0),
+    )
+    .unwrap();
+    fo.add_operator_product(FermionProduct::new([], [0, 1, 4]).unwrap(), 0.25.into())
+        .unwrap();
+    let jw_matrix = fo.jordan_wigner().sparse_matrix(number_modes).unwrap();
+    let parity_matrix = fo
+        .parity_mapping(number_modes, None)
+        .unwrap()
+        .sparse_matrix(number_modes)
+        .unwrap();
+    assert_eq!(jw_matrix.len(), parity_matrix.len());
+    for ((row, column), value) in jw_matrix {
+        let parity_value = parity_matrix
+            .get(&(
+                parity_index(row, number_modes),
+                parity_index(column, number_modes),
+            ))
+            .unwrap();
+        assert!((parity_value - value).norm() < 1e-10);
+    }
+}
+
+// Test the parity mapping of FermionProducts
+#[test]
+fn parity_fermion_product() {
+    // The occupation of mode 1 is the parity of qubits 0 and 1
+    let fp = FermionProduct::new([1], [1]).unwrap();
+    let mut so = PauliOperator::new();
+    so.add_operator_product(PauliProduct::new(), 0.5.into())
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().z(0).z(1), (-0.5).into())
+        .unwrap();
+    assert_eq!(fp.parity_mapping(3, None).unwrap(), so);
+
+    // Creating a fermion in mode 0 flips the parity of all qubits
+    let fp = FermionProduct::new([0], []).unwrap();
+    let mut so = PauliOperator::new();
+    so.add_operator_product(PauliProduct::new().x(0).x(1).x(2), 0.5.into())
+        .unwrap();
+    so.add_operator_product(
+        PauliProduct::new().y(0).x(1).x(2),
+        CalculatorComplex::new(0.0, -0.5),
+    )
+    .unwrap();
+    assert_eq!(fp.parity_mapping(3, None).unwrap(), so);
+}
+
+// Test the two-qubit reduction of the parity mapping of a FermionHamiltonian
+#[test]
+fn parity_two_qubit_reduction() {
+    let fh = spin_conserving_hamiltonian();
+    let full_matrix = fh
+        .parity_mapping(4, None)
+        .unwrap()
+        .sparse_matrix(4)
+        .unwrap();
+    for (number_up, number_down) in [(0, 0), (1, 0), (1, 1), (2, 1), (0, 2)] {
+        let reduced = fh
+            .parity_mapping(4, Some((number_up, number_down)))
+            .unwrap();
+        assert!(reduced.current_number_spins() <= 2);
+        assert_eq!(
+            PauliOperator::from(reduced.clone()),
+            FermionOperator::from(fh.clone())
+                .parity_mapping(4, Some((number_up, number_down)))
+                .unwrap()
+        );
+        // The qubits 0 and 2 remain, the qubits 1 and 3 store the spin-up and total parities
+        let full_index = |index: usize| {
+            (index & 1)
+                | ((number_up % 2) << 1)
+                | (((index >> 1) & 1) << 2)
+                | (((number_up + number_down) % 2) << 3)
+        };
+        let reduced_matrix = reduced.sparse_matrix(2).unwrap();
+        for row in 0..4 {
+            for column in 0..4 {
+                let expected = full_matrix
+                    .get(&(full_index(row), full_index(column)))
+                    .copied()
+                    .unwrap_or_default();
+                let value = reduced_matrix
+                    .get(&(row, column))
+                    .copied()
+                    .unwrap_or_default();
+                assert!((expected - value).norm() < 1e-10);
+            }
+        }
+    }
+
+    let hfp = HermitianFermionProduct::new([1], [1]).unwrap();
+    let mut sh = PauliHamiltonian::new();
+    sh.add_operator_product(PauliProduct::new(), 0.5.into())
+        .unwrap();
+    sh.add_operator_product(PauliProduct::new().z(0), 0.5.into())
+        .unwrap();
+    assert_eq!(hfp.parity_mapping(4, Some((1, 0))).unwrap(), sh);
+}
+
+// Test the parity mapping of a FermionLindbladNoiseOperator and a FermionLindbladOpenSystem
+#[test]
+fn parity_fermion_noise_and_open_system() {
+    let mut noise = FermionLindbladNoiseOperator::new();
+    noise
+        .add_operator_product(
+            (
+                FermionProduct::new([0], [1]).unwrap(),
+                FermionProduct::new([0], [1]).unwrap(),
+            ),
+            CalculatorComplex::new(0.5, 0.0),
+        )
+        .unwrap();
+    noise
+        .add_operator_product(
+            (
+                FermionProduct::new([2], [2]).unwrap(),
+                FermionProduct::new([2], [2]).unwrap(),
+            ),
+            2.0.into(),
+        )
+        .unwrap();
+    let full_noise = noise.parity_mapping(4, None).unwrap();
+    assert_eq!(
+        full_noise,
+        noise
+            .jordan_wigner()
+            .conjugate_with_clifford_sequence(&[
+                CliffordGate::ControlledNot {
+                    control: 0,
+                    target: 1
+                },
+                CliffordGate::ControlledNot {
+                    control: 1,
+                    target: 2
+                },
+                CliffordGate::ControlledNot {
+                    control: 2,
+                    target: 3
+                },
+            ])
+            .unwrap()
+    );
+    let reduced_noise = noise.parity_mapping(4, Some((1, 1))).unwrap();
+    assert!(reduced_noise.current_number_spins() <= 2);
+
+    let open_system =
+        FermionLindbladOpenSystem::group(spin_conserving_hamiltonian(), noise.clone()).unwrap();
+    assert_eq!(
+        open_system.parity_mapping(4, Some((1, 1))).unwrap(),
+        PauliLindbladOpenSystem::group(
+            spin_conserving_hamiltonian()
+                .parity_mapping(4, Some((1, 1)))
+                .unwrap(),
+            reduced_noise
+        )
+        .unwrap()
+    );
+}
+
+// Test the parity mapping of noise terms that have an identity on one side after the two-qubit reduction
+#[test]
+fn parity_noise_identity_terms() {
+    // Z_1 of n_0 n_1 is replaced by the spin-up parity, which leaves (I, Z_0) and (Z_0, I) terms
+    let pair = FermionProduct::new([0, 1], [0, 1]).unwrap();
+    let number = FermionProduct::new([0], [0]).unwrap();
+    let noise_with_rate = |rate: CalculatorComplex| {
+        let mut noise = FermionLindbladNoiseOperator::new();
+        noise
+            .add_operator_product((pair.clone(), number.clone()), rate.clone())
+            .unwrap();
+        noise
+            .add_operator_product((number.clone(), pair.clone()), rate.conj())
+            .unwrap();
+        noise
+    };
+
+    // For real rates the terms have no coherent part and are dropped
+    let reduced = noise_with_rate(CalculatorComplex::new(1.0, 0.0))
+        .parity_mapping(4, Some((1, 1)))
+        .unwrap();
+    assert!(reduced
+        .keys()
+        .all(|(left, right)| !left.is_empty() && !right.is_empty()));
+
+    // For imaginary rates the terms act as a Hamiltonian and cannot be dropped
+    assert_eq!(
+        noise_with_rate(CalculatorComplex::new(0.0, 1.0)).parity_mapping(4, Some((1, 1))),
+        Err(StruqtureError::GenericError {
+            msg: "The noise terms with an identity on one side act as a Hamiltonian on 0Z, which cannot be represented in the noise operator.".to_string()
+        })
+    );
+}
+
+// Test the errors of the parity mapping
+#[test]
+fn parity_errors() {
+    let fp = FermionProduct::new([0], [3]).unwrap();
+    assert_eq!(
+        fp.parity_mapping(3, None),
+        Err(StruqtureError::GenericError {
+            msg: "Cannot apply the parity mapping for 3 modes to an object acting on 4 modes."
+                .to_string()
+        })
+    );
+    assert_eq!(
+        fp.parity_mapping(4, Some((1, 0))),
+        Err(StruqtureError::GenericError {
+            msg: "Cannot remove qubit 1 in the two-qubit reduction, the operator does not conserve the parity stored in it.".to_string()
+        })
+    );
+    assert_eq!(
+        FermionProduct::new([0], [0])
+            .unwrap()
+            .parity_mapping(5, Some((1, 0))),
+        Err(StruqtureError::GenericError {
+            msg: "The two-qubit reduction requires a positive even number of modes, got 5."
+                .to_string()
+        })
+    );
+    assert_eq!(
+        FermionProduct::new([0], [0])
+            .unwrap()
+            .parity_mapping(4, Some((3, 0))),
+        Err(StruqtureError::GenericError {
+            msg: "Cannot place 3 spin-up and 0 spin-down fermions in 2 spin-orbitals of each spin."
+                .to_string()
+        })
+    );
+}
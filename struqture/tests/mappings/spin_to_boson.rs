@@ -0,0 +1,216 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
+use struqture::bosons::*;
+use struqture::mappings::SpinToBoson;
+use struqture::prelude::*;
+use struqture::spins::*;
+use struqture::StruqtureError;
+
+/// Asserts that all coefficients of the difference of the BosonOperators vanish.
+fn assert_close(left: BosonOperator, right: BosonOperator) {
+    for (product, value) in (left - right).iter() {
+        assert!(
+            *value.norm().float().unwrap() < 1e-10,
+            "{product} has coefficient {value}"
+        );
+    }
+}
+
+fn boson_operator(terms: &[(&[usize], &[usize], CalculatorComplex)]) -> BosonOperator {
+    let mut bo = BosonOperator::new();
+    for (creators, annihilators, value) in terms {
+        bo.add_operator_product(
+            BosonProduct::new(creators.iter().copied(), annihilators.iter().copied()).unwrap(),
+            value.clone(),
+        )
+        .unwrap();
+    }
+    bo
+}
+
+// Test the Holstein-Primakoff mapping of PauliProducts and PlusMinusProducts
+#[test]
+fn holstein_primakoff_products() {
+    // For spin 1/2 in linear spin-wave theory X -> b + b† and Z -> 1 - 2b†b
+    let pp = PauliProduct::new().x(0).z(1);
+    let expected = boson_operator(&[
+        (&[], &[0], 1.0.into()),
+        (&[0], &[], 1.0.into()),
+        (&[1], &[0, 1], (-2.0).into()),
+        (&[0, 1], &[1], (-2.0).into()),
+    ]);
+    assert_eq!(pp.holstein_primakoff_mapping(0.5, 0).unwrap(), expected);
+
+    let pp = PauliProduct::new().y(2);
+    let expected = boson_operator(&[
+        (&[], &[2], CalculatorComplex::new(0.0, -1.0)),
+        (&[2], &[], CalculatorComplex::new(0.0, 1.0)),
+    ]);
+    assert_eq!(pp.holstein_primakoff_mapping(0.5, 0).unwrap(), expected);
+
+    // S^+ = sqrt(2S) (1 - b†b / 4S + ...) b
+    let pmp = PlusMinusProduct::new().plus(0);
+    let expected = boson_operator(&[
+        (&[], &[0], 2.0_f64.sqrt().into()),
+        (&[0], &[0, 0], (-2.0_f64.sqrt() / 4.0).into()),
+    ]);
+    assert_close(pmp.holstein_primakoff_mapping(1.0, 1).unwrap(), expected);
+
+    // The second order adds -(b†b)^2 b / 32 S^2 = -(b†b†bbb + b†bb) / 32 S^2
+    let pmp = PlusMinusProduct::new().minus(1);
+    let coefficient = 2.0_f64.sqrt();
+    let expected = boson_operator(&[
+        (&[1], &[], coefficient.into()),
+        (&[1, 1], &[1], (-coefficient * (0.25 + 1.0 / 32.0)).into()),
+        (&[1, 1, 1], &[1, 1], (-coefficient / 32.0).into()),
+    ]);
+    assert_close(pmp.holstein_primakoff_mapping(1.0, 2).unwrap(), expected);
+}
+
+// Test the Holstein-Primakoff mapping of a Heisenberg PauliHamiltonian to linear spin-wave theory
+#[test]
+fn holstein_primakoff_heisenberg() {
+    // S_0 S_1 = (X_0 X_1 + Y_0 Y_1 + Z_0 Z_1) / 4 for spin 1/2
+    let mut sh = PauliHamiltonian::new();
+    for pp in [
+        PauliProduct::new().x(0).x(1),
+        PauliProduct::new().y(0).y(1),
+        PauliProduct::new().z(0).z(1),
+    ] {
+        sh.add_operator_product(pp, CalculatorFloat::from(0.25))
+            .unwrap();
+    }
+    // S^2 - S (n_0 + n_1) + S (b_0† b_1 + b_1† b_0) + n_0 n_1
+    let spin = 2.0;
+    let mut expected = BosonHamiltonian::new();
+    for (creators, annihilators, value) in [
+        (vec![], vec![], spin * spin),
+        (vec![0], vec![0], -spin),
+        (vec![1], vec![1], -spin),
+        (vec![0], vec![1], spin),
+        (vec![0, 1], vec![0, 1], 1.0),
+    ] {
+        expected
+            .add_operator_product(
+                HermitianBosonProduct::new(creators, annihilators).unwrap(),
+                value.into(),
+            )
+            .unwrap();
+    }
+    let mapped = sh.holstein_primakoff_mapping(spin, 0).unwrap();
+    assert_eq!(mapped, expected);
+    assert_eq!(
+        BosonOperator::from(mapped),
+        PauliOperator::from(sh)
+            .holstein_primakoff_mapping(spin, 0)
+            .unwrap()
+    );
+}
+
+// Test the Schwinger-boson mapping of PauliProducts and PlusMinusProducts
+#[test]
+fn schwinger_boson_products() {
+    let pp = PauliProduct::new().x(0).z(1);
+    let expected = boson_operator(&[
+        (&[0, 2], &[1, 2], 1.0.into()),
+        (&[1, 2], &[0, 2], 1.0.into()),
+        (&[0, 3], &[1, 3], (-1.0).into()),
+        (&[1, 3], &[0, 3], (-1.0).into()),
+    ]);
+    assert_eq!(pp.schwinger_boson_mapping().unwrap(), expected);
+
+    let pmp = PlusMinusProduct::new().plus(0).minus(1);
+    let expected = boson_operator(&[(&[0, 3], &[1, 2], 1.0.into())]);
+    assert_eq!(pmp.schwinger_boson_mapping().unwrap(), expected);
+}
+
+// Test that the Schwinger-boson mapping conserves the commutation relations of the spin operators
+#[test]
+fn schwinger_boson_commutators() {
+    let x = PauliProduct::new().x(1).schwinger_boson_mapping().unwrap();
+    let y = PauliProduct::new().y(1).schwinger_boson_mapping().unwrap();
+    let z = PauliProduct::new().z(1).schwinger_boson_mapping().unwrap();
+    assert_close(
+        x.clone() * y.clone() - y.clone() * x.clone(),
+        z.clone() * CalculatorComplex::new(0.0, 2.0),
+    );
+    assert_close(
+        y.clone() * z.clone() - z.clone() * y.clone(),
+        x.clone() * CalculatorComplex::new(0.0, 2.0),
+    );
+    assert_close(
+        z.clone() * x.clone() - x * z,
+        y * CalculatorComplex::new(0.0, 2.0),
+    );
+}
+
+// Test the spin to boson mappings of PauliOperators, PlusMinusOperators and PauliHamiltonians
+#[test]
+fn spin_to_boson_operators() {
+    let mut so = PauliOperator::new();
+    so.add_operator_product(
+        PauliProduct::new().x(0).y(1),
+        CalculatorComplex::new(1.0, 0.5),
+    )
+    .unwrap();
+    so.add_operator_product(PauliProduct::new().z(1), (-0.5).into())
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().y(0).z(2), 2.0.into())
+        .unwrap();
+    let pmo = PlusMinusOperator::from(so.clone());
+    assert_close(
+        so.holstein_primakoff_mapping(1.5, 2).unwrap(),
+        pmo.holstein_primakoff_mapping(1.5, 2).unwrap(),
+    );
+    assert_close(
+        so.schwinger_boson_mapping().unwrap(),
+        pmo.schwinger_boson_mapping().unwrap(),
+    );
+
+    let mut sh = PauliHamiltonian::new();
+    sh.add_operator_product(PauliProduct::new().x(0).y(1), CalculatorFloat::from(1.0))
+        .unwrap();
+    sh.add_operator_product(PauliProduct::new().z(0).z(2), CalculatorFloat::from(-0.5))
+        .unwrap();
+    sh.add_operator_product(PauliProduct::new().x(2), CalculatorFloat::from(0.3))
+        .unwrap();
+    let so = PauliOperator::from(sh.clone());
+    assert_close(
+        BosonOperator::from(sh.holstein_primakoff_mapping(1.0, 1).unwrap()),
+        so.holstein_primakoff_mapping(1.0, 1).unwrap(),
+    );
+    assert_close(
+        BosonOperator::from(sh.schwinger_boson_mapping().unwrap()),
+        so.schwinger_boson_mapping().unwrap(),
+    );
+}
+
+// Test the errors of the Holstein-Primakoff mapping
+#[test]
+fn holstein_primakoff_errors() {
+    let pp = PauliProduct::new().z(0);
+    assert_eq!(
+        pp.holstein_primakoff_mapping(0.3, 1),
+        Err(StruqtureError::GenericError {
+            msg: "The spin of the Holstein-Primakoff mapping must be a positive multiple of 1/2, got 0.3.".to_string()
+        })
+    );
+    assert!(pp.holstein_primakoff_mapping(0.0, 1).is_err());
+    assert!(PauliOperator::new()
+        .holstein_primakoff_mapping(-1.0, 0)
+        .is_err());
+    assert!(PauliHamiltonian::new()
+        .holstein_primakoff_mapping(f64::NAN, 0)
+        .is_err());
+}
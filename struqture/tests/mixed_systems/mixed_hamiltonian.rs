@@ -12,7 +12,7 @@
 
 //! Integration test for public API of MixedHamiltonian
 
-// use num_complex::Complex64;
+use super::{assert_sparse_matrices_close, dense_lindblad_superoperator, dense_matrix};
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde_test::{assert_tokens, Configure, Token};
 use std::collections::BTreeMap;
@@ -20,9 +20,11 @@ use std::collections::HashMap;
 #[cfg(feature = "struqture_1_import")]
 #[cfg(feature = "struqture_1_export")]
 use std::str::FromStr;
-use struqture::bosons::BosonProduct;
+use struqture::bosons::{BosonCutoff, BosonProduct};
 use struqture::fermions::FermionProduct;
-use struqture::mixed_systems::{HermitianMixedProduct, MixedHamiltonian, MixedOperator};
+use struqture::mixed_systems::{
+    HermitianMixedProduct, MixedHamiltonian, MixedHilbertSpace, MixedOperator, MixedProduct,
+};
 use struqture::prelude::*;
 use struqture::spins::PauliProduct;
 use struqture::STRUQTURE_VERSION;
@@ -792,6 +794,286 @@ fn serde_compact() {
     );
 }
 
+// Test the commutator of a MixedHamiltonian with a complex coefficient of a term that is not naturally hermitian
+#[test]
+fn commutator_complex_coefficient() {
+    let mut hamiltonian = MixedHamiltonian::new(1, 1, 0);
+    hamiltonian
+        .set(
+            HermitianMixedProduct::new(
+                [PauliProduct::new().z(0)],
+                [BosonProduct::new([], [0]).unwrap()],
+                [],
+            )
+            .unwrap(),
+            CalculatorComplex::new(1.0, 0.5),
+        )
+        .unwrap();
+    let mut number = MixedHamiltonian::new(1, 1, 0);
+    number
+        .set(
+            HermitianMixedProduct::new(
+                [PauliProduct::new()],
+                [BosonProduct::new([0], [0]).unwrap()],
+                [],
+            )
+            .unwrap(),
+            1.0.into(),
+        )
+        .unwrap();
+
+    // The hermitian conjugate term has the complex conjugate coefficient
+    let product = MixedProduct::new(
+        [PauliProduct::new().z(0)],
+        [BosonProduct::new([], [0]).unwrap()],
+        [],
+    )
+    .unwrap();
+    let (conjugate, sign) = product.hermitian_conjugate();
+    let mut operator = MixedOperator::new(1, 1, 0);
+    operator
+        .add_operator_product(product, CalculatorComplex::new(1.0, 0.5))
+        .unwrap();
+    operator
+        .add_operator_product(conjugate, CalculatorComplex::new(1.0, -0.5) * sign)
+        .unwrap();
+    let mut number_operator = MixedOperator::new(1, 1, 0);
+    number_operator
+        .add_operator_product(
+            MixedProduct::new(
+                [PauliProduct::new()],
+                [BosonProduct::new([0], [0]).unwrap()],
+                [],
+            )
+            .unwrap(),
+            1.0.into(),
+        )
+        .unwrap();
+
+    assert_eq!(
+        hamiltonian.commutator(&number).unwrap(),
+        operator.commutator(&number_operator).unwrap()
+    );
+}
+
+// Test the commutator and anticommutator functions of the MixedHamiltonian
+#[test]
+fn commutator_anticommutator() {
+    let mut left = MixedHamiltonian::new(1, 1, 1);
+    left.set(
+        HermitianMixedProduct::new(
+            [PauliProduct::new().x(0)],
+            [BosonProduct::new([0], [1]).unwrap()],
+            [FermionProduct::new([0], [0]).unwrap()],
+        )
+        .unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    let mut right = MixedHamiltonian::new(1, 1, 1);
+    right
+        .set(
+            HermitianMixedProduct::new(
+                [PauliProduct::new().z(0)],
+                [BosonProduct::new([1], [1]).unwrap()],
+                [FermionProduct::new([1], [2]).unwrap()],
+            )
+            .unwrap(),
+            CalculatorComplex::from(0.5),
+        )
+        .unwrap();
+
+    let left_right = (left.clone() * right.clone()).unwrap();
+    let right_left = (right.clone() * left.clone()).unwrap();
+    assert_eq!(
+        left.commutator(&right).unwrap(),
+        (left_right.clone() - right_left.clone()).unwrap()
+    );
+    assert_eq!(
+        left.anticommutator(&right).unwrap(),
+        (left_right + right_left).unwrap()
+    );
+}
+
+// Test the sparse_matrix and sparse_matrix_superoperator functions of the MixedHamiltonian
+#[test]
+fn sparse_matrix() {
+    let spins = [PauliProduct::new().y(0)];
+    let bosons = [BosonProduct::new([0], [1]).unwrap()];
+    let fermions = [FermionProduct::new([0], [0]).unwrap()];
+    let mut mh = MixedHamiltonian::new(1, 1, 1);
+    mh.set(
+        HermitianMixedProduct::new(spins.clone(), bosons.clone(), fermions.clone()).unwrap(),
+        CalculatorComplex::new(0.3, 0.4),
+    )
+    .unwrap();
+    mh.set(
+        HermitianMixedProduct::new(
+            [PauliProduct::new().z(0)],
+            [BosonProduct::new([1], [1]).unwrap()],
+            [FermionProduct::new([], []).unwrap()],
+        )
+        .unwrap(),
+        CalculatorComplex::from(-0.7),
+    )
+    .unwrap();
+
+    // The hermitian conjugate of a term has the complex conjugate coefficient
+    let mut mo = MixedOperator::new(1, 1, 1);
+    mo.set(
+        MixedProduct::new(spins.clone(), bosons, fermions.clone()).unwrap(),
+        CalculatorComplex::new(0.3, 0.4),
+    )
+    .unwrap();
+    mo.set(
+        MixedProduct::new(spins, [BosonProduct::new([1], [0]).unwrap()], fermions).unwrap(),
+        CalculatorComplex::new(0.3, -0.4),
+    )
+    .unwrap();
+    mo.set(
+        MixedProduct::new(
+            [PauliProduct::new().z(0)],
+            [BosonProduct::new([1], [1]).unwrap()],
+            [FermionProduct::new([], []).unwrap()],
+        )
+        .unwrap(),
+        CalculatorComplex::from(-0.7),
+    )
+    .unwrap();
+
+    let space =
+        MixedHilbertSpace::new(vec![1], vec![(2, BosonCutoff::Uniform(2))], vec![1]).unwrap();
+    let matrix = mh.sparse_matrix(&space).unwrap();
+    assert_sparse_matrices_close(&matrix, &mo.sparse_matrix(&space).unwrap());
+    for ((row, column), value) in matrix.iter() {
+        let transposed = matrix.get(&(*column, *row)).copied().unwrap_or_default();
+        assert!((value - transposed.conj()).norm() < 1e-12);
+    }
+    assert_eq!(mh.sparse_matrix_coo(&space).unwrap().0.len(), matrix.len());
+
+    let hamiltonian_matrix = dense_matrix(&matrix, space.dimension());
+    assert_sparse_matrices_close(
+        &mh.sparse_matrix_superoperator(&space).unwrap(),
+        &dense_lindblad_superoperator(&hamiltonian_matrix, &[]),
+    );
+    let too_small =
+        MixedHilbertSpace::new(vec![1], vec![(1, BosonCutoff::Uniform(2))], vec![1]).unwrap();
+    assert!(mh.sparse_matrix(&too_small).is_err());
+}
+
+// Test the conserved quantity functions of the MixedHamiltonian
+#[test]
+fn conserved_quantities() {
+    let number = BosonProduct::new([0], [0]).unwrap();
+    let fermion_number = FermionProduct::new([0], [0]).unwrap();
+    let mut hamiltonian = MixedHamiltonian::new(1, 1, 1);
+    for spins in [PauliProduct::new().x(0).x(1), PauliProduct::new().y(0).y(1)] {
+        hamiltonian
+            .add_operator_product(
+                HermitianMixedProduct::new([spins], [number.clone()], [fermion_number.clone()])
+                    .unwrap(),
+                1.0.into(),
+            )
+            .unwrap();
+    }
+    assert_eq!(hamiltonian.conserves_total_sz(), vec![true]);
+    assert_eq!(hamiltonian.conserves_z_parity(), vec![true]);
+    assert_eq!(hamiltonian.conserves_bosonic_particle_number(), vec![true]);
+    assert_eq!(hamiltonian.conserves_bosonic_parity(), vec![true]);
+    assert_eq!(
+        hamiltonian.conserves_fermionic_particle_number(),
+        vec![true]
+    );
+    assert_eq!(hamiltonian.conserves_fermionic_parity(), vec![true]);
+    assert_eq!(hamiltonian.conserves_fermionic_sz(), vec![true]);
+
+    // Displacement of the bosons and singlet pairing of the fermions
+    hamiltonian
+        .add_operator_product(
+            HermitianMixedProduct::new(
+                [PauliProduct::new().z(0)],
+                [BosonProduct::new([], [0]).unwrap()],
+                [FermionProduct::new([], [0, 1]).unwrap()],
+            )
+            .unwrap(),
+            0.5.into(),
+        )
+        .unwrap();
+    assert_eq!(hamiltonian.conserves_total_sz(), vec![true]);
+    assert_eq!(hamiltonian.conserves_z_parity(), vec![true]);
+    assert_eq!(hamiltonian.conserves_bosonic_particle_number(), vec![false]);
+    assert_eq!(hamiltonian.conserves_bosonic_parity(), vec![false]);
+    assert_eq!(
+        hamiltonian.conserves_fermionic_particle_number(),
+        vec![false]
+    );
+    assert_eq!(hamiltonian.conserves_fermionic_parity(), vec![true]);
+    assert_eq!(hamiltonian.conserves_fermionic_sz(), vec![true]);
+
+    // Spin flip in the spin and the fermion subsystem
+    hamiltonian
+        .add_operator_product(
+            HermitianMixedProduct::new(
+                [PauliProduct::new().x(0)],
+                [BosonProduct::new([], []).unwrap()],
+                [FermionProduct::new([0], [1]).unwrap()],
+            )
+            .unwrap(),
+            0.5.into(),
+        )
+        .unwrap();
+    assert_eq!(hamiltonian.conserves_total_sz(), vec![false]);
+    assert_eq!(hamiltonian.conserves_z_parity(), vec![false]);
+    assert_eq!(
+        hamiltonian.conserves_fermionic_particle_number(),
+        vec![false]
+    );
+    assert_eq!(hamiltonian.conserves_fermionic_parity(), vec![true]);
+    assert_eq!(hamiltonian.conserves_fermionic_sz(), vec![false]);
+
+    // The quantities are checked separately in every subsystem
+    let mut hamiltonian = MixedHamiltonian::new(2, 0, 0);
+    hamiltonian
+        .add_operator_product(
+            HermitianMixedProduct::new(
+                [PauliProduct::new().x(0).x(1), PauliProduct::new().z(0)],
+                [],
+                [],
+            )
+            .unwrap(),
+            1.0.into(),
+        )
+        .unwrap();
+    assert_eq!(hamiltonian.conserves_total_sz(), vec![false, true]);
+    assert_eq!(hamiltonian.conserves_z_parity(), vec![true, true]);
+    assert!(hamiltonian.conserves_bosonic_particle_number().is_empty());
+    assert!(hamiltonian.conserves_fermionic_sz().is_empty());
+}
+
+// Test the conserves_total_sz function of the MixedHamiltonian with symbolic coefficients
+#[test]
+fn conserves_total_sz_symbolic() {
+    let annihilator = BosonProduct::new([], [0]).unwrap();
+    let mut hamiltonian = MixedHamiltonian::new(1, 1, 0);
+    for spins in [PauliProduct::new().x(0).x(1), PauliProduct::new().y(0).y(1)] {
+        hamiltonian
+            .add_operator_product(
+                HermitianMixedProduct::new([spins], [annihilator.clone()], []).unwrap(),
+                CalculatorComplex::new("J", "K"),
+            )
+            .unwrap();
+    }
+    assert_eq!(hamiltonian.conserves_total_sz(), vec![true]);
+
+    hamiltonian
+        .set(
+            HermitianMixedProduct::new([PauliProduct::new().y(0).y(1)], [annihilator], []).unwrap(),
+            CalculatorComplex::new("J", "L"),
+        )
+        .unwrap();
+    assert_eq!(hamiltonian.conserves_total_sz(), vec![false]);
+}
+
 #[cfg(feature = "json_schema")]
 #[test]
 fn test_mixed_hamiltonian_schema() {
@@ -12,17 +12,20 @@
 
 //! Integration test for public API of MixedLindbladOpenSystem
 
+use super::{assert_sparse_matrices_close, dense_lindblad_superoperator};
+use nalgebra::DMatrix;
+use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde_test::{assert_tokens, Configure, Token};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 #[cfg(feature = "struqture_1_import")]
 #[cfg(feature = "struqture_1_export")]
 use std::str::FromStr;
-use struqture::bosons::BosonProduct;
+use struqture::bosons::{BosonCutoff, BosonProduct};
 use struqture::fermions::FermionProduct;
 use struqture::mixed_systems::{
-    HermitianMixedProduct, MixedDecoherenceProduct, MixedHamiltonian, MixedLindbladNoiseOperator,
-    MixedLindbladOpenSystem,
+    HermitianMixedProduct, MixedDecoherenceProduct, MixedHamiltonian, MixedHilbertSpace,
+    MixedLindbladNoiseOperator, MixedLindbladOpenSystem,
 };
 use struqture::spins::{DecoherenceProduct, PauliProduct};
 use struqture::{prelude::*, STRUQTURE_VERSION};
@@ -1264,6 +1267,168 @@ fn test_truncate() {
     assert_eq!(test_system2, comparison_system2);
 }
 
+// Test the substitute_parameters function of the MixedLindbladOpenSystem
+#[test]
+fn test_substitute_parameters() {
+    let pp: HermitianMixedProduct = HermitianMixedProduct::new(
+        [PauliProduct::new().z(0)],
+        [BosonProduct::new([0], [1]).unwrap()],
+        [FermionProduct::new([0], [0]).unwrap()],
+    )
+    .unwrap();
+    let dp: MixedDecoherenceProduct = MixedDecoherenceProduct::new(
+        [DecoherenceProduct::new().z(0)],
+        [BosonProduct::new([], [1]).unwrap()],
+        [FermionProduct::new([], [0]).unwrap()],
+    )
+    .unwrap();
+    let mut system = MixedLindbladOpenSystem::new(1, 1, 1);
+    system
+        .system_mut()
+        .set(pp.clone(), CalculatorComplex::from("2 * j"))
+        .unwrap();
+    system
+        .noise_mut()
+        .set((dp.clone(), dp.clone()), CalculatorComplex::from("gamma"))
+        .unwrap();
+
+    let mut comparison = MixedLindbladOpenSystem::new(1, 1, 1);
+    comparison
+        .system_mut()
+        .set(pp, CalculatorComplex::from(1.0))
+        .unwrap();
+
+    let parameters = HashMap::from([("j".to_string(), 0.5), ("gamma".to_string(), 0.0)]);
+    assert_eq!(
+        system.substitute_parameters(&parameters).unwrap(),
+        comparison
+    );
+}
+
+// Test the free_parameters function of the MixedLindbladOpenSystem
+#[test]
+fn test_free_parameters() {
+    let pp: HermitianMixedProduct = HermitianMixedProduct::new(
+        [PauliProduct::new().z(0)],
+        [BosonProduct::new([0], [1]).unwrap()],
+        [FermionProduct::new([0], [0]).unwrap()],
+    )
+    .unwrap();
+    let dp: MixedDecoherenceProduct = MixedDecoherenceProduct::new(
+        [DecoherenceProduct::new().z(0)],
+        [BosonProduct::new([], [1]).unwrap()],
+        [FermionProduct::new([], [0]).unwrap()],
+    )
+    .unwrap();
+    let mut system = MixedLindbladOpenSystem::new(1, 1, 1);
+    system
+        .system_mut()
+        .set(pp, CalculatorComplex::from("2 * j"))
+        .unwrap();
+    system
+        .noise_mut()
+        .set((dp.clone(), dp), CalculatorComplex::from("gamma"))
+        .unwrap();
+
+    assert_eq!(
+        system.system().free_parameters(),
+        BTreeSet::from(["j".to_string()])
+    );
+    assert_eq!(
+        system.noise().free_parameters(),
+        BTreeSet::from(["gamma".to_string()])
+    );
+    assert_eq!(
+        system.free_parameters(),
+        BTreeSet::from(["gamma".to_string(), "j".to_string()])
+    );
+}
+
+// Test the sparse_matrix_superoperator function of the MixedLindbladOpenSystem
+#[test]
+fn sparse_matrix_superoperator() {
+    let mut system = MixedLindbladOpenSystem::new(1, 1, 1);
+    system
+        .system_mut()
+        .set(
+            HermitianMixedProduct::new(
+                [PauliProduct::new().x(0)],
+                [BosonProduct::new([0], [0]).unwrap()],
+                [FermionProduct::new([0], [0]).unwrap()],
+            )
+            .unwrap(),
+            CalculatorComplex::from(0.6),
+        )
+        .unwrap();
+    let left = MixedDecoherenceProduct::new(
+        [DecoherenceProduct::new().iy(0)],
+        [BosonProduct::new([], [0]).unwrap()],
+        [FermionProduct::new([], [0]).unwrap()],
+    )
+    .unwrap();
+    let right = MixedDecoherenceProduct::new(
+        [DecoherenceProduct::new().z(0)],
+        [BosonProduct::new([], [0]).unwrap()],
+        [FermionProduct::new([], []).unwrap()],
+    )
+    .unwrap();
+    system
+        .noise_mut()
+        .set((left, right), CalculatorComplex::new(0.3, 0.1))
+        .unwrap();
+
+    let space =
+        MixedHilbertSpace::new(vec![1], vec![(1, BosonCutoff::Uniform(2))], vec![1]).unwrap();
+    let zero = Complex64::new(0.0, 0.0);
+    let one = Complex64::new(1.0, 0.0);
+    let sqrt_2 = Complex64::new(2.0_f64.sqrt(), 0.0);
+    let identity_2 = DMatrix::<Complex64>::identity(2, 2);
+    let spin_x = DMatrix::from_row_slice(2, 2, &[zero, one, one, zero]);
+    let spin_iy = DMatrix::from_row_slice(2, 2, &[zero, one, -one, zero]);
+    let spin_z = DMatrix::from_row_slice(2, 2, &[one, zero, zero, -one]);
+    let annihilator = DMatrix::from_row_slice(
+        3,
+        3,
+        &[zero, one, zero, zero, zero, sqrt_2, zero, zero, zero],
+    );
+    let number = annihilator.adjoint() * &annihilator;
+    let fermion_annihilator = DMatrix::from_row_slice(2, 2, &[zero, one, zero, zero]);
+    let fermion_number = fermion_annihilator.adjoint() * &fermion_annihilator;
+
+    let hamiltonian =
+        fermion_number.kronecker(&number.kronecker(&spin_x)) * Complex64::new(0.6, 0.0);
+    let left_matrix = fermion_annihilator.kronecker(&annihilator.kronecker(&spin_iy));
+    let right_matrix = identity_2.kronecker(&annihilator.kronecker(&spin_z));
+    assert_sparse_matrices_close(
+        &system.sparse_matrix_superoperator(&space).unwrap(),
+        &dense_lindblad_superoperator(
+            &hamiltonian,
+            &[(
+                left_matrix.clone(),
+                right_matrix.clone(),
+                Complex64::new(0.3, 0.1),
+            )],
+        ),
+    );
+    assert_sparse_matrices_close(
+        &system.noise().sparse_matrix_superoperator(&space).unwrap(),
+        &dense_lindblad_superoperator(
+            &DMatrix::<Complex64>::zeros(12, 12),
+            &[(left_matrix, right_matrix, Complex64::new(0.3, 0.1))],
+        ),
+    );
+    assert_eq!(
+        system
+            .sparse_matrix_superoperator_coo(&space)
+            .unwrap()
+            .0
+            .len(),
+        system.sparse_matrix_superoperator(&space).unwrap().len()
+    );
+    let mismatched = MixedHilbertSpace::new(vec![1], vec![], vec![1]).unwrap();
+    assert!(system.sparse_matrix_superoperator(&mismatched).is_err());
+}
+
 #[cfg(feature = "json_schema")]
 #[test]
 fn test_mixed_open_system_schema() {
@@ -12,22 +12,23 @@
 
 //! Integration test for public API of MixedOperator
 
-// use num_complex::Complex64;
+use super::{assert_sparse_matrices_close, dense_lindblad_superoperator, dense_matrix};
+use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde_test::{assert_tokens, Configure, Token};
 use std::collections::BTreeMap;
 use std::collections::HashMap;
-use struqture::bosons::BosonProduct;
-use struqture::fermions::FermionProduct;
+use struqture::bosons::{BosonCutoff, BosonOperator, BosonProduct};
+use struqture::fermions::{FermionOperator, FermionProduct};
 use struqture::prelude::*;
-use struqture::spins::PauliProduct;
+use struqture::spins::{PauliOperator, PauliProduct};
 use struqture::StruqtureError;
 use struqture::STRUQTURE_VERSION;
 
 #[cfg(feature = "struqture_1_import")]
 #[cfg(feature = "struqture_1_export")]
 use std::str::FromStr;
-use struqture::mixed_systems::{MixedOperator, MixedProduct};
+use struqture::mixed_systems::{MixedHilbertSpace, MixedOperator, MixedProduct};
 use struqture::OperateOnDensityMatrix;
 use struqture::SpinIndex;
 use test_case::test_case;
@@ -814,6 +815,176 @@ fn serde_compact() {
     );
 }
 
+// Test the commutator and anticommutator functions of the MixedOperator
+#[test]
+fn commutator_anticommutator() {
+    let mut left = MixedOperator::new(1, 1, 1);
+    left.set(
+        MixedProduct::new(
+            [PauliProduct::new().x(0)],
+            [BosonProduct::new([0], [1]).unwrap()],
+            [FermionProduct::new([0], []).unwrap()],
+        )
+        .unwrap(),
+        CalculatorComplex::new(1.0, 0.5),
+    )
+    .unwrap();
+    left.set(
+        MixedProduct::new(
+            [PauliProduct::new().z(1)],
+            [BosonProduct::new([], []).unwrap()],
+            [FermionProduct::new([1], [1]).unwrap()],
+        )
+        .unwrap(),
+        CalculatorComplex::from(0.3),
+    )
+    .unwrap();
+    let mut right = MixedOperator::new(1, 1, 1);
+    right
+        .set(
+            MixedProduct::new(
+                [PauliProduct::new().y(0)],
+                [BosonProduct::new([1], []).unwrap()],
+                [FermionProduct::new([], [0]).unwrap()],
+            )
+            .unwrap(),
+            CalculatorComplex::from(1.5),
+        )
+        .unwrap();
+    right
+        .set(
+            MixedProduct::new(
+                [PauliProduct::new().x(1)],
+                [BosonProduct::new([2], [2]).unwrap()],
+                [FermionProduct::new([2], []).unwrap()],
+            )
+            .unwrap(),
+            CalculatorComplex::new(0.0, 2.0),
+        )
+        .unwrap();
+
+    let left_right = (left.clone() * right.clone()).unwrap();
+    let right_left = (right.clone() * left.clone()).unwrap();
+    assert_eq!(
+        left.commutator(&right).unwrap(),
+        (left_right.clone() - right_left.clone()).unwrap()
+    );
+    assert_eq!(
+        left.anticommutator(&right).unwrap(),
+        (left_right + right_left).unwrap()
+    );
+
+    let other = MixedOperator::new(2, 1, 1);
+    assert_eq!(
+        left.commutator(&other),
+        Err(StruqtureError::MismatchedNumberSubsystems {
+            target_number_spin_subsystems: 1,
+            target_number_boson_subsystems: 1,
+            target_number_fermion_subsystems: 1,
+            actual_number_spin_subsystems: 2,
+            actual_number_boson_subsystems: 1,
+            actual_number_fermion_subsystems: 1,
+        })
+    );
+}
+
+// Test the sparse_matrix and sparse_matrix_superoperator functions of the MixedOperator
+#[test]
+fn sparse_matrix() {
+    let spin = PauliProduct::new().x(0).z(1);
+    let boson = BosonProduct::new([0], [1]).unwrap();
+    let fermion = FermionProduct::new([0], [1]).unwrap();
+    let mut mo = MixedOperator::new(1, 1, 1);
+    mo.set(
+        MixedProduct::new([spin.clone()], [boson.clone()], [fermion.clone()]).unwrap(),
+        CalculatorComplex::new(0.5, -0.2),
+    )
+    .unwrap();
+    let space = MixedHilbertSpace::new(
+        vec![2],
+        vec![(2, BosonCutoff::PerMode(vec![1, 2]))],
+        vec![2],
+    )
+    .unwrap();
+    assert_eq!(space.subsystem_dimensions(), vec![4, 6, 4]);
+    assert_eq!(space.dimension(), 96);
+
+    // The matrix is the Kronecker product of the subsystem matrices with the first subsystem changing fastest
+    let mut spin_operator = PauliOperator::new();
+    spin_operator
+        .set(spin, CalculatorComplex::from(1.0))
+        .unwrap();
+    let mut boson_operator = BosonOperator::new();
+    boson_operator
+        .set(boson, CalculatorComplex::from(1.0))
+        .unwrap();
+    let mut fermion_operator = FermionOperator::new();
+    fermion_operator
+        .set(fermion, CalculatorComplex::from(1.0))
+        .unwrap();
+    let spin_matrix = dense_matrix(&spin_operator.sparse_matrix(2).unwrap(), 4);
+    let boson_matrix = dense_matrix(&boson_operator.sparse_matrix(2, vec![1, 2]).unwrap(), 6);
+    let fermion_matrix = dense_matrix(&fermion_operator.sparse_matrix(2, None).unwrap(), 4);
+    let expected =
+        fermion_matrix.kronecker(&boson_matrix.kronecker(&spin_matrix)) * Complex64::new(0.5, -0.2);
+    let matrix = mo.sparse_matrix(&space).unwrap();
+    assert_eq!(matrix.len(), 8);
+    assert_sparse_matrices_close(&matrix, &super::sparse_matrix(&expected));
+    assert_eq!(mo.sparse_matrix_coo(&space).unwrap().0.len(), matrix.len());
+
+    let mut small = MixedOperator::new(1, 1, 1);
+    small
+        .set(
+            MixedProduct::new(
+                [PauliProduct::new().x(0)],
+                [BosonProduct::new([0], []).unwrap()],
+                [FermionProduct::new([], [0]).unwrap()],
+            )
+            .unwrap(),
+            CalculatorComplex::new(0.5, -0.2),
+        )
+        .unwrap();
+    let small_space =
+        MixedHilbertSpace::new(vec![1], vec![(1, BosonCutoff::Uniform(1))], vec![1]).unwrap();
+    let small_matrix = dense_matrix(&small.sparse_matrix(&small_space).unwrap(), 8);
+    assert_sparse_matrices_close(
+        &small.sparse_matrix_superoperator(&small_space).unwrap(),
+        &dense_lindblad_superoperator(&small_matrix, &[]),
+    );
+    assert_eq!(
+        small
+            .sparse_matrix_superoperator_coo(&small_space)
+            .unwrap()
+            .0
+            .len(),
+        small
+            .sparse_matrix_superoperator(&small_space)
+            .unwrap()
+            .len()
+    );
+
+    let too_small =
+        MixedHilbertSpace::new(vec![2], vec![(2, BosonCutoff::Uniform(1))], vec![1]).unwrap();
+    assert!(mo.sparse_matrix(&too_small).is_err());
+    let mismatched =
+        MixedHilbertSpace::new(vec![2, 1], vec![(2, BosonCutoff::Uniform(1))], vec![2]).unwrap();
+    assert_eq!(
+        mo.sparse_matrix_superoperator(&mismatched),
+        Err(StruqtureError::MismatchedNumberSubsystems {
+            target_number_spin_subsystems: 2,
+            target_number_boson_subsystems: 1,
+            target_number_fermion_subsystems: 1,
+            actual_number_spin_subsystems: 1,
+            actual_number_boson_subsystems: 1,
+            actual_number_fermion_subsystems: 1,
+        })
+    );
+    assert!(
+        MixedHilbertSpace::new(vec![1], vec![(2, BosonCutoff::PerMode(vec![1]))], vec![]).is_err()
+    );
+    assert!(MixedHilbertSpace::new(vec![40], vec![], vec![40]).is_err());
+}
+
 #[cfg(feature = "json_schema")]
 #[test]
 fn test_mixed_operator_schema() {
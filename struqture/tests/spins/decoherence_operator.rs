@@ -12,14 +12,17 @@
 
 //! Integration test for public API of DecoherenceOperator
 
+use super::{apply_sparse_matrix_to_state, create_test_state};
+use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
-// use num_complex::Complex64;
 use serde_test::{assert_tokens, Configure, Token};
 use std::collections::BTreeMap;
 use std::iter::{FromIterator, IntoIterator};
 use std::ops::{Add, Sub};
 use std::str::FromStr;
-use struqture::spins::{DecoherenceOperator, DecoherenceProduct, PauliOperator, PauliProduct};
+use struqture::spins::{
+    DecoherenceOperator, DecoherenceProduct, PauliOperator, PauliProduct, PlusMinusOperator,
+};
 use struqture::SpinIndex;
 use struqture::{prelude::*, STRUQTURE_VERSION};
 use test_case::test_case;
@@ -622,6 +625,33 @@ fn serde_compact() {
     );
 }
 
+// Test the apply_to_state and apply_to_state_into functions of the DecoherenceOperator
+#[test]
+fn apply_to_state() {
+    let mut so = DecoherenceOperator::new();
+    so.set(
+        DecoherenceProduct::new().iy(0).z(1),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    so.set(
+        DecoherenceProduct::new().x(1).iy(2),
+        CalculatorComplex::new(0.3, 0.4),
+    )
+    .unwrap();
+
+    let state = create_test_state(3);
+    let pauli_operator = PauliOperator::from(PlusMinusOperator::from(so.clone()));
+    let expected = apply_sparse_matrix_to_state(&pauli_operator, &state);
+    let result = so.apply_to_state(&state).unwrap();
+    for (value, expected_value) in result.iter().zip(expected.iter()) {
+        assert!((value - expected_value).norm() < 1e-12);
+    }
+    let mut buffer = vec![Complex64::new(1.0, 1.0); state.len()];
+    so.apply_to_state_into(&state, &mut buffer).unwrap();
+    assert_eq!(buffer, result);
+}
+
 #[cfg(feature = "json_schema")]
 #[test]
 fn test_decoherence_operator_schema() {
@@ -23,6 +23,10 @@ mod decoherence_operator;
 
 mod pauli_operator;
 
+mod packed_pauli_product;
+
+mod packed_pauli_operator;
+
 mod plus_minus_operator;
 
 mod pauli_hamiltonian;
@@ -33,6 +37,8 @@ mod plus_minus_noise_operator;
 
 mod pauli_open_system;
 
+mod z2_symmetries;
+
 fn create_na_matrix_from_operator_list(operators: &[&str]) -> na::DMatrix<Complex64> {
     let cc1 = Complex64::new(1.0, 0.0);
     let cc0 = Complex64::new(0.0, 0.0);
@@ -91,3 +97,122 @@ fn create_na_matrix_from_decoherence_list(operators: &[&str]) -> na::DMatrix<Com
     }
     matrix
 }
+
+/// Applies the sparse matrix of a PauliOperator to a dense state vector.
+fn apply_sparse_matrix_to_state(
+    operator: &struqture::spins::PauliOperator,
+    state: &[Complex64],
+) -> Vec<Complex64> {
+    use struqture::spins::ToSparseMatrixOperator;
+    let number_spins = state.len().trailing_zeros() as usize;
+    let mut result = vec![Complex64::new(0.0, 0.0); state.len()];
+    for ((row, column), value) in operator.sparse_matrix(number_spins).unwrap() {
+        result[row] += value * state[column];
+    }
+    result
+}
+
+/// Creates a dense test state vector with distinct complex amplitudes.
+fn create_test_state(number_spins: usize) -> Vec<Complex64> {
+    (0..2usize.pow(number_spins as u32))
+        .map(|index| Complex64::new(1.0 + index as f64, 0.5 - 0.25 * index as f64))
+        .collect()
+}
+
+/// Applies the sparse superoperator of a spin system to a dense density matrix.
+fn apply_sparse_superoperator_to_density_matrix<'a, T>(
+    system: &'a T,
+    density_matrix: &ndarray::Array2<Complex64>,
+) -> ndarray::Array2<Complex64>
+where
+    T: struqture::spins::ToSparseMatrixSuperOperator<'a>,
+{
+    let dimension = density_matrix.nrows();
+    let number_spins = dimension.trailing_zeros() as usize;
+    let mut result = ndarray::Array2::zeros((dimension, dimension));
+    for ((row, column), value) in system.sparse_matrix_superoperator(number_spins).unwrap() {
+        result[(row / dimension, row % dimension)] +=
+            value * density_matrix[(column / dimension, column % dimension)];
+    }
+    result
+}
+
+/// Creates a dense test density matrix with distinct complex entries.
+fn create_test_density_matrix(number_spins: usize) -> ndarray::Array2<Complex64> {
+    let dimension = 2usize.pow(number_spins as u32);
+    ndarray::Array2::from_shape_fn((dimension, dimension), |(row, column)| {
+        Complex64::new(
+            1.0 + (row * dimension + column) as f64,
+            0.5 - 0.25 * row as f64,
+        )
+    })
+}
+
+/// Creates the dense little endian matrix of a Clifford gate from its expansion in Pauli matrices.
+fn create_clifford_gate_matrix(
+    gate: &struqture::spins::CliffordGate,
+    number_spins: usize,
+) -> ndarray::Array2<Complex64> {
+    use struqture::prelude::*;
+    use struqture::spins::{CliffordGate, PauliOperator, PauliProduct, ToSparseMatrixOperator};
+    let half = Complex64::new(0.5, 0.0);
+    let terms: Vec<(PauliProduct, Complex64)> = match *gate {
+        CliffordGate::Hadamard(qubit) => vec![
+            (
+                PauliProduct::new().x(qubit),
+                Complex64::new(0.5f64.sqrt(), 0.0),
+            ),
+            (
+                PauliProduct::new().z(qubit),
+                Complex64::new(0.5f64.sqrt(), 0.0),
+            ),
+        ],
+        CliffordGate::S(qubit) => vec![
+            (PauliProduct::new(), Complex64::new(0.5, 0.5)),
+            (PauliProduct::new().z(qubit), Complex64::new(0.5, -0.5)),
+        ],
+        CliffordGate::SDagger(qubit) => vec![
+            (PauliProduct::new(), Complex64::new(0.5, -0.5)),
+            (PauliProduct::new().z(qubit), Complex64::new(0.5, 0.5)),
+        ],
+        CliffordGate::ControlledNot { control, target } => vec![
+            (PauliProduct::new(), half),
+            (PauliProduct::new().z(control), half),
+            (PauliProduct::new().x(target), half),
+            (PauliProduct::new().z(control).x(target), -half),
+        ],
+        CliffordGate::ControlledZ(first, second) => vec![
+            (PauliProduct::new(), half),
+            (PauliProduct::new().z(first), half),
+            (PauliProduct::new().z(second), half),
+            (PauliProduct::new().z(first).z(second), -half),
+        ],
+        CliffordGate::Swap(first, second) => vec![
+            (PauliProduct::new(), half),
+            (PauliProduct::new().x(first).x(second), half),
+            (PauliProduct::new().y(first).y(second), half),
+            (PauliProduct::new().z(first).z(second), half),
+        ],
+    };
+    let mut operator = PauliOperator::new();
+    for (product, value) in terms {
+        operator
+            .add_operator_product(product, value.into())
+            .unwrap();
+    }
+    operator.dense_matrix(number_spins).unwrap()
+}
+
+/// Creates the dense little endian matrix of a PauliProduct.
+fn pauli_product_matrix(
+    product: &struqture::spins::PauliProduct,
+    number_spins: usize,
+) -> ndarray::Array2<Complex64> {
+    use struqture::prelude::*;
+    use struqture::spins::{PauliOperator, ToSparseMatrixOperator};
+    let mut operator = PauliOperator::new();
+    operator
+        .add_operator_product(product.clone(), 1.0.into())
+        .unwrap();
+    operator.dense_matrix(number_spins).unwrap()
+}
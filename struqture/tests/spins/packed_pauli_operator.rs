@@ -0,0 +1,169 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for public API of PackedPauliOperator
+
+use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
+use struqture::prelude::*;
+use struqture::spins::{
+    OperateOnSpins, PackedPauliOperator, PackedPauliProduct, PauliOperator, PauliProduct,
+};
+
+fn create_operator() -> PauliOperator {
+    let mut operator = PauliOperator::new();
+    operator
+        .add_operator_product(
+            PauliProduct::new().x(0).z(70),
+            CalculatorComplex::new(1.0, 0.5),
+        )
+        .unwrap();
+    operator
+        .add_operator_product(PauliProduct::new().y(0), CalculatorComplex::new(-2.0, 0.0))
+        .unwrap();
+    operator
+        .add_operator_product(PauliProduct::new().z(1).y(70), "theta".into())
+        .unwrap();
+    operator
+        .add_operator_product(PauliProduct::new(), CalculatorComplex::new(0.0, 3.0))
+        .unwrap();
+    operator
+}
+
+// Test the conversion between PauliOperator and PackedPauliOperator
+#[test]
+fn from_to_pauli_operator() {
+    let operator = create_operator();
+    let packed = PackedPauliOperator::from(operator.clone());
+    assert_eq!(packed.len(), operator.len());
+    assert_eq!(packed.current_number_spins(), 71);
+    for (product, value) in operator.iter() {
+        assert_eq!(
+            packed.get(&PackedPauliProduct::from(product.clone())),
+            value
+        );
+    }
+    let converted = PauliOperator::from(packed);
+    assert_eq!(converted, operator);
+    assert_eq!(
+        converted.keys().collect::<Vec<_>>(),
+        operator.keys().collect::<Vec<_>>()
+    );
+    assert_eq!(PackedPauliOperator::default(), PackedPauliOperator::new());
+    assert_eq!(PackedPauliOperator::new().current_number_spins(), 0);
+}
+
+// Test the multiplication of PackedPauliOperators against the multiplication of PauliOperators
+#[test]
+fn multiply() {
+    let operator = create_operator();
+    let mut other = PauliOperator::new();
+    other
+        .add_operator_product(
+            PauliProduct::new().z(0).x(70),
+            CalculatorComplex::new(0.5, 0.0),
+        )
+        .unwrap();
+    other
+        .add_operator_product(PauliProduct::new().x(1), CalculatorComplex::new(0.0, -1.0))
+        .unwrap();
+    let packed =
+        PackedPauliOperator::from(operator.clone()) * PackedPauliOperator::from(other.clone());
+    assert_eq!(
+        PauliOperator::from(packed),
+        operator.clone() * other.clone()
+    );
+
+    let packed =
+        PackedPauliOperator::from(operator.clone()) * PackedPauliOperator::from(operator.clone());
+    assert_eq!(
+        PauliOperator::from(packed),
+        operator.clone() * operator.clone()
+    );
+
+    assert_eq!(
+        PauliOperator::from(
+            PackedPauliOperator::from(operator.clone()) * CalculatorFloat::from(2.0)
+        ),
+        operator.clone() * CalculatorFloat::from(2.0)
+    );
+}
+
+// Test the add, sub and neg functions of the PackedPauliOperator
+#[test]
+fn add_sub_neg() {
+    let operator = create_operator();
+    let packed = PackedPauliOperator::from(operator.clone());
+    let sum = packed.clone() + packed.clone();
+    assert_eq!(
+        PauliOperator::from(sum.clone()),
+        operator.clone() + operator.clone()
+    );
+    assert_eq!(
+        PauliOperator::from(sum - packed.clone()),
+        (operator.clone() + operator.clone()) - operator.clone()
+    );
+    assert_eq!(PauliOperator::from(-packed.clone()), -operator.clone());
+    let numeric: PackedPauliOperator = vec![(
+        PackedPauliProduct::from(PauliProduct::new().x(3).y(80)),
+        CalculatorComplex::new(1.0, -1.0),
+    )]
+    .into_iter()
+    .collect();
+    assert!((numeric.clone() - numeric).is_empty());
+}
+
+// Test the hermitian_conjugate function of the PackedPauliOperator
+#[test]
+fn hermitian_conjugate() {
+    let operator = create_operator();
+    assert_eq!(
+        PauliOperator::from(PackedPauliOperator::from(operator.clone()).hermitian_conjugate()),
+        operator.hermitian_conjugate()
+    );
+}
+
+// Test the serialisation of the PackedPauliOperator, which is the serialisation of the PauliOperator
+#[test]
+fn serde() {
+    let operator = create_operator();
+    let packed = PackedPauliOperator::from(operator.clone());
+    let serialized = serde_json::to_string(&packed).unwrap();
+    assert_eq!(serialized, serde_json::to_string(&operator).unwrap());
+    let deserialized: PackedPauliOperator = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, packed);
+
+    let config = bincode::config::legacy();
+    let encoded = bincode::serde::encode_to_vec(&packed, config).unwrap();
+    assert_eq!(
+        encoded,
+        bincode::serde::encode_to_vec(&operator, config).unwrap()
+    );
+    let (decoded, _len): (PackedPauliOperator, usize) =
+        bincode::serde::decode_from_slice(&encoded, config).unwrap();
+    assert_eq!(decoded, packed);
+}
+
+// Test the format function (Display trait) of the PackedPauliOperator
+#[test]
+fn display() {
+    let mut packed = PackedPauliOperator::new();
+    packed
+        .add_operator_product(
+            PackedPauliProduct::from(PauliProduct::new().x(0)),
+            CalculatorComplex::from(0.5),
+        )
+        .unwrap();
+    assert_eq!(
+        format!("{packed}"),
+        "PackedPauliOperator{\n0X: (5e-1 + i * 0e0),\n}"
+    );
+}
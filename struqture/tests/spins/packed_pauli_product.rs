@@ -0,0 +1,144 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for public API of PackedPauliProduct
+
+use num_complex::Complex64;
+use std::str::FromStr;
+use struqture::spins::{PackedPauliProduct, PauliProduct, SinglePauliOperator};
+use struqture::{SpinIndex, SymmetricIndex};
+use test_case::test_case;
+
+const PAULIS: [SinglePauliOperator; 4] = [
+    SinglePauliOperator::Identity,
+    SinglePauliOperator::X,
+    SinglePauliOperator::Y,
+    SinglePauliOperator::Z,
+];
+
+// Test the conversion between PauliProduct and PackedPauliProduct
+#[test_case(PauliProduct::new(); "identity")]
+#[test_case(PauliProduct::new().x(0).y(3).z(5); "single word")]
+#[test_case(PauliProduct::new().y(63).x(64); "word boundary")]
+#[test_case(PauliProduct::new().z(1).x(130).y(200); "several words")]
+fn from_to_pauli_product(product: PauliProduct) {
+    let packed = PackedPauliProduct::from(product.clone());
+    assert_eq!(
+        packed.current_number_spins(),
+        product.current_number_spins()
+    );
+    for (index, pauli) in product.iter() {
+        assert_eq!(packed.get(*index), Some(*pauli));
+    }
+    assert_eq!(
+        packed.iter().collect::<Vec<_>>(),
+        product
+            .iter()
+            .map(|(index, pauli)| (*index, *pauli))
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(PauliProduct::from(packed.clone()), product);
+    assert_eq!(packed.to_string(), product.to_string());
+    assert_eq!(
+        PackedPauliProduct::from_str(&product.to_string()).unwrap(),
+        packed
+    );
+}
+
+// Test the get and set_pauli functions of the PackedPauliProduct
+#[test]
+fn get_set_pauli() {
+    let packed = PackedPauliProduct::new()
+        .set_pauli(2, SinglePauliOperator::X)
+        .set_pauli(100, SinglePauliOperator::Y);
+    assert_eq!(packed.get(2), Some(SinglePauliOperator::X));
+    assert_eq!(packed.get(100), Some(SinglePauliOperator::Y));
+    assert_eq!(packed.get(3), None);
+    assert_eq!(packed.get(1000), None);
+    assert_eq!(packed.current_number_spins(), 101);
+
+    // Removing the last pauli matrix gives the same PackedPauliProduct as never setting it
+    let packed = packed.set_pauli(100, SinglePauliOperator::Identity);
+    assert_eq!(packed.current_number_spins(), 3);
+    assert_eq!(
+        packed,
+        PackedPauliProduct::new().set_pauli(2, SinglePauliOperator::X)
+    );
+    assert_eq!(
+        packed.clone().set_pauli(500, SinglePauliOperator::Identity),
+        packed
+    );
+    assert_eq!(
+        PackedPauliProduct::new().set_pauli(0, SinglePauliOperator::Identity),
+        PackedPauliProduct::default()
+    );
+    assert_eq!(packed.hermitian_conjugate(), (packed.clone(), 1.0));
+    assert!(packed.is_natural_hermitian());
+}
+
+// Test the multiply function and `*` of the PackedPauliProduct against PauliProduct multiplication
+#[test]
+fn multiply() {
+    for left_first in PAULIS {
+        for left_second in PAULIS {
+            for right_first in PAULIS {
+                for right_second in PAULIS {
+                    let left = PauliProduct::new()
+                        .set_pauli(1, left_first)
+                        .set_pauli(70, left_second);
+                    let right = PauliProduct::new()
+                        .set_pauli(1, right_first)
+                        .set_pauli(70, right_second);
+                    let (expected, expected_phase) = left.clone() * right.clone();
+                    let (packed, phase) = PackedPauliProduct::from(left.clone())
+                        * PackedPauliProduct::from(right.clone());
+                    assert_eq!(PauliProduct::from(packed), expected);
+                    assert_eq!(phase, expected_phase);
+                    assert_eq!(
+                        PackedPauliProduct::from(left.clone())
+                            .commutes_with(&PackedPauliProduct::from(right.clone())),
+                        left.commutes_with(&right)
+                    );
+                }
+            }
+        }
+    }
+    let packed = PackedPauliProduct::from(PauliProduct::new().y(0).z(64));
+    assert_eq!(
+        packed.multiply(&packed),
+        (PackedPauliProduct::new(), Complex64::new(1.0, 0.0))
+    );
+}
+
+// Test the serialisation of the PackedPauliProduct, which is the serialisation of the PauliProduct
+#[test]
+fn serde() {
+    let product = PauliProduct::new().x(0).y(65).z(3);
+    let packed = PackedPauliProduct::from(product.clone());
+    assert_eq!(
+        serde_json::to_string(&packed).unwrap(),
+        serde_json::to_string(&product).unwrap()
+    );
+    let deserialized: PackedPauliProduct =
+        serde_json::from_str(&serde_json::to_string(&product).unwrap()).unwrap();
+    assert_eq!(deserialized, packed);
+
+    let config = bincode::config::legacy();
+    let encoded = bincode::serde::encode_to_vec(&packed, config).unwrap();
+    assert_eq!(
+        encoded,
+        bincode::serde::encode_to_vec(&product, config).unwrap()
+    );
+    let (decoded, _len): (PackedPauliProduct, usize) =
+        bincode::serde::decode_from_slice(&encoded, config).unwrap();
+    assert_eq!(decoded, packed);
+}
@@ -12,17 +12,20 @@
 
 //! Integration test for public API of PauliHamiltonian
 
-use super::create_na_matrix_from_operator_list;
+use super::{
+    apply_sparse_matrix_to_state, apply_sparse_superoperator_to_density_matrix,
+    create_na_matrix_from_operator_list, create_test_density_matrix, create_test_state,
+};
 use num_complex::Complex64;
-use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
+use qoqo_calculator::{Calculator, CalculatorComplex, CalculatorFloat};
 use serde_test::{assert_tokens, Configure, Token};
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::iter::{FromIterator, IntoIterator};
 use std::ops::{Add, Sub};
 use std::str::FromStr;
 use struqture::spins::{
-    OperateOnSpins, PauliHamiltonian, PauliOperator, PauliProduct, SinglePauliOperator,
-    ToSparseMatrixOperator,
+    magnetization_sector_basis, GraphColouring, OperateOnSpins, PauliCommutation, PauliHamiltonian,
+    PauliOperator, PauliProduct, SinglePauliOperator, ToSparseMatrixOperator,
 };
 use struqture::{prelude::*, STRUQTURE_VERSION};
 use struqture::{OperateOnDensityMatrix, SpinIndex, StruqtureError};
@@ -705,6 +708,315 @@ fn test_operator(pauli_representation: &str, pauli_operators: &[&str]) {
     }
 }
 
+// Test the commutator and anticommutator functions of the PauliHamiltonian
+#[test]
+fn commutator_anticommutator() {
+    let mut left = PauliHamiltonian::new();
+    left.set(PauliProduct::new().x(0).x(1), CalculatorFloat::from(1.0))
+        .unwrap();
+    left.set(PauliProduct::new().z(0), CalculatorFloat::from(0.5))
+        .unwrap();
+    let mut right = PauliHamiltonian::new();
+    right
+        .set(PauliProduct::new().y(1), CalculatorFloat::from(2.0))
+        .unwrap();
+    right
+        .set(PauliProduct::new().z(0).z(1), CalculatorFloat::from(0.3))
+        .unwrap();
+
+    let commutator = left.clone() * right.clone() - right.clone() * left.clone();
+    let anticommutator = left.clone() * right.clone() + right.clone() * left.clone();
+    assert_eq!(left.commutator(&right), commutator);
+    assert_eq!(left.anticommutator(&right), anticommutator);
+    assert_eq!(left.commutator(&left), PauliOperator::new());
+}
+
+// Test the free_parameters function of the PauliHamiltonian
+#[test]
+fn free_parameters() {
+    let mut sh = PauliHamiltonian::new();
+    assert_eq!(sh.free_parameters(), BTreeSet::new());
+    sh.set(PauliProduct::new().x(0), CalculatorFloat::from(1.0))
+        .unwrap();
+    sh.set(
+        PauliProduct::new().z(1),
+        CalculatorFloat::from("2e-3 * theta + sin(phi_1)"),
+    )
+    .unwrap();
+    sh.set(
+        PauliProduct::new().y(2),
+        CalculatorFloat::from("a=2; a * theta / gamma"),
+    )
+    .unwrap();
+    assert_eq!(
+        sh.free_parameters(),
+        BTreeSet::from([
+            "gamma".to_string(),
+            "phi_1".to_string(),
+            "theta".to_string()
+        ])
+    );
+}
+
+// Test that the free parameters of the PauliHamiltonian are all that is needed to substitute its parameters
+#[test]
+fn free_parameters_substitute_parameters() {
+    let mut sh = PauliHamiltonian::new();
+    sh.set(
+        PauliProduct::new().z(1),
+        CalculatorFloat::from("2e-3 * theta + sin(phi_1)"),
+    )
+    .unwrap();
+    sh.set(
+        PauliProduct::new().y(2),
+        CalculatorFloat::from("a=2; a * theta / gamma"),
+    )
+    .unwrap();
+    sh.set(
+        PauliProduct::new().x(3),
+        CalculatorFloat::from("c * 2; c=3; c"),
+    )
+    .unwrap();
+
+    let parameters: HashMap<String, f64> = sh
+        .free_parameters()
+        .into_iter()
+        .map(|name| (name, 0.5))
+        .collect();
+    let substituted = sh.substitute_parameters(&parameters).unwrap();
+    assert_eq!(substituted.free_parameters(), BTreeSet::new());
+    assert_eq!(
+        substituted.get(&PauliProduct::new().z(1)),
+        &CalculatorFloat::from(2e-3 * 0.5 + 0.5_f64.sin())
+    );
+    assert_eq!(
+        substituted.get(&PauliProduct::new().y(2)),
+        &CalculatorFloat::from(2.0)
+    );
+    assert_eq!(
+        substituted.get(&PauliProduct::new().x(3)),
+        &CalculatorFloat::from(3.0)
+    );
+}
+
+// Test the free_parameters function of symbolic expressions
+#[test_case("2e-3 * theta + 1.5E+2 * x", &["theta", "x"]; "scientific_notation")]
+#[test_case("sin(theta) + atan2(phi, 2) * sqrt(alpha)", &["alpha", "phi", "theta"]; "function_names")]
+#[test_case("j_1 * J2_x + e", &["J2_x", "e", "j_1"]; "underscores")]
+#[test_case("a=2; a * b", &["b"]; "assigned_variable")]
+#[test_case("c * 2; c=3; c", &["c"]; "used_before_assignment")]
+#[test_case("x # y\n+ z", &["x", "z"]; "comment")]
+#[test_case("3.0", &[]; "number")]
+fn free_parameters_expressions(expression: &str, expected: &[&str]) {
+    let expected: BTreeSet<String> = expected.iter().map(|name| name.to_string()).collect();
+    assert_eq!(
+        CalculatorFloat::from(expression).free_parameters(),
+        expected
+    );
+    assert_eq!(
+        CalculatorComplex::new(expression, 1.0).free_parameters(),
+        expected
+    );
+    // The expression can be substituted once the free parameters are set
+    let mut calculator = Calculator::new();
+    for name in expected.iter() {
+        calculator.set_variable(name, 0.5);
+    }
+    assert!(CalculatorFloat::from(expression)
+        .substitute_parameters(&calculator)
+        .is_ok());
+}
+
+// Test the apply_to_state and apply_to_state_into functions of the PauliHamiltonian
+#[test]
+fn apply_to_state() {
+    let mut sh = PauliHamiltonian::new();
+    sh.set(PauliProduct::new().x(0).x(1), CalculatorFloat::from(1.0))
+        .unwrap();
+    sh.set(PauliProduct::new().y(0).y(1), CalculatorFloat::from(0.5))
+        .unwrap();
+    sh.set(PauliProduct::new().z(1), CalculatorFloat::from(-0.7))
+        .unwrap();
+
+    let state = create_test_state(3);
+    let expected = apply_sparse_matrix_to_state(&PauliOperator::from(sh.clone()), &state);
+    let result = sh.apply_to_state(&state).unwrap();
+    for (value, expected_value) in result.iter().zip(expected.iter()) {
+        assert!((value - expected_value).norm() < 1e-12);
+    }
+    let mut buffer = vec![Complex64::new(1.0, 1.0); state.len()];
+    sh.apply_to_state_into(&state, &mut buffer).unwrap();
+    assert_eq!(buffer, result);
+}
+
+// Test the apply_to_density_matrix function of the PauliHamiltonian
+#[test]
+fn apply_to_density_matrix() {
+    let mut sh = PauliHamiltonian::new();
+    sh.set(PauliProduct::new().x(0).x(1), CalculatorFloat::from(1.0))
+        .unwrap();
+    sh.set(PauliProduct::new().y(0).z(2), CalculatorFloat::from(0.5))
+        .unwrap();
+    sh.set(PauliProduct::new().z(1), CalculatorFloat::from(-0.7))
+        .unwrap();
+
+    let density_matrix = create_test_density_matrix(3);
+    let expected = apply_sparse_superoperator_to_density_matrix(&sh, &density_matrix);
+    let result = sh.apply_to_density_matrix(&density_matrix).unwrap();
+    for (value, expected_value) in result.iter().zip(expected.iter()) {
+        assert!((value - expected_value).norm() < 1e-12);
+    }
+
+    let too_small = create_test_density_matrix(2);
+    assert!(sh.apply_to_density_matrix(&too_small).is_err());
+    let not_square = ndarray::Array2::<Complex64>::zeros((8, 4));
+    assert!(sh.apply_to_density_matrix(&not_square).is_err());
+
+    let mut symbolic = PauliHamiltonian::new();
+    symbolic
+        .set(PauliProduct::new().x(0), CalculatorFloat::from("a"))
+        .unwrap();
+    assert!(symbolic.apply_to_density_matrix(&density_matrix).is_err());
+}
+
+// Test the sparse_matrix_magnetization_sector function of the PauliHamiltonian
+#[test]
+fn sparse_matrix_magnetization_sector() {
+    // XXZ chain with an additional field in z-direction
+    let mut hamiltonian = PauliHamiltonian::new();
+    for i in 0..3 {
+        hamiltonian
+            .add_operator_product(PauliProduct::new().x(i).x(i + 1), 1.0.into())
+            .unwrap();
+        hamiltonian
+            .add_operator_product(PauliProduct::new().y(i).y(i + 1), 1.0.into())
+            .unwrap();
+        hamiltonian
+            .add_operator_product(PauliProduct::new().z(i).z(i + 1), 0.5.into())
+            .unwrap();
+    }
+    hamiltonian
+        .add_operator_product(PauliProduct::new().z(2), 0.3.into())
+        .unwrap();
+
+    let full_matrix = hamiltonian.sparse_matrix(4).unwrap();
+    let mut dimension = 0;
+    for number_up_spins in 0..=4 {
+        let (matrix, basis) = hamiltonian
+            .sparse_matrix_magnetization_sector(4, number_up_spins)
+            .unwrap();
+        assert_eq!(
+            basis,
+            magnetization_sector_basis(4, number_up_spins).unwrap()
+        );
+        for state in basis.iter() {
+            assert_eq!(4 - state.count_ones() as usize, number_up_spins);
+        }
+        for row in 0..basis.len() {
+            for column in 0..basis.len() {
+                let expected = full_matrix
+                    .get(&(basis[row], basis[column]))
+                    .copied()
+                    .unwrap_or_default();
+                let value = matrix.get(&(row, column)).copied().unwrap_or_default();
+                assert!((value - expected).norm() < 1e-12);
+            }
+        }
+        let (coo, coo_basis) = hamiltonian
+            .sparse_matrix_coo_magnetization_sector(4, number_up_spins)
+            .unwrap();
+        assert_eq!(coo_basis, basis);
+        assert_eq!(coo.0.len(), matrix.len());
+        dimension += basis.len();
+    }
+    assert_eq!(dimension, 16);
+
+    // A transverse field does not conserve the magnetization
+    hamiltonian
+        .add_operator_product(PauliProduct::new().x(1), 0.1.into())
+        .unwrap();
+    assert!(hamiltonian
+        .sparse_matrix_magnetization_sector(4, 2)
+        .is_err());
+    assert!(PauliHamiltonian::new()
+        .sparse_matrix_magnetization_sector(2, 3)
+        .is_err());
+    assert!(hamiltonian
+        .sparse_matrix_magnetization_sector(3, 1)
+        .is_err());
+}
+
+// Test the conserves_total_sz and conserves_z_parity functions of the PauliHamiltonian
+#[test_case(vec![], true, true; "empty")]
+#[test_case(vec![(PauliProduct::new().z(0), 1.0)], true, true; "z")]
+#[test_case(vec![(PauliProduct::new().x(0), 1.0)], false, false; "x")]
+#[test_case(vec![(PauliProduct::new().x(0).x(1), 1.0)], false, true; "xx")]
+#[test_case(vec![(PauliProduct::new().x(0).x(1), 1.0), (PauliProduct::new().y(0).y(1), 1.0), (PauliProduct::new().z(0).z(1), 0.5)], true, true; "xxz")]
+#[test_case(vec![(PauliProduct::new().x(0).y(1), 1.0), (PauliProduct::new().y(0).x(1), -1.0)], true, true; "dzyaloshinskii_moriya")]
+#[test_case(vec![(PauliProduct::new().x(0).y(1), 1.0), (PauliProduct::new().y(0).x(1), 1.0)], false, true; "xy_plus_yx")]
+#[test_case(vec![(PauliProduct::new().x(0).x(1), 0.1 + 0.2), (PauliProduct::new().y(0).y(1), 0.3)], true, true; "rounded_coefficients")]
+#[test_case(vec![(PauliProduct::new().x(0).x(1), 1e-20)], false, true; "small_coefficient")]
+#[test_case(vec![(PauliProduct::new().x(0).x(1), 1e-20), (PauliProduct::new().y(0).y(1), 1e-20)], true, true; "small_exchange")]
+fn conserved_quantities(terms: Vec<(PauliProduct, f64)>, total_sz: bool, z_parity: bool) {
+    let mut hamiltonian = PauliHamiltonian::new();
+    for (product, value) in terms {
+        hamiltonian
+            .add_operator_product(product, value.into())
+            .unwrap();
+    }
+    assert_eq!(hamiltonian.conserves_total_sz(), total_sz);
+    assert_eq!(hamiltonian.conserves_z_parity(), z_parity);
+}
+
+// Test the conserves_total_sz function of the PauliHamiltonian with symbolic coefficients
+#[test_case(vec![(PauliProduct::new().x(0).x(1), "J"), (PauliProduct::new().y(0).y(1), "J")], true; "exchange")]
+#[test_case(vec![(PauliProduct::new().x(0).x(1), "J"), (PauliProduct::new().y(0).y(1), "K")], false; "anisotropic_exchange")]
+#[test_case(vec![(PauliProduct::new().x(0).y(1), "J"), (PauliProduct::new().y(0).x(1), "J")], false; "xy_plus_yx")]
+#[test_case(vec![(PauliProduct::new().z(0).z(1), "J"), (PauliProduct::new().x(0), "h")], false; "transverse_field")]
+fn conserves_total_sz_symbolic(terms: Vec<(PauliProduct, &str)>, total_sz: bool) {
+    let mut hamiltonian = PauliHamiltonian::new();
+    for (product, value) in terms {
+        hamiltonian
+            .add_operator_product(product, value.into())
+            .unwrap();
+    }
+    assert_eq!(hamiltonian.conserves_total_sz(), total_sz);
+}
+
+// Test the commuting_groups function of the PauliHamiltonian
+#[test]
+fn commuting_groups() {
+    let mut hamiltonian = PauliHamiltonian::new();
+    for (product, value) in [
+        (PauliProduct::new(), 0.5),
+        (PauliProduct::new().x(0).x(1), 1.0),
+        (PauliProduct::new().y(0).y(1), 1.0),
+        (PauliProduct::new().z(0).z(1), 2.0),
+    ] {
+        hamiltonian
+            .add_operator_product(product, value.into())
+            .unwrap();
+    }
+
+    // XX, YY and ZZ commute, but not qubit-wise
+    let groups = hamiltonian.commuting_groups(PauliCommutation::Full, GraphColouring::Greedy);
+    assert_eq!(groups, vec![(hamiltonian.clone(), None)]);
+
+    let groups = hamiltonian.commuting_groups(PauliCommutation::QubitWise, GraphColouring::Greedy);
+    assert_eq!(groups.len(), 3);
+    let mut combined = PauliHamiltonian::new();
+    for (group, basis) in groups.iter() {
+        let basis = basis.clone().unwrap();
+        for product in group.keys() {
+            assert!(product.qubit_wise_commutes_with(&basis));
+        }
+        combined = combined + group.clone();
+    }
+    assert_eq!(combined, hamiltonian);
+    assert_eq!(groups[0].1, Some(PauliProduct::new().x(0).x(1)));
+    assert_eq!(groups[0].0.len(), 2);
+}
+
 #[cfg(feature = "json_schema")]
 #[test]
 fn test_hamiltonian_schema() {
@@ -12,7 +12,10 @@
 
 //! Integration test for public API of PauliLindbladNoiseOperator
 
-use super::create_na_matrix_from_decoherence_list;
+use super::{
+    apply_sparse_superoperator_to_density_matrix, create_clifford_gate_matrix,
+    create_na_matrix_from_decoherence_list, create_test_density_matrix,
+};
 use na::DMatrix;
 use nalgebra as na;
 use num_complex::Complex64;
@@ -22,7 +25,9 @@ use std::collections::{BTreeMap, HashMap};
 use std::iter::{FromIterator, IntoIterator};
 use std::ops::{Add, Sub};
 use std::str::FromStr;
-use struqture::spins::{DecoherenceOperator, DecoherenceProduct, PauliLindbladNoiseOperator};
+use struqture::spins::{
+    CliffordGate, DecoherenceOperator, DecoherenceProduct, PauliLindbladNoiseOperator,
+};
 use struqture::{prelude::*, STRUQTURE_VERSION};
 use struqture::{OperateOnDensityMatrix, SpinIndex};
 use test_case::test_case;
@@ -767,6 +772,13 @@ fn test_superoperator(
             }
         }
     }
+
+    let dense_test_matrix = system.dense_superoperator(left_operators.len()).unwrap();
+    for row in 0..dimension {
+        for column in 0..dimension {
+            assert_eq!(test_matrix[(row, column)], dense_test_matrix[(row, column)]);
+        }
+    }
 }
 
 #[test]
@@ -891,6 +903,109 @@ fn illegal_identity_operators() {
     assert!(ok);
 }
 
+// Test the apply_to_density_matrix function of the PauliLindbladNoiseOperator
+#[test]
+fn apply_to_density_matrix() {
+    let mut system = PauliLindbladNoiseOperator::new();
+    system
+        .set(
+            (
+                DecoherenceProduct::new().x(0),
+                DecoherenceProduct::new().x(0),
+            ),
+            CalculatorComplex::from(0.4),
+        )
+        .unwrap();
+    system
+        .set(
+            (
+                DecoherenceProduct::new().iy(1),
+                DecoherenceProduct::new().z(0).x(2),
+            ),
+            CalculatorComplex::new(0.3, -0.2),
+        )
+        .unwrap();
+    system
+        .set(
+            (
+                DecoherenceProduct::new().z(2),
+                DecoherenceProduct::new().iy(0).iy(1),
+            ),
+            CalculatorComplex::new(0.1, 0.5),
+        )
+        .unwrap();
+
+    let density_matrix = create_test_density_matrix(3);
+    let expected = apply_sparse_superoperator_to_density_matrix(&system, &density_matrix);
+    let result = system.apply_to_density_matrix(&density_matrix).unwrap();
+    for (value, expected_value) in result.iter().zip(expected.iter()) {
+        assert!((value - expected_value).norm() < 1e-12);
+    }
+
+    let too_small = create_test_density_matrix(2);
+    assert!(system.apply_to_density_matrix(&too_small).is_err());
+}
+
+// Test the conjugate_with_clifford and conjugate_with_clifford_sequence functions of the PauliLindbladNoiseOperator
+#[test]
+fn conjugate_with_clifford() {
+    let mut noise = PauliLindbladNoiseOperator::new();
+    noise
+        .add_operator_product(
+            (
+                DecoherenceProduct::new().x(0).iy(1),
+                DecoherenceProduct::new().z(1),
+            ),
+            CalculatorComplex::new(0.5, 0.25),
+        )
+        .unwrap();
+    noise
+        .add_operator_product(
+            (
+                DecoherenceProduct::new().iy(0),
+                DecoherenceProduct::new().iy(0),
+            ),
+            1.0.into(),
+        )
+        .unwrap();
+    let gates = [
+        CliffordGate::S(0),
+        CliffordGate::Swap(0, 1),
+        CliffordGate::ControlledNot {
+            control: 1,
+            target: 0,
+        },
+    ];
+    let conjugated = noise.conjugate_with_clifford_sequence(&gates).unwrap();
+
+    // The conjugated channel applied to ρ equals U N(U^† ρ U) U^†
+    let mut gate_matrix = create_clifford_gate_matrix(&gates[0], 2);
+    for gate in gates[1..].iter() {
+        gate_matrix = create_clifford_gate_matrix(gate, 2).dot(&gate_matrix);
+    }
+    let gate_adjoint = gate_matrix.t().mapv(|value| value.conj());
+    let density_matrix = create_test_density_matrix(2);
+    let expected = gate_matrix
+        .dot(&apply_sparse_superoperator_to_density_matrix(
+            &noise,
+            &gate_adjoint.dot(&density_matrix).dot(&gate_matrix),
+        ))
+        .dot(&gate_adjoint);
+    let result = apply_sparse_superoperator_to_density_matrix(&conjugated, &density_matrix);
+    for (expected_value, value) in expected.iter().zip(result.iter()) {
+        assert!((expected_value - value).norm() < 1e-10);
+    }
+
+    let mut stepwise = noise.clone();
+    for gate in gates.iter() {
+        stepwise = stepwise.conjugate_with_clifford(gate).unwrap();
+    }
+    assert_eq!(stepwise, conjugated);
+    assert!(noise
+        .conjugate_with_clifford(&CliffordGate::ControlledZ(1, 1))
+        .is_err());
+}
+
 #[cfg(feature = "json_schema")]
 #[test]
 fn test_noise_operator_schema() {
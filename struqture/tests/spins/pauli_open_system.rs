@@ -14,6 +14,7 @@
 
 use super::create_na_matrix_from_decoherence_list;
 use super::create_na_matrix_from_operator_list;
+use super::{apply_sparse_superoperator_to_density_matrix, create_test_density_matrix};
 use nalgebra as na;
 use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
@@ -991,6 +992,82 @@ fn test_truncate() {
     assert_eq!(test_system2, comparison_system2);
 }
 
+// Test the substitute_parameters function of the PauliLindbladOpenSystem
+#[test]
+fn test_substitute_parameters() {
+    let mut system = PauliLindbladOpenSystem::new();
+    system
+        .system_mut()
+        .set(PauliProduct::from_str("0X").unwrap(), "omega".into())
+        .unwrap();
+    system
+        .system_mut()
+        .set(PauliProduct::from_str("1Y").unwrap(), "omega - 0.5".into())
+        .unwrap();
+    let _ = system.noise_mut().set(
+        (
+            DecoherenceProduct::from_str("0Z").unwrap(),
+            DecoherenceProduct::from_str("0Z").unwrap(),
+        ),
+        "gamma".into(),
+    );
+
+    let mut comparison = PauliLindbladOpenSystem::new();
+    comparison
+        .system_mut()
+        .set(PauliProduct::from_str("0X").unwrap(), 0.5.into())
+        .unwrap();
+    let _ = comparison.noise_mut().set(
+        (
+            DecoherenceProduct::from_str("0Z").unwrap(),
+            DecoherenceProduct::from_str("0Z").unwrap(),
+        ),
+        0.1.into(),
+    );
+
+    let parameters = HashMap::from([("omega".to_string(), 0.5), ("gamma".to_string(), 0.1)]);
+    assert_eq!(
+        system.substitute_parameters(&parameters).unwrap(),
+        comparison
+    );
+    let missing = HashMap::from([("omega".to_string(), 0.5)]);
+    assert!(system.substitute_parameters(&missing).is_err());
+}
+
+// Test the apply_to_density_matrix function of the PauliLindbladOpenSystem
+#[test]
+fn apply_to_density_matrix() {
+    let mut system = PauliLindbladOpenSystem::new();
+    system
+        .system_mut()
+        .set(PauliProduct::new().x(0).y(1), CalculatorFloat::from(0.8))
+        .unwrap();
+    system
+        .system_mut()
+        .set(PauliProduct::new().z(2), CalculatorFloat::from(-0.3))
+        .unwrap();
+    system
+        .noise_mut()
+        .set(
+            (
+                DecoherenceProduct::new().iy(0),
+                DecoherenceProduct::new().x(1).z(2),
+            ),
+            CalculatorComplex::new(0.2, 0.1),
+        )
+        .unwrap();
+
+    let density_matrix = create_test_density_matrix(3);
+    let expected = apply_sparse_superoperator_to_density_matrix(&system, &density_matrix);
+    let result = system.apply_to_density_matrix(&density_matrix).unwrap();
+    for (value, expected_value) in result.iter().zip(expected.iter()) {
+        assert!((value - expected_value).norm() < 1e-12);
+    }
+
+    let not_power_of_two = ndarray::Array2::<Complex64>::zeros((6, 6));
+    assert!(system.apply_to_density_matrix(&not_power_of_two).is_err());
+}
+
 #[cfg(feature = "json_schema")]
 #[test]
 fn test_noise_system_schema() {
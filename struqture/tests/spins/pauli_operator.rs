@@ -12,8 +12,12 @@
 
 //! Integration test for public API of PauliOperator
 
-use super::create_na_matrix_from_operator_list;
+use super::{
+    apply_sparse_matrix_to_state, create_clifford_gate_matrix, create_na_matrix_from_operator_list,
+    create_test_state,
+};
 use nalgebra as na;
+use ndarray::array;
 use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde_test::{assert_tokens, Configure, Token};
@@ -24,10 +28,14 @@ use std::ops::{Add, Sub};
 use std::str::FromStr;
 use struqture::prelude::*;
 use struqture::spins::{
-    OperateOnSpins, PauliHamiltonian, PauliOperator, PauliProduct, ToSparseMatrixOperator,
+    CliffordGate, GraphColouring, OperateOnSpins, PauliCommutation, PauliHamiltonian,
+    PauliOperator, PauliProduct, SinglePauliOperator, ToSparseMatrixOperator,
 };
 use struqture::STRUQTURE_VERSION;
-use struqture::{CooSparseMatrix, OperateOnDensityMatrix, SpinIndex};
+use struqture::{
+    CooSparseMatrix, OperateOnDensityMatrix, SpinIndex, StruqtureError,
+    MAXIMUM_DENSE_MATRIX_DIMENSION,
+};
 use test_case::test_case;
 
 // Test the new function of the PauliOperator
@@ -632,6 +640,15 @@ fn test_superoperator(pauli_representation: &str, pauli_operators: &[&str]) {
         let val = test_matrix[(*row, *column)];
         assert_eq!(&val, second_val);
     }
+
+    let dense_test_matrix = system
+        .dense_superoperator(system.current_number_spins())
+        .unwrap();
+    for row in 0..dimension {
+        for column in 0..dimension {
+            assert_eq!(test_matrix[(row, column)], dense_test_matrix[(row, column)]);
+        }
+    }
 }
 
 #[test_case("0Z", &["Z"]; "0Z")]
@@ -708,6 +725,134 @@ fn test_operator(pauli_representation: &str, pauli_operators: &[&str]) {
             }
         }
     }
+
+    let dense_test_matrix = system.dense_matrix(system.current_number_spins()).unwrap();
+    for row in 0..dimension {
+        for column in 0..dimension {
+            assert_eq!(test_matrix[(row, column)], dense_test_matrix[(row, column)]);
+        }
+    }
+}
+
+// Test the CSR and CSC sparse matrix functions of the PauliOperator
+#[test]
+fn sparse_matrix_csr_csc() {
+    let mut system = PauliOperator::new();
+    system
+        .set(
+            PauliProduct::new().x(0).z(2),
+            CalculatorComplex::new(1.0, 0.5),
+        )
+        .unwrap();
+    system
+        .set(PauliProduct::new().y(1), CalculatorComplex::from(-0.3))
+        .unwrap();
+    system
+        .set(PauliProduct::new().z(0).z(1), CalculatorComplex::from(0.7))
+        .unwrap();
+
+    let dense = system.dense_matrix(3).unwrap();
+    let (values, columns, row_pointers) = system.sparse_matrix_csr(3).unwrap();
+    assert_eq!(row_pointers.len(), 9);
+    assert_eq!(*row_pointers.last().unwrap(), values.len());
+    let mut number_entries = 0;
+    for row in 0..8 {
+        let row_columns = &columns[row_pointers[row]..row_pointers[row + 1]];
+        assert!(row_columns.windows(2).all(|pair| pair[0] < pair[1]));
+        for index in row_pointers[row]..row_pointers[row + 1] {
+            assert_eq!(values[index], dense[(row, columns[index])]);
+        }
+        number_entries += dense
+            .row(row)
+            .iter()
+            .filter(|value| value.norm() > 0.0)
+            .count();
+    }
+    assert_eq!(values.len(), number_entries);
+
+    let (values, rows, column_pointers) = system.sparse_matrix_csc(3).unwrap();
+    assert_eq!(column_pointers.len(), 9);
+    for column in 0..8 {
+        let column_rows = &rows[column_pointers[column]..column_pointers[column + 1]];
+        assert!(column_rows.windows(2).all(|pair| pair[0] < pair[1]));
+        for index in column_pointers[column]..column_pointers[column + 1] {
+            assert_eq!(values[index], dense[(rows[index], column)]);
+        }
+    }
+    assert_eq!(values.len(), number_entries);
+
+    let dense_superoperator = system.dense_superoperator(3).unwrap();
+    let (values, columns, row_pointers) = system.sparse_matrix_superoperator_csr(3).unwrap();
+    assert_eq!(row_pointers.len(), 65);
+    for row in 0..64 {
+        let row_columns = &columns[row_pointers[row]..row_pointers[row + 1]];
+        assert!(row_columns.windows(2).all(|pair| pair[0] < pair[1]));
+        for index in row_pointers[row]..row_pointers[row + 1] {
+            assert_eq!(values[index], dense_superoperator[(row, columns[index])]);
+        }
+    }
+    let (csc_values, rows, column_pointers) = system.sparse_matrix_superoperator_csc(3).unwrap();
+    assert_eq!(column_pointers.len(), 65);
+    assert_eq!(csc_values.len(), values.len());
+    for column in 0..64 {
+        for index in column_pointers[column]..column_pointers[column + 1] {
+            let row = rows[index];
+            let position = columns[row_pointers[row]..row_pointers[row + 1]]
+                .binary_search(&column)
+                .unwrap();
+            assert_eq!(csc_values[index], values[row_pointers[row] + position]);
+        }
+    }
+}
+
+// Test the errors of the CSR and CSC functions of the PauliOperator for too few spins
+#[test]
+fn sparse_matrix_csr_csc_too_few_spins() {
+    let mut system = PauliOperator::new();
+    system
+        .set(PauliProduct::new().x(2), CalculatorComplex::from(1.0))
+        .unwrap();
+    let error = Err(StruqtureError::GenericError {
+        msg: "Cannot construct a sparse matrix for 1 spins of an object acting on 3 spins."
+            .to_string(),
+    });
+    assert_eq!(system.sparse_matrix_csr(1), error);
+    assert_eq!(system.sparse_matrix_csc(1), error);
+    assert_eq!(system.sparse_matrix_superoperator_csr(1), error);
+    assert_eq!(system.sparse_matrix_superoperator_csc(1), error);
+    assert!(system.sparse_matrix_csc(3).is_ok());
+}
+
+// Test the size checks of the dense_matrix and dense_superoperator functions of the PauliOperator
+#[test]
+fn dense_matrix_size_limit() {
+    let mut system = PauliOperator::new();
+    system
+        .set(PauliProduct::new().x(0).z(2), CalculatorComplex::from(1.0))
+        .unwrap();
+
+    let padded = system.dense_matrix(4).unwrap();
+    assert_eq!(padded.dim(), (16, 16));
+    assert_eq!(padded[(0, 1)], Complex64::new(1.0, 0.0));
+    assert_eq!(padded[(4, 5)], Complex64::new(-1.0, 0.0));
+    assert_eq!(padded[(8, 9)], Complex64::new(1.0, 0.0));
+
+    assert_eq!(
+        system.dense_matrix(14),
+        Err(StruqtureError::DenseMatrixTooLarge {
+            dimension: 16384,
+            maximum_dimension: MAXIMUM_DENSE_MATRIX_DIMENSION
+        })
+    );
+    assert_eq!(
+        system.dense_superoperator(7),
+        Err(StruqtureError::DenseMatrixTooLarge {
+            dimension: 16384,
+            maximum_dimension: MAXIMUM_DENSE_MATRIX_DIMENSION
+        })
+    );
+    assert!(system.dense_matrix(2).is_err());
+    assert!(system.dense_superoperator(2).is_err());
 }
 
 #[test]
@@ -759,6 +904,544 @@ fn test_operator_pauli_multiplication() {
     assert_eq!(prod_3, qubit_op_4 * CalculatorComplex::from((0.0, 1.0)));
 }
 
+// Test the commutator and anticommutator functions of the PauliOperator
+#[test]
+fn commutator_anticommutator() {
+    let mut left = PauliOperator::new();
+    left.set(PauliProduct::new().x(0).z(1), CalculatorComplex::from(0.5))
+        .unwrap();
+    left.set(PauliProduct::new().y(2), CalculatorComplex::new(0.2, 1.0))
+        .unwrap();
+    left.set(PauliProduct::new().z(0).z(2), CalculatorComplex::from(0.7))
+        .unwrap();
+    let mut right = PauliOperator::new();
+    right
+        .set(PauliProduct::new().y(0), CalculatorComplex::from(2.0))
+        .unwrap();
+    right
+        .set(
+            PauliProduct::new().x(1).x(2),
+            CalculatorComplex::new(0.0, -1.5),
+        )
+        .unwrap();
+    right
+        .set(PauliProduct::new().z(3), CalculatorComplex::from(1.0))
+        .unwrap();
+
+    let commutator = left.clone() * right.clone() - right.clone() * left.clone();
+    let anticommutator = left.clone() * right.clone() + right.clone() * left.clone();
+    assert_eq!(left.commutator(&right), commutator);
+    assert_eq!(left.anticommutator(&right), anticommutator);
+
+    // Products acting on different qubits commute
+    let mut disjoint = PauliOperator::new();
+    disjoint
+        .set(PauliProduct::new().x(5), CalculatorComplex::from(1.0))
+        .unwrap();
+    assert_eq!(left.commutator(&disjoint), PauliOperator::new());
+    assert_eq!(
+        left.anticommutator(&disjoint),
+        left.clone() * disjoint.clone() * CalculatorComplex::from(2.0)
+    );
+}
+
+// Test the substitute_parameters function of the PauliOperator
+#[test]
+fn substitute_parameters() {
+    let mut so = PauliOperator::new();
+    so.set(
+        PauliProduct::new().x(0),
+        CalculatorComplex::from("2 * theta"),
+    )
+    .unwrap();
+    so.set(
+        PauliProduct::new().z(1),
+        CalculatorComplex::new("phi", "theta"),
+    )
+    .unwrap();
+    so.set(PauliProduct::new().y(2), CalculatorComplex::from(0.5))
+        .unwrap();
+    so.set(
+        PauliProduct::new().z(3),
+        CalculatorComplex::from("theta - 1"),
+    )
+    .unwrap();
+
+    let parameters = HashMap::from([("theta".to_string(), 1.0), ("phi".to_string(), 0.2)]);
+    let mut comparison = PauliOperator::new();
+    comparison
+        .set(PauliProduct::new().x(0), CalculatorComplex::from(2.0))
+        .unwrap();
+    comparison
+        .set(PauliProduct::new().z(1), CalculatorComplex::new(0.2, 1.0))
+        .unwrap();
+    comparison
+        .set(PauliProduct::new().y(2), CalculatorComplex::from(0.5))
+        .unwrap();
+    assert_eq!(so.substitute_parameters(&parameters).unwrap(), comparison);
+
+    let missing = HashMap::from([("theta".to_string(), 1.0)]);
+    assert!(so.substitute_parameters(&missing).is_err());
+}
+
+// Test the substitute_parameters function of the PauliOperator with variables assigned in the coefficients
+#[test]
+fn substitute_parameters_assignment() {
+    let mut so = PauliOperator::new();
+    so.set(
+        PauliProduct::new().x(0),
+        CalculatorComplex::from("a=2; a * theta"),
+    )
+    .unwrap();
+    // The assignment in the first coefficient does not affect the second one
+    so.set(
+        PauliProduct::new().z(1),
+        CalculatorComplex::from("a + theta"),
+    )
+    .unwrap();
+
+    let parameters = HashMap::from([("theta".to_string(), 1.5), ("a".to_string(), 0.5)]);
+    let mut comparison = PauliOperator::new();
+    comparison
+        .set(PauliProduct::new().x(0), CalculatorComplex::from(3.0))
+        .unwrap();
+    comparison
+        .set(PauliProduct::new().z(1), CalculatorComplex::from(2.0))
+        .unwrap();
+    assert_eq!(so.substitute_parameters(&parameters).unwrap(), comparison);
+}
+
+// Test the apply_to_state and apply_to_state_into functions of the PauliOperator
+#[test]
+fn apply_to_state() {
+    let mut so = PauliOperator::new();
+    so.set(PauliProduct::new().x(0).z(2), CalculatorComplex::from(0.5))
+        .unwrap();
+    so.set(
+        PauliProduct::new().y(1).y(2),
+        CalculatorComplex::new(0.2, -1.0),
+    )
+    .unwrap();
+    so.set(PauliProduct::new().z(0).y(1), CalculatorComplex::from(1.5))
+        .unwrap();
+    so.set(PauliProduct::new(), CalculatorComplex::from(0.3))
+        .unwrap();
+
+    let state = create_test_state(4);
+    let expected = apply_sparse_matrix_to_state(&so, &state);
+    let result = so.apply_to_state(&state).unwrap();
+    for (value, expected_value) in result.iter().zip(expected.iter()) {
+        assert!((value - expected_value).norm() < 1e-12);
+    }
+    let mut buffer = vec![Complex64::new(1.0, 1.0); state.len()];
+    so.apply_to_state_into(&state, &mut buffer).unwrap();
+    assert_eq!(buffer, result);
+    assert!(so.apply_to_state_into(&state, &mut buffer[..8]).is_err());
+
+    assert!(so.apply_to_state(&create_test_state(2)).is_err());
+    assert!(so.apply_to_state(&state[..6]).is_err());
+    so.set(PauliProduct::new().x(3), CalculatorComplex::from("theta"))
+        .unwrap();
+    assert!(so.apply_to_state(&state).is_err());
+}
+
+// Test the sparse_matrix_magnetization_sector function of the PauliOperator
+#[test]
+fn sparse_matrix_magnetization_sector() {
+    // X_0 X_1 + Y_0 Y_1 exchanges the spins and conserves the magnetization
+    let mut exchange = PauliOperator::new();
+    exchange
+        .add_operator_product(PauliProduct::new().x(0).x(1), 1.0.into())
+        .unwrap();
+    exchange
+        .add_operator_product(PauliProduct::new().y(0).y(1), 1.0.into())
+        .unwrap();
+    let (matrix, basis) = exchange.sparse_matrix_magnetization_sector(2, 1).unwrap();
+    assert_eq!(basis, vec![1, 2]);
+    let mut expected: HashMap<(usize, usize), Complex64> = HashMap::new();
+    expected.insert((0, 1), Complex64::new(2.0, 0.0));
+    expected.insert((1, 0), Complex64::new(2.0, 0.0));
+    assert_eq!(matrix, expected);
+    let (matrix, basis) = exchange.sparse_matrix_magnetization_sector(2, 2).unwrap();
+    assert_eq!(basis, vec![0]);
+    assert!(matrix.is_empty());
+
+    // X_0 + i Y_0 flips the spin from |1> to |0>, which only shows on the rows outside the sector
+    let mut raising = PauliOperator::new();
+    raising
+        .add_operator_product(PauliProduct::new().x(0), 1.0.into())
+        .unwrap();
+    raising
+        .add_operator_product(PauliProduct::new().y(0), CalculatorComplex::new(0.0, 1.0))
+        .unwrap();
+    assert!(raising.sparse_matrix_magnetization_sector(1, 0).is_err());
+    assert!(raising.sparse_matrix_magnetization_sector(1, 1).is_err());
+    assert!(raising
+        .sparse_matrix_coo_magnetization_sector(1, 0)
+        .is_err());
+}
+
+// Test the commuting_groups function of the PauliOperator
+#[test]
+fn commuting_groups() {
+    // The non-commuting terms form the path X0 - Z0Z1 - X1 - Z1
+    let mut operator = PauliOperator::new();
+    for (product, value) in [
+        (PauliProduct::new().x(0), 1.0),
+        (PauliProduct::new().z(1), 2.0),
+        (PauliProduct::new().z(0).z(1), 3.0),
+        (PauliProduct::new().x(1), 4.0),
+    ] {
+        operator
+            .add_operator_product(product, CalculatorComplex::new(value, 1.0))
+            .unwrap();
+    }
+    let group = |terms: &[(PauliProduct, f64)]| {
+        let mut group = PauliOperator::new();
+        for (product, value) in terms {
+            group
+                .add_operator_product(product.clone(), CalculatorComplex::new(*value, 1.0))
+                .unwrap();
+        }
+        group
+    };
+
+    // The greedy colouring in the order of the terms needs three groups
+    assert_eq!(
+        operator.commuting_groups(PauliCommutation::QubitWise, GraphColouring::Greedy),
+        vec![
+            (
+                group(&[
+                    (PauliProduct::new().x(0), 1.0),
+                    (PauliProduct::new().z(1), 2.0)
+                ]),
+                Some(PauliProduct::new().x(0).z(1))
+            ),
+            (
+                group(&[(PauliProduct::new().z(0).z(1), 3.0)]),
+                Some(PauliProduct::new().z(0).z(1))
+            ),
+            (
+                group(&[(PauliProduct::new().x(1), 4.0)]),
+                Some(PauliProduct::new().x(1))
+            ),
+        ]
+    );
+    // Starting with the terms with the most conflicts needs only two groups
+    assert_eq!(
+        operator.commuting_groups(
+            PauliCommutation::QubitWise,
+            GraphColouring::LargestDegreeFirst
+        ),
+        vec![
+            (
+                group(&[
+                    (PauliProduct::new().z(1), 2.0),
+                    (PauliProduct::new().z(0).z(1), 3.0)
+                ]),
+                Some(PauliProduct::new().z(0).z(1))
+            ),
+            (
+                group(&[
+                    (PauliProduct::new().x(0), 1.0),
+                    (PauliProduct::new().x(1), 4.0)
+                ]),
+                Some(PauliProduct::new().x(0).x(1))
+            ),
+        ]
+    );
+    assert_eq!(
+        operator.commuting_groups(PauliCommutation::Full, GraphColouring::LargestDegreeFirst),
+        vec![
+            (
+                group(&[
+                    (PauliProduct::new().z(1), 2.0),
+                    (PauliProduct::new().z(0).z(1), 3.0)
+                ]),
+                None
+            ),
+            (
+                group(&[
+                    (PauliProduct::new().x(0), 1.0),
+                    (PauliProduct::new().x(1), 4.0)
+                ]),
+                None
+            ),
+        ]
+    );
+    assert!(PauliOperator::new()
+        .commuting_groups(PauliCommutation::Full, GraphColouring::Greedy)
+        .is_empty());
+}
+
+// Test the conjugate_with_clifford and conjugate_with_clifford_sequence functions of the PauliOperator
+#[test]
+fn conjugate_with_clifford() {
+    let mut operator = PauliOperator::new();
+    operator
+        .add_operator_product(
+            PauliProduct::new().x(0).y(1),
+            CalculatorComplex::new(1.0, 0.5),
+        )
+        .unwrap();
+    operator
+        .add_operator_product(PauliProduct::new().z(1), CalculatorComplex::new(-0.5, 0.0))
+        .unwrap();
+    operator
+        .add_operator_product(PauliProduct::new().y(0), CalculatorComplex::new(0.0, 2.0))
+        .unwrap();
+    let gates = [
+        CliffordGate::ControlledZ(0, 1),
+        CliffordGate::SDagger(0),
+        CliffordGate::Hadamard(1),
+    ];
+    let mut expected = operator.dense_matrix(2).unwrap();
+    for gate in gates.iter() {
+        let gate_matrix = create_clifford_gate_matrix(gate, 2);
+        expected = gate_matrix
+            .dot(&expected)
+            .dot(&gate_matrix.t().mapv(|value| value.conj()));
+    }
+    let conjugated = operator.conjugate_with_clifford_sequence(&gates).unwrap();
+    for (expected_value, value) in expected
+        .iter()
+        .zip(conjugated.dense_matrix(2).unwrap().iter())
+    {
+        assert!((expected_value - value).norm() < 1e-12);
+    }
+
+    let mut stepwise = operator.clone();
+    for gate in gates.iter() {
+        stepwise = stepwise.conjugate_with_clifford(gate).unwrap();
+    }
+    assert_eq!(stepwise, conjugated);
+    assert!(operator
+        .conjugate_with_clifford(&CliffordGate::ControlledNot {
+            control: 0,
+            target: 0
+        })
+        .is_err());
+}
+
+// Test the to_symplectic_matrices and from_symplectic_matrices functions of the PauliOperator
+#[test]
+fn symplectic_matrices() {
+    let mut operator = PauliOperator::new();
+    operator
+        .add_operator_product(
+            PauliProduct::new().x(0).z(2),
+            CalculatorComplex::new(1.0, 0.5),
+        )
+        .unwrap();
+    operator
+        .add_operator_product(PauliProduct::new().y(1), CalculatorComplex::new(-2.0, 0.0))
+        .unwrap();
+    operator
+        .add_operator_product(PauliProduct::new(), CalculatorComplex::new(0.0, 3.0))
+        .unwrap();
+
+    let (x, z, coefficients) = operator.to_symplectic_matrices(3).unwrap();
+    assert_eq!(
+        x,
+        array![
+            [true, false, false],
+            [false, true, false],
+            [false, false, false]
+        ]
+    );
+    assert_eq!(
+        z,
+        array![
+            [false, false, true],
+            [false, true, false],
+            [false, false, false]
+        ]
+    );
+    assert_eq!(
+        coefficients,
+        array![
+            Complex64::new(1.0, 0.5),
+            Complex64::new(-2.0, 0.0),
+            Complex64::new(0.0, 3.0)
+        ]
+    );
+    assert_eq!(
+        PauliOperator::from_symplectic_matrices(&x, &z, &coefficients).unwrap(),
+        operator
+    );
+
+    // Rows with the same PauliProduct are added up
+    let x = array![[true, false], [true, false]];
+    let z = array![[false, true], [false, true]];
+    let coefficients = array![Complex64::new(1.0, 0.0), Complex64::new(0.5, 1.0)];
+    let mut expected = PauliOperator::new();
+    expected
+        .add_operator_product(
+            PauliProduct::new().x(0).z(1),
+            CalculatorComplex::new(1.5, 1.0),
+        )
+        .unwrap();
+    assert_eq!(
+        PauliOperator::from_symplectic_matrices(&x, &z, &coefficients).unwrap(),
+        expected
+    );
+
+    assert!(operator.to_symplectic_matrices(2).is_err());
+    assert_eq!(
+        PauliOperator::from_symplectic_matrices(&x, &z, &array![Complex64::new(1.0, 0.0)]),
+        Err(StruqtureError::GenericError {
+            msg: "Shapes of the X matrix (2, 2), the Z matrix (2, 2) and the coefficient vector 1 do not match.".to_string()
+        })
+    );
+    let mut symbolic = PauliOperator::new();
+    symbolic
+        .add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from("theta"))
+        .unwrap();
+    assert!(symbolic.to_symplectic_matrices(1).is_err());
+}
+
+// Test the multiplication of PauliOperators with more products than are evaluated together with the parallel feature
+#[test]
+fn multiply_many_terms() {
+    let paulis = [
+        SinglePauliOperator::X,
+        SinglePauliOperator::Y,
+        SinglePauliOperator::Z,
+    ];
+    let mut operator = PauliOperator::new();
+    for index in 0..300usize {
+        let product = (0..6).fold(PauliProduct::new(), |product, qubit| {
+            product.set_pauli(qubit, paulis[(index / 3usize.pow(qubit as u32)) % 3])
+        });
+        operator
+            .add_operator_product(
+                product,
+                CalculatorComplex::new(0.1 * index as f64, 1.0 / (index + 1) as f64),
+            )
+            .unwrap();
+    }
+    let mut expected = PauliOperator::new();
+    for (left, left_value) in operator.iter() {
+        for (right, right_value) in operator.iter() {
+            let (product, phase) = left.clone() * right.clone();
+            expected
+                .add_operator_product(product, right_value.clone() * phase * left_value.clone())
+                .unwrap();
+        }
+    }
+    assert_eq!(operator.clone() * operator, expected);
+}
+
+// Test the sparse matrices of a PauliOperator with more rows than are constructed together with the parallel feature
+#[test]
+fn sparse_matrix_many_rows() {
+    let mut operator = PauliOperator::new();
+    operator
+        .add_operator_product(
+            PauliProduct::new().x(0).y(12),
+            CalculatorComplex::new(1.0, 0.5),
+        )
+        .unwrap();
+    operator
+        .add_operator_product(
+            PauliProduct::new().z(5).z(6),
+            CalculatorComplex::new(-2.0, 0.0),
+        )
+        .unwrap();
+    let matrix = operator.sparse_matrix(13).unwrap();
+    assert_eq!(matrix.len(), 2 * 8192);
+    let (values, (rows, columns)) = operator.sparse_matrix_coo(13).unwrap();
+    assert_eq!(values.len(), matrix.len());
+    let mut previous_row = 0;
+    for ((value, row), column) in values.iter().zip(rows.iter()).zip(columns.iter()) {
+        assert!(*row >= previous_row);
+        previous_row = *row;
+        assert_eq!(matrix.get(&(*row, *column)), Some(value));
+    }
+    let (csr_values, csr_columns, row_pointers) = operator.sparse_matrix_csr(13).unwrap();
+    assert_eq!(row_pointers.len(), 8193);
+    for row in 0..8192 {
+        for entry in row_pointers[row]..row_pointers[row + 1] {
+            assert_eq!(
+                matrix.get(&(row, csr_columns[entry])),
+                Some(&csr_values[entry])
+            );
+        }
+    }
+}
+
+/// Creates a PauliOperator with 300 different products on 7 spins and integer coefficients.
+#[cfg(feature = "parallel")]
+fn create_large_operator() -> PauliOperator {
+    let mut so = PauliOperator::new();
+    for term in 0..300_usize {
+        let mut product = PauliProduct::new();
+        for spin in 0..7 {
+            product = match (term >> (2 * spin)) % 4 {
+                1 => product.x(spin),
+                2 => product.y(spin),
+                3 => product.z(spin),
+                _ => product,
+            };
+        }
+        so.add_operator_product(
+            product,
+            CalculatorComplex::new((term % 7) as f64, (term % 3) as f64),
+        )
+        .unwrap();
+    }
+    so
+}
+
+// Test the parallel multiplication and matrices of the PauliOperator against a serial reference
+// with more products and rows than are evaluated together in one chunk
+#[cfg(feature = "parallel")]
+#[test]
+fn parallel_against_serial() {
+    let so = create_large_operator();
+
+    let mut serial_product = PauliOperator::new();
+    for (left, left_value) in so.iter() {
+        for (right, right_value) in so.iter() {
+            let (product, phase) = left.clone() * right.clone();
+            serial_product
+                .add_operator_product(product, left_value.clone() * right_value.clone() * phase)
+                .unwrap();
+        }
+    }
+    assert_eq!(so.clone() * so.clone(), serial_product);
+
+    let number_spins = 13;
+    let mut serial_matrix: HashMap<(usize, usize), Complex64> = HashMap::new();
+    for row in 0..2usize.pow(number_spins as u32) {
+        for (column, value) in so.sparse_matrix_entries_on_row(row).unwrap() {
+            serial_matrix.insert((row, column), value);
+        }
+    }
+    assert_eq!(so.sparse_matrix(number_spins).unwrap(), serial_matrix);
+
+    // A few terms are enough for the superoperator to have more rows than one chunk
+    let so: PauliOperator = so.into_iter().skip(290).collect();
+    let number_spins = 7;
+    let mut serial_superoperator: HashMap<(usize, usize), Complex64> = HashMap::new();
+    for row in 0..4usize.pow(number_spins as u32) {
+        for (column, value) in
+            ToSparseMatrixSuperOperator::sparse_matrix_superoperator_entries_on_row(
+                &so,
+                row,
+                number_spins,
+            )
+            .unwrap()
+        {
+            serial_superoperator.insert((row, column), value);
+        }
+    }
+    assert_eq!(
+        so.sparse_matrix_superoperator(number_spins).unwrap(),
+        serial_superoperator
+    );
+}
+
 #[cfg(feature = "json_schema")]
 #[test]
 fn test_operator_schema() {
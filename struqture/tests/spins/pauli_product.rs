@@ -12,6 +12,7 @@
 
 //! Integration test for public API of PauliProduct
 
+use super::{create_clifford_gate_matrix, pauli_product_matrix};
 use ndarray::{array, Array2};
 use num_complex::Complex64;
 use qoqo_calculator::CalculatorComplex;
@@ -23,7 +24,7 @@ use std::collections::{BTreeMap, HashMap};
 use std::hash::{Hash, Hasher};
 use std::iter::{FromIterator, IntoIterator};
 use std::str::FromStr;
-use struqture::spins::{PauliProduct, SinglePauliOperator};
+use struqture::spins::{CliffordGate, PauliProduct, SinglePauliOperator};
 use struqture::{CorrespondsTo, GetValue, SpinIndex, StruqtureError, SymmetricIndex};
 use test_case::test_case;
 
@@ -613,6 +614,116 @@ fn test_pauli_product_schema() {
     assert!(validation.is_ok());
 }
 
+// Test the qubit_wise_commutes_with function of the PauliProduct
+#[test_case(PauliProduct::new().x(0).z(1), PauliProduct::new().x(0), true; "shared_qubit")]
+#[test_case(PauliProduct::new().x(0), PauliProduct::new().z(1), true; "disjoint")]
+#[test_case(PauliProduct::new().x(0).x(1), PauliProduct::new().z(0).z(1), false; "commuting_not_qubit_wise")]
+#[test_case(PauliProduct::new().x(0).y(2), PauliProduct::new().x(0).z(2), false; "different_pauli")]
+#[test_case(PauliProduct::new(), PauliProduct::new().y(3), true; "identity")]
+fn qubit_wise_commutes_with(left: PauliProduct, right: PauliProduct, commutes: bool) {
+    assert_eq!(left.qubit_wise_commutes_with(&right), commutes);
+    assert_eq!(right.qubit_wise_commutes_with(&left), commutes);
+}
+
+// Test the conjugate_with_clifford function of the PauliProduct
+#[test_case(CliffordGate::Hadamard(1); "hadamard")]
+#[test_case(CliffordGate::S(0); "s")]
+#[test_case(CliffordGate::SDagger(2); "s_dagger")]
+#[test_case(CliffordGate::ControlledNot { control: 2, target: 0 }; "controlled_not")]
+#[test_case(CliffordGate::ControlledZ(0, 1); "controlled_z")]
+#[test_case(CliffordGate::Swap(1, 2); "swap")]
+fn conjugate_with_clifford(gate: CliffordGate) {
+    let gate_matrix = create_clifford_gate_matrix(&gate, 3);
+    let gate_adjoint = gate_matrix.t().mapv(|value| value.conj());
+    let paulis = [
+        SinglePauliOperator::Identity,
+        SinglePauliOperator::X,
+        SinglePauliOperator::Y,
+        SinglePauliOperator::Z,
+    ];
+    for index in 0..64 {
+        let mut product = PauliProduct::new();
+        for qubit in 0..3 {
+            product = product.set_pauli(qubit, paulis[(index >> (2 * qubit)) & 3]);
+        }
+        let (conjugated, phase) = product.conjugate_with_clifford(&gate).unwrap();
+        let expected = gate_matrix
+            .dot(&pauli_product_matrix(&product, 3))
+            .dot(&gate_adjoint);
+        let result = pauli_product_matrix(&conjugated, 3).mapv(|value| value * phase);
+        for (expected_value, value) in expected.iter().zip(result.iter()) {
+            assert!((expected_value - value).norm() < 1e-12);
+        }
+    }
+}
+
+// Test the conjugate_with_clifford_sequence function of the PauliProduct
+#[test]
+fn conjugate_with_clifford_sequence() {
+    let gates = [
+        CliffordGate::Hadamard(0),
+        CliffordGate::ControlledNot {
+            control: 0,
+            target: 1,
+        },
+        CliffordGate::S(1),
+    ];
+    let product = PauliProduct::new().z(0).y(1);
+    let (first, first_phase) = product.conjugate_with_clifford(&gates[0]).unwrap();
+    let (second, second_phase) = first.conjugate_with_clifford(&gates[1]).unwrap();
+    let (third, third_phase) = second.conjugate_with_clifford(&gates[2]).unwrap();
+    assert_eq!(
+        product.conjugate_with_clifford_sequence(&gates).unwrap(),
+        (third, first_phase * second_phase * third_phase)
+    );
+    // Z0 Y1 -> X0 Y1 -> (X0 X1) (Z0 Y1) = Y0 Z1 -> Y0 Z1
+    assert_eq!(
+        product.conjugate_with_clifford_sequence(&gates).unwrap(),
+        (PauliProduct::new().y(0).z(1), Complex64::new(1.0, 0.0))
+    );
+    assert_eq!(
+        product.conjugate_with_clifford_sequence(&[]).unwrap(),
+        (product.clone(), Complex64::new(1.0, 0.0))
+    );
+    assert_eq!(
+        product.conjugate_with_clifford(&CliffordGate::Swap(1, 1)),
+        Err(StruqtureError::GenericError {
+            msg: "Clifford gate Swap(1, 1) acts twice on the same qubit.".to_string()
+        })
+    );
+}
+
+// Test the to_symplectic and from_symplectic functions of the PauliProduct
+#[test]
+fn symplectic() {
+    let product = PauliProduct::new().x(0).y(2).z(3);
+    let x = vec![true, false, true, false, false];
+    let z = vec![false, false, true, true, false];
+    assert_eq!(product.to_symplectic(5).unwrap(), (x.clone(), z.clone()));
+    assert_eq!(PauliProduct::from_symplectic(&x, &z).unwrap(), product);
+    assert_eq!(
+        PauliProduct::new().to_symplectic(2).unwrap(),
+        (vec![false, false], vec![false, false])
+    );
+    assert_eq!(
+        PauliProduct::from_symplectic(&[], &[]).unwrap(),
+        PauliProduct::new()
+    );
+
+    assert_eq!(
+        product.to_symplectic(3),
+        Err(StruqtureError::GenericError {
+            msg: "Cannot represent a PauliProduct acting on 4 spins with 3 spins.".to_string()
+        })
+    );
+    assert_eq!(
+        PauliProduct::from_symplectic(&[true], &[true, false]),
+        Err(StruqtureError::GenericError {
+            msg: "Lengths of the x bit vector 1 and the z bit vector 2 do not match.".to_string()
+        })
+    );
+}
+
 #[cfg(feature = "struqture_1_import")]
 #[cfg(feature = "struqture_1_export")]
 #[test]
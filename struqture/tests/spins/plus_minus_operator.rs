@@ -12,6 +12,8 @@
 
 //! Integration test for public API of PlusMinusOperator
 
+use super::{apply_sparse_matrix_to_state, create_test_state};
+use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde_test::{assert_tokens, Configure, Token};
 use std::collections::BTreeMap;
@@ -844,6 +846,37 @@ fn pmo_from_so() {
     assert_eq!(PlusMinusOperator::from(qubit_op), pm_op);
 }
 
+// Test the apply_to_state and apply_to_state_into functions of the PlusMinusOperator
+#[test]
+fn apply_to_state() {
+    let mut pmo = PlusMinusOperator::new();
+    pmo.set(
+        PlusMinusProduct::new().plus(0).minus(1),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    pmo.set(
+        PlusMinusProduct::new().minus(0).z(2),
+        CalculatorComplex::new(0.0, 0.5),
+    )
+    .unwrap();
+    pmo.set(
+        PlusMinusProduct::new().plus(2),
+        CalculatorComplex::from(-2.0),
+    )
+    .unwrap();
+
+    let state = create_test_state(3);
+    let expected = apply_sparse_matrix_to_state(&PauliOperator::from(pmo.clone()), &state);
+    let result = pmo.apply_to_state(&state).unwrap();
+    for (value, expected_value) in result.iter().zip(expected.iter()) {
+        assert!((value - expected_value).norm() < 1e-12);
+    }
+    let mut buffer = vec![Complex64::new(1.0, 1.0); state.len()];
+    pmo.apply_to_state_into(&state, &mut buffer).unwrap();
+    assert_eq!(buffer, result);
+}
+
 #[cfg(feature = "json_schema")]
 #[test]
 fn test_plus_minus_operator_schema() {
@@ -0,0 +1,208 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for public API of Z2Symmetries
+
+use nalgebra as na;
+use num_complex::Complex64;
+use struqture::fermions::{FermionHamiltonian, HermitianFermionProduct};
+use struqture::mappings::JordanWignerFermionToSpin;
+use struqture::prelude::*;
+use struqture::spins::{
+    OperateOnSpins, PauliHamiltonian, PauliOperator, PauliProduct, SinglePauliOperator,
+    ToSparseMatrixOperator, Z2Symmetries,
+};
+use struqture::StruqtureError;
+
+/// Returns the sorted eigenvalues of a PauliHamiltonian on a number of spins.
+fn eigenvalues(hamiltonian: &PauliHamiltonian, number_spins: usize) -> Vec<f64> {
+    let matrix = hamiltonian.dense_matrix(number_spins).unwrap();
+    let dimension = matrix.nrows();
+    let matrix: na::DMatrix<Complex64> =
+        na::DMatrix::from_fn(dimension, dimension, |row, column| matrix[(row, column)]);
+    let mut eigenvalues: Vec<f64> = matrix.symmetric_eigenvalues().iter().copied().collect();
+    eigenvalues.sort_by(|left, right| left.partial_cmp(right).unwrap());
+    eigenvalues
+}
+
+/// Returns the eigenvalues of all sectors of the tapered PauliHamiltonian.
+fn tapered_eigenvalues(
+    symmetries: &Z2Symmetries,
+    hamiltonian: &PauliHamiltonian,
+    number_spins: usize,
+) -> Vec<f64> {
+    let number_symmetries = symmetries.symmetries().len();
+    let mut all_eigenvalues: Vec<f64> = Vec::new();
+    for sector in 0..(1usize << number_symmetries) {
+        let eigenvalues_sector: Vec<i8> = (0..number_symmetries)
+            .map(|index| if sector >> index & 1 == 0 { 1 } else { -1 })
+            .collect();
+        let tapered = symmetries.taper(hamiltonian, &eigenvalues_sector).unwrap();
+        all_eigenvalues.extend(eigenvalues(
+            &tapered,
+            number_spins - symmetries.tapered_qubits().len(),
+        ));
+    }
+    all_eigenvalues.sort_by(|left, right| left.partial_cmp(right).unwrap());
+    all_eigenvalues
+}
+
+/// Returns the two-site Hubbard model with interleaved spin-orbitals after the Jordan-Wigner transformation.
+fn hubbard_dimer() -> PauliHamiltonian {
+    let mut hamiltonian = FermionHamiltonian::new();
+    for (creator, annihilator) in [(0, 2), (1, 3)] {
+        hamiltonian
+            .add_operator_product(
+                HermitianFermionProduct::new([creator], [annihilator]).unwrap(),
+                (-1.0).into(),
+            )
+            .unwrap();
+    }
+    for site in 0..2 {
+        hamiltonian
+            .add_operator_product(
+                HermitianFermionProduct::new([2 * site, 2 * site + 1], [2 * site, 2 * site + 1])
+                    .unwrap(),
+                2.0.into(),
+            )
+            .unwrap();
+    }
+    hamiltonian
+        .add_operator_product(HermitianFermionProduct::new([0], [0]).unwrap(), 0.3.into())
+        .unwrap();
+    hamiltonian.jordan_wigner()
+}
+
+// Test the find function of Z2Symmetries
+#[test]
+fn find() {
+    let hamiltonian = hubbard_dimer();
+    let symmetries = Z2Symmetries::find(&hamiltonian);
+    // The parities of the spin-up and spin-down fermions
+    assert!(symmetries.symmetries().len() >= 2);
+    assert_eq!(
+        symmetries.symmetries().len(),
+        symmetries.tapered_qubits().len()
+    );
+    for (index, symmetry) in symmetries.symmetries().iter().enumerate() {
+        for product in hamiltonian.keys() {
+            assert!(symmetry.commutes_with(product));
+        }
+        for other in symmetries.symmetries()[index..].iter() {
+            assert!(symmetry.commutes_with(other));
+        }
+    }
+
+    // The Clifford maps every symmetry onto Z operators on the tapered qubits
+    for symmetry in symmetries.symmetries() {
+        let mut transformed = PauliOperator::new();
+        transformed
+            .add_operator_product(symmetry.clone(), 1.0.into())
+            .unwrap();
+        for rotation in symmetries.cliffords() {
+            transformed = rotation.clone() * transformed * rotation;
+        }
+        let transformed = transformed.truncate(1e-10);
+        assert_eq!(transformed.len(), 1);
+        let (product, value) = transformed.iter().next().unwrap();
+        assert!((value.re.float().unwrap().abs() - 1.0).abs() < 1e-10);
+        for (qubit, pauli) in product.iter() {
+            assert_eq!(*pauli, SinglePauliOperator::Z);
+            assert!(symmetries.tapered_qubits().contains(qubit));
+        }
+    }
+
+    // A Hamiltonian without symmetries
+    let mut hamiltonian = PauliHamiltonian::new();
+    hamiltonian
+        .add_operator_product(PauliProduct::new().x(0), 1.0.into())
+        .unwrap();
+    hamiltonian
+        .add_operator_product(PauliProduct::new().z(0), 1.0.into())
+        .unwrap();
+    let symmetries = Z2Symmetries::find(&hamiltonian);
+    assert!(symmetries.symmetries().is_empty());
+    assert!(symmetries.cliffords().is_empty());
+    assert_eq!(symmetries.taper(&hamiltonian, &[]).unwrap(), hamiltonian);
+}
+
+// Test the taper function of Z2Symmetries
+#[test]
+fn taper() {
+    let hamiltonian = hubbard_dimer();
+    let symmetries = Z2Symmetries::find(&hamiltonian);
+    let tapered = symmetries
+        .taper(&hamiltonian, &vec![1; symmetries.symmetries().len()])
+        .unwrap();
+    assert_eq!(
+        tapered.current_number_spins(),
+        4 - symmetries.tapered_qubits().len()
+    );
+    let full_eigenvalues = eigenvalues(&hamiltonian, 4);
+    let sector_eigenvalues = tapered_eigenvalues(&symmetries, &hamiltonian, 4);
+    assert_eq!(full_eigenvalues.len(), sector_eigenvalues.len());
+    for (full, sector) in full_eigenvalues.iter().zip(sector_eigenvalues.iter()) {
+        assert!((full - sector).abs() < 1e-10);
+    }
+
+    // All qubits of ZZ + XX are tapered off
+    let mut hamiltonian = PauliHamiltonian::new();
+    hamiltonian
+        .add_operator_product(PauliProduct::new().z(0).z(1), 1.0.into())
+        .unwrap();
+    hamiltonian
+        .add_operator_product(PauliProduct::new().x(0).x(1), 0.5.into())
+        .unwrap();
+    let symmetries = Z2Symmetries::find(&hamiltonian);
+    assert_eq!(symmetries.symmetries().len(), 2);
+    let sector_eigenvalues = tapered_eigenvalues(&symmetries, &hamiltonian, 2);
+    let full_eigenvalues = eigenvalues(&hamiltonian, 2);
+    for (full, sector) in full_eigenvalues.iter().zip(sector_eigenvalues.iter()) {
+        assert!((full - sector).abs() < 1e-10);
+    }
+}
+
+// Test the errors of the taper function of Z2Symmetries
+#[test]
+fn taper_errors() {
+    let mut hamiltonian = PauliHamiltonian::new();
+    hamiltonian
+        .add_operator_product(PauliProduct::new().z(0).z(1), 1.0.into())
+        .unwrap();
+    let symmetries = Z2Symmetries::find(&hamiltonian);
+    let number_symmetries = symmetries.symmetries().len();
+
+    assert_eq!(
+        symmetries.taper(&hamiltonian, &vec![1; number_symmetries + 1]),
+        Err(StruqtureError::GenericError {
+            msg: format!(
+                "Number of eigenvalues {} does not match the number of symmetries {}.",
+                number_symmetries + 1,
+                number_symmetries
+            )
+        })
+    );
+    assert_eq!(
+        symmetries.taper(&hamiltonian, &vec![0; number_symmetries]),
+        Err(StruqtureError::GenericError {
+            msg: "Eigenvalue 0 of a Z2 symmetry is not 1 or -1.".to_string()
+        })
+    );
+
+    let mut other = hamiltonian.clone();
+    other
+        .add_operator_product(PauliProduct::new().x(0).y(1), 1.0.into())
+        .unwrap();
+    assert!(symmetries
+        .taper(&other, &vec![1; number_symmetries])
+        .is_err());
+}